@@ -144,8 +144,8 @@ const Attendees = () => {
             params: { year: year }
         });
         // Set attendees and filtered attendees states with response data
-        setAttendees(attendeesRes.data);
-        setFilteredAttendees(attendeesRes.data);
+        setAttendees(attendeesRes.data.attendees);
+        setFilteredAttendees(attendeesRes.data.attendees);
         // Reset query filters and pagination
         setQuery("");
         setPage(1);
@@ -0,0 +1,3 @@
+-- Track per-attendee check-in
+ALTER TABLE attendees ADD COLUMN checked_in INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE attendees ADD COLUMN checked_in_at DATETIME;
@@ -0,0 +1,78 @@
+-- Add created_at/updated_at to event child tables.
+-- SQLite cannot add a column with a CURRENT_TIMESTAMP default, so each table is rebuilt.
+
+CREATE TABLE "agendas_new" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"event_id"	INTEGER NOT NULL,
+	"start_time"	DATETIME NOT NULL,
+	"title"	TEXT NOT NULL,
+	"speaker"	TEXT NOT NULL,
+	"created_at"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	"updated_at"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("event_id") REFERENCES "events"("id")
+);
+INSERT INTO agendas_new (id, event_id, start_time, title, speaker)
+SELECT id, event_id, start_time, title, speaker FROM agendas;
+DROP TABLE agendas;
+ALTER TABLE agendas_new RENAME TO agendas;
+
+CREATE TABLE "speakers_new" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"event_id"	INTEGER NOT NULL,
+	"name"	TEXT NOT NULL,
+	"bio"	TEXT,
+	"photo"	TEXT,
+	"created_at"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	"updated_at"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("event_id") REFERENCES "events"("id")
+);
+INSERT INTO speakers_new (id, event_id, name, bio, photo)
+SELECT id, event_id, name, bio, photo FROM speakers;
+DROP TABLE speakers;
+ALTER TABLE speakers_new RENAME TO speakers;
+
+CREATE TABLE "faqs_new" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"event_id"	INTEGER NOT NULL,
+	"question"	TEXT NOT NULL,
+	"answer"	TEXT,
+	"created_at"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	"updated_at"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("event_id") REFERENCES "events"("id")
+);
+INSERT INTO faqs_new (id, event_id, question, answer)
+SELECT id, event_id, question, answer FROM faqs;
+DROP TABLE faqs;
+ALTER TABLE faqs_new RENAME TO faqs;
+
+CREATE TABLE "attachments_new" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"event_id"	INTEGER NOT NULL,
+	"name"	TEXT NOT NULL,
+	"url"	TEXT NOT NULL,
+	"created_at"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	"updated_at"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("event_id") REFERENCES "events"("id")
+);
+INSERT INTO attachments_new (id, event_id, name, url)
+SELECT id, event_id, name, url FROM attachments;
+DROP TABLE attachments;
+ALTER TABLE attachments_new RENAME TO attachments;
+
+CREATE TABLE "comments_new" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"event_id"	INTEGER NOT NULL,
+	"message"	TEXT NOT NULL,
+	"created_at"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	"updated_at"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("event_id") REFERENCES "events"("id")
+);
+INSERT INTO comments_new (id, event_id, message)
+SELECT id, event_id, message FROM comments;
+DROP TABLE comments;
+ALTER TABLE comments_new RENAME TO comments;
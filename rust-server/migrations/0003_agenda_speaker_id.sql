@@ -0,0 +1,10 @@
+-- Optionally link agenda items to a speaker of the same event
+ALTER TABLE agendas ADD COLUMN speaker_id INTEGER REFERENCES speakers(id);
+
+UPDATE agendas
+SET speaker_id = (
+    SELECT speakers.id
+    FROM speakers
+    WHERE speakers.event_id = agendas.event_id AND lower(speakers.name) = lower(agendas.speaker)
+    LIMIT 1
+);
@@ -0,0 +1,2 @@
+-- Role of each user; existing users stay organizers
+ALTER TABLE users ADD COLUMN role TEXT NOT NULL DEFAULT 'organizer' CHECK (role IN ('organizer', 'admin'));
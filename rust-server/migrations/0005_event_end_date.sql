@@ -0,0 +1,13 @@
+-- Last day of multi-day events; equal to event_date for single-day events.
+-- SQLite only allows adding a NOT NULL column with a constant default, so rows inserted
+-- without an end_date get the empty placeholder, which the trigger replaces with event_date.
+ALTER TABLE events ADD COLUMN end_date DATE NOT NULL DEFAULT '';
+
+UPDATE events SET end_date = event_date;
+
+CREATE TRIGGER events_default_end_date
+AFTER INSERT ON events
+WHEN NEW.end_date = ''
+BEGIN
+    UPDATE events SET end_date = NEW.event_date WHERE id = NEW.id;
+END;
@@ -0,0 +1,8 @@
+-- Double opt-in registration: new attendees start unconfirmed with a confirmation token.
+-- Existing attendees predate confirmation and are treated as confirmed.
+ALTER TABLE attendees ADD COLUMN confirmed INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE attendees ADD COLUMN confirmation_token TEXT;
+
+UPDATE attendees SET confirmed = 1;
+
+CREATE UNIQUE INDEX attendees_confirmation_token ON attendees (confirmation_token);
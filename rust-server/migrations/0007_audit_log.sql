@@ -0,0 +1,16 @@
+-- Per-organizer log of create/update actions for accountability.
+CREATE TABLE "audit_log" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"organizer_id"	INTEGER NOT NULL,
+	"actor_user_id"	INTEGER NOT NULL,
+	"entity_type"	TEXT NOT NULL,
+	"entity_id"	INTEGER NOT NULL,
+	"action"	TEXT NOT NULL,
+	"timestamp"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	"detail"	TEXT,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("organizer_id") REFERENCES "users"("id"),
+	FOREIGN KEY("actor_user_id") REFERENCES "users"("id")
+);
+
+CREATE INDEX audit_log_organizer_timestamp ON audit_log (organizer_id, timestamp);
@@ -0,0 +1,2 @@
+-- Reusable event templates: excluded from listings and analytics, used to seed new events.
+ALTER TABLE events ADD COLUMN is_template INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,2 @@
+-- Moderation of new events: only approved events are shown publicly. Existing events stay public.
+ALTER TABLE events ADD COLUMN approval_status TEXT NOT NULL DEFAULT 'approved';
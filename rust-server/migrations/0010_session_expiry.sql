@@ -0,0 +1,18 @@
+-- Sessions expire: add created_at/expires_at. SQLite cannot add a column with a
+-- CURRENT_TIMESTAMP default, so the table is rebuilt. Existing sessions get a fresh week.
+
+CREATE TABLE "sessions_new" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"user_id"	INTEGER NOT NULL,
+	"token"	TEXT NOT NULL,
+	"created_at"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	"expires_at"	DATETIME NOT NULL,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("user_id") REFERENCES "users"("id")
+);
+INSERT INTO sessions_new (id, user_id, token, expires_at)
+SELECT id, user_id, token, datetime('now', '+7 days') FROM sessions;
+DROP TABLE sessions;
+ALTER TABLE sessions_new RENAME TO sessions;
+
+CREATE INDEX sessions_token ON sessions (token);
@@ -0,0 +1,2 @@
+-- Category names are unique, ignoring case
+CREATE UNIQUE INDEX categories_name ON categories (name COLLATE NOCASE);
@@ -0,0 +1,6 @@
+-- Categories belong to the organizer who created them. Existing categories keep a NULL
+-- organizer_id and stay shared by everyone. Names are unique per owner, ignoring case.
+ALTER TABLE categories ADD COLUMN organizer_id INTEGER REFERENCES organizers(id);
+
+DROP INDEX categories_name;
+CREATE UNIQUE INDEX categories_owner_name ON categories (COALESCE(organizer_id, 0), name COLLATE NOCASE);
@@ -0,0 +1,13 @@
+-- Registrations confirmed while their event is full wait here, oldest first, for a seat to
+-- free up. A waitlisted attendee stays unconfirmed until promoted.
+CREATE TABLE "waitlist" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"event_id"	INTEGER NOT NULL,
+	"attendee_id"	INTEGER NOT NULL UNIQUE,
+	"created_at"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("event_id") REFERENCES "events"("id"),
+	FOREIGN KEY("attendee_id") REFERENCES "attendees"("id")
+);
+
+CREATE INDEX waitlist_event ON waitlist (event_id, id);
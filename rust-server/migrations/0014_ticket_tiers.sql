@@ -0,0 +1,15 @@
+-- Priced ticket tiers of an event (e.g. General, Student, VIP). A confirmed attendee belongs to
+-- the tier whose name matches their ticket_type; events without tiers sell every ticket at price.
+CREATE TABLE "ticket_tiers" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"event_id"	INTEGER NOT NULL,
+	"name"	TEXT NOT NULL,
+	"price"	REAL NOT NULL,
+	"quantity"	INTEGER NOT NULL,
+	"created_at"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	"updated_at"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("event_id") REFERENCES "events"("id")
+);
+
+CREATE UNIQUE INDEX ticket_tiers_event_name ON ticket_tiers (event_id, name COLLATE NOCASE);
@@ -0,0 +1,2 @@
+-- Deleted events are kept, with the time they were deleted, so they can be restored.
+ALTER TABLE events ADD COLUMN deleted_at DATETIME;
@@ -0,0 +1,3 @@
+-- Optional author of a comment. The email is shown to the event's organizer only.
+ALTER TABLE comments ADD COLUMN author_name TEXT;
+ALTER TABLE comments ADD COLUMN author_email TEXT;
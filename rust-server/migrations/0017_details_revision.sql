@@ -0,0 +1,133 @@
+-- Revision of everything an organizer's event details are built from: the organizer, their
+-- events and the events' agendas, speakers, faqs, attachments and comments. Every write bumps
+-- it, so it can serve as the ETag of GET /api/events/{id}/details/.
+ALTER TABLE organizers ADD COLUMN details_revision INTEGER NOT NULL DEFAULT 0;
+
+CREATE TRIGGER organizers_details_revision
+AFTER UPDATE OF name, logo, website ON organizers
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1 WHERE id = NEW.id;
+END;
+
+CREATE TRIGGER events_insert_details_revision
+AFTER INSERT ON events
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1 WHERE id IN (NEW.organizer_id);
+END;
+
+CREATE TRIGGER events_update_details_revision
+AFTER UPDATE ON events
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1 WHERE id IN (OLD.organizer_id, NEW.organizer_id);
+END;
+
+CREATE TRIGGER events_delete_details_revision
+AFTER DELETE ON events
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1 WHERE id IN (OLD.organizer_id);
+END;
+
+CREATE TRIGGER agendas_insert_details_revision
+AFTER INSERT ON agendas
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1
+    WHERE id IN (SELECT organizer_id FROM events WHERE id IN (NEW.event_id));
+END;
+
+CREATE TRIGGER agendas_update_details_revision
+AFTER UPDATE ON agendas
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1
+    WHERE id IN (SELECT organizer_id FROM events WHERE id IN (OLD.event_id, NEW.event_id));
+END;
+
+CREATE TRIGGER agendas_delete_details_revision
+AFTER DELETE ON agendas
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1
+    WHERE id IN (SELECT organizer_id FROM events WHERE id IN (OLD.event_id));
+END;
+
+CREATE TRIGGER speakers_insert_details_revision
+AFTER INSERT ON speakers
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1
+    WHERE id IN (SELECT organizer_id FROM events WHERE id IN (NEW.event_id));
+END;
+
+CREATE TRIGGER speakers_update_details_revision
+AFTER UPDATE ON speakers
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1
+    WHERE id IN (SELECT organizer_id FROM events WHERE id IN (OLD.event_id, NEW.event_id));
+END;
+
+CREATE TRIGGER speakers_delete_details_revision
+AFTER DELETE ON speakers
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1
+    WHERE id IN (SELECT organizer_id FROM events WHERE id IN (OLD.event_id));
+END;
+
+CREATE TRIGGER faqs_insert_details_revision
+AFTER INSERT ON faqs
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1
+    WHERE id IN (SELECT organizer_id FROM events WHERE id IN (NEW.event_id));
+END;
+
+CREATE TRIGGER faqs_update_details_revision
+AFTER UPDATE ON faqs
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1
+    WHERE id IN (SELECT organizer_id FROM events WHERE id IN (OLD.event_id, NEW.event_id));
+END;
+
+CREATE TRIGGER faqs_delete_details_revision
+AFTER DELETE ON faqs
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1
+    WHERE id IN (SELECT organizer_id FROM events WHERE id IN (OLD.event_id));
+END;
+
+CREATE TRIGGER attachments_insert_details_revision
+AFTER INSERT ON attachments
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1
+    WHERE id IN (SELECT organizer_id FROM events WHERE id IN (NEW.event_id));
+END;
+
+CREATE TRIGGER attachments_update_details_revision
+AFTER UPDATE ON attachments
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1
+    WHERE id IN (SELECT organizer_id FROM events WHERE id IN (OLD.event_id, NEW.event_id));
+END;
+
+CREATE TRIGGER attachments_delete_details_revision
+AFTER DELETE ON attachments
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1
+    WHERE id IN (SELECT organizer_id FROM events WHERE id IN (OLD.event_id));
+END;
+
+CREATE TRIGGER comments_insert_details_revision
+AFTER INSERT ON comments
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1
+    WHERE id IN (SELECT organizer_id FROM events WHERE id IN (NEW.event_id));
+END;
+
+CREATE TRIGGER comments_update_details_revision
+AFTER UPDATE ON comments
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1
+    WHERE id IN (SELECT organizer_id FROM events WHERE id IN (OLD.event_id, NEW.event_id));
+END;
+
+CREATE TRIGGER comments_delete_details_revision
+AFTER DELETE ON comments
+BEGIN
+    UPDATE organizers SET details_revision = details_revision + 1
+    WHERE id IN (SELECT organizer_id FROM events WHERE id IN (OLD.event_id));
+END;
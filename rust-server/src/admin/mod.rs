@@ -0,0 +1,2 @@
+// Internal Modules
+pub mod routes;
\ No newline at end of file
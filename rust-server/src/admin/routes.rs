@@ -0,0 +1,202 @@
+// External Libraries
+use actix_web::{web, Responder, HttpResponse, HttpRequest, ResponseError};
+use log::info;
+use sqlx::SqlitePool;
+
+// Internal Mappers
+use crate::auth::mapper::update_user_role;
+use crate::organizer::mapper::fetch_all_organizers;
+use crate::event::mapper::update_event_approval;
+
+// Internal Models
+use crate::auth::models::{UpdateUserRoleRequestData, UpdateUserRoleData, ADMIN_ROLE, ORGANIZER_ROLE};
+use crate::event::models::{PaginationQuery, UpdateEventApprovalData, APPROVAL_APPROVED, APPROVAL_REJECTED};
+use crate::organizer::models::GetAllOrganizersData;
+use crate::audit::models::AuditEntryData;
+
+// Internal Services
+use crate::audit::services::record_audit;
+use crate::auth::services::{validate_session, require_role};
+
+// Internal Routes
+use crate::event::routes::{DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT};
+
+// Internal Errors
+use crate::error::AppError;
+
+
+/// Retrieves a page of all organizers with their event counts. Admin only.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `query` - Query parameters containing the optional `limit` and `offset`.
+/// * `pool` - A reference to the SQLite database connection pool.
+///
+/// # Returns
+///
+/// A JSON response containing the page of organizers, `403 Forbidden` for non-admins,
+/// or an error message if the operation fails.
+pub async fn get_all_organizers(
+    req: HttpRequest,
+    query: web::Query<PaginationQuery>,
+    pool: web::Data<SqlitePool>,
+) -> impl Responder {
+    let session = match validate_session(&req, &pool).await {
+        Ok(session) => session,
+        Err(e) => return e.error_response(),
+    };
+
+    if let Err(e) = require_role(&session, ADMIN_ROLE) {
+        return e.error_response();
+    }
+
+    let limit = query.limit.unwrap_or(DEFAULT_PAGE_LIMIT).clamp(1, MAX_PAGE_LIMIT);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    match fetch_all_organizers(GetAllOrganizersData {limit, offset}, &pool).await {
+        Ok(page) => HttpResponse::Ok().json(page),
+        Err(e) => AppError::Internal(format!("Failed to fetch organizers: {}", e)).error_response(),
+    }
+}
+
+
+/// Changes the role of a user, e.g. to promote them to admin. Admin only.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `user_id` - The path parameter representing the user's ID.
+/// * `data` - A JSON object containing the new role.
+/// * `pool` - A reference to the SQLite database connection pool.
+///
+/// # Returns
+///
+/// A response indicating the result of the role change, `403 Forbidden` for non-admins,
+/// or `400 Bad Request` for an unknown role.
+pub async fn put_user_role(
+    req: HttpRequest,
+    user_id: web::Path<i64>,
+    data: web::Json<UpdateUserRoleRequestData>,
+    pool: web::Data<SqlitePool>,
+) -> impl Responder {
+    let session = match validate_session(&req, &pool).await {
+        Ok(session) => session,
+        Err(e) => return e.error_response(),
+    };
+
+    if let Err(e) = require_role(&session, ADMIN_ROLE) {
+        return e.error_response();
+    }
+
+    let role = data.into_inner().role;
+
+    if role != ADMIN_ROLE && role != ORGANIZER_ROLE {
+        return AppError::BadRequest(format!("Unknown role '{}'", role)).error_response();
+    }
+
+    match update_user_role(UpdateUserRoleData {user_id: *user_id, role: role.clone()}, &pool).await {
+        Ok(()) => {
+            info!(target: "admin", "User {} set role of user {} to '{}'", session.user_id, user_id, role);
+            HttpResponse::Ok().body("Role updated")
+        },
+        Err(sqlx::Error::RowNotFound) => AppError::NotFound("User not found".to_string()).error_response(),
+        Err(e) => AppError::Internal(format!("Failed to update role: {}", e)).error_response(),
+    }
+}
+
+
+/// Approves an event so that it is shown publicly. Admin only.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `pool` - A reference to the SQLite database connection pool.
+///
+/// # Returns
+///
+/// A JSON response containing the updated event, `403 Forbidden` for non-admins,
+/// or `404 Not Found` if the event does not exist.
+pub async fn approve_event(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    pool: web::Data<SqlitePool>,
+) -> impl Responder {
+    set_event_approval(&req, *event_id, APPROVAL_APPROVED, &pool).await
+}
+
+
+/// Rejects an event so that it is kept out of public listings. Admin only.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `pool` - A reference to the SQLite database connection pool.
+///
+/// # Returns
+///
+/// A JSON response containing the updated event, `403 Forbidden` for non-admins,
+/// or `404 Not Found` if the event does not exist.
+pub async fn reject_event(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    pool: web::Data<SqlitePool>,
+) -> impl Responder {
+    set_event_approval(&req, *event_id, APPROVAL_REJECTED, &pool).await
+}
+
+
+/// Sets the approval status of an event on behalf of an admin and records it in the
+/// organizer's audit log.
+async fn set_event_approval(
+    req: &HttpRequest,
+    event_id: i64,
+    approval_status: &'static str,
+    pool: &SqlitePool,
+) -> HttpResponse {
+    let session = match validate_session(req, pool).await {
+        Ok(session) => session,
+        Err(e) => return e.error_response(),
+    };
+
+    if let Err(e) = require_role(&session, ADMIN_ROLE) {
+        return e.error_response();
+    }
+
+    match update_event_approval(UpdateEventApprovalData {event_id, approval_status: approval_status.to_string()}, pool).await {
+        Ok(event) => {
+            info!(target: "admin", "User {} set approval status of event {} to '{}'", session.user_id, event_id, approval_status);
+            record_audit(AuditEntryData {
+                organizer_id: event.organizer_id,
+                actor_user_id: session.user_id,
+                entity_type: "event",
+                entity_id: event_id,
+                action: if approval_status == APPROVAL_APPROVED { "approve" } else { "reject" },
+                detail: None,
+            }, pool).await;
+            HttpResponse::Ok().json(event)
+        },
+        Err(sqlx::Error::RowNotFound) => AppError::NotFound("Event not found".to_string()).error_response(),
+        Err(e) => AppError::Internal(format!("Failed to update approval status: {}", e)).error_response(),
+    }
+}
+
+
+/// Configures the admin-only routes for the application.
+///
+/// # Arguments
+///
+/// * `cfg` - A mutable reference to the service configuration.
+///
+/// # Returns
+///
+/// Configures the provided service with admin routes.
+pub fn configure_admin_routes(cfg: &mut web::ServiceConfig) {
+    cfg
+        .route("/admin/organizers/", web::get().to(get_all_organizers))
+        .route("/admin/users/{id}/role/", web::put().to(put_user_role))
+        .route("/admin/events/{id}/approve/", web::post().to(approve_event))
+        .route("/admin/events/{id}/reject/", web::post().to(reject_event));
+}
@@ -1,8 +1,9 @@
 // External Libraries
-use sqlx::SqlitePool;
+use log::debug;
+use sqlx::{SqliteConnection, SqlitePool};
 
 // Internal Models
-use crate::agenda::models::{Agenda, GetAgendaData};
+use crate::agenda::models::{Agenda, AgendaDay, GetAgendaData, DeleteAgendaData};
 
 
 /// Retrieves agenda items by their event ID.
@@ -27,7 +28,7 @@ pub async fn fetch_agenda(
 
     sqlx::query_as!(
         Agenda,
-        "SELECT id, event_id, start_time, title, speaker
+        "SELECT id, event_id, start_time, title, speaker, speaker_id, created_at, updated_at
          FROM agendas
          WHERE event_id = ?",
         event_id
@@ -37,12 +38,81 @@ pub async fn fetch_agenda(
 }
 
 
+/// Retrieves agenda items by their event ID, grouped by the day they start on.
+///
+/// Days are ordered by date and items within a day by `start_time`; days without
+/// agenda items are omitted.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing a list of `AgendaDays`, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn fetch_agenda_grouped(
+    data: GetAgendaData,
+    pool: &SqlitePool
+) -> Result<Vec<AgendaDay>, sqlx::Error> {
+    let mut agenda_items = fetch_agenda(data, pool).await?;
+    agenda_items.sort_by_key(|agenda_item| agenda_item.start_time);
+
+    let mut days: Vec<AgendaDay> = Vec::new();
+
+    for agenda_item in agenda_items {
+        let date = agenda_item.start_time.date();
+
+        match days.last_mut() {
+            Some(day) if day.date == date => day.items.push(agenda_item),
+            _ => days.push(AgendaDay { date, items: vec![agenda_item] }),
+        }
+    }
+
+    Ok(days)
+}
+
+
+/// Retrieves a single agenda item by its ID.
+///
+/// # Arguments
+///
+/// * `id` - Unique identifier of the agenda item.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the `Agenda` if found, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if no agenda item has the given ID.
+pub async fn fetch_agenda_item(
+    id: i64,
+    pool: &SqlitePool
+) -> Result<Agenda, sqlx::Error> {
+    sqlx::query_as!(
+        Agenda,
+        "SELECT id, event_id, start_time, title, speaker, speaker_id, created_at, updated_at
+         FROM agendas
+         WHERE id = ?",
+        id
+    )
+        .fetch_one(pool)
+        .await
+}
+
+
 /// Creates multiple agenda items in the database.
 ///
 /// # Arguments
 ///
 /// * `data` - A vector of `Agenda` structs containing the new agenda items.
-/// * `pool` - A reference to the SQLite connection pool.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
 ///
 /// # Returns
 ///
@@ -53,19 +123,21 @@ pub async fn fetch_agenda(
 /// Returns an error if any of the creation queries fail during execution.
 pub async fn create_agenda(
     data: Vec<Agenda>,
-    pool: &SqlitePool
+    conn: &mut SqliteConnection
 ) -> Result<Vec<Agenda>, sqlx::Error> {
+    debug!(target: "agenda", "Creating {} agenda item(s)", data.len());
+
     let mut agendas = Vec::new();
 
     for agenda_item in data {
         let rec = sqlx::query_as!(
             Agenda,
-            "INSERT INTO agendas (event_id, start_time, title, speaker) 
-             VALUES (?, ?, ?, ?)
-             RETURNING id, event_id, start_time, title, speaker",
-            agenda_item.event_id, agenda_item.start_time, agenda_item.title, agenda_item.speaker
+            "INSERT INTO agendas (event_id, start_time, title, speaker, speaker_id) 
+             VALUES (?, ?, ?, ?, ?)
+             RETURNING id, event_id, start_time, title, speaker, speaker_id, created_at, updated_at",
+            agenda_item.event_id, agenda_item.start_time, agenda_item.title, agenda_item.speaker, agenda_item.speaker_id
         )
-            .fetch_one(pool)
+            .fetch_one(&mut *conn)
             .await?;
 
         agendas.push(rec);
@@ -79,8 +151,9 @@ pub async fn create_agenda(
 ///
 /// # Arguments
 ///
+/// * `event_id` - Unique identifier of the event the items belong to.
 /// * `data` - A vector of `Agenda` structs containing the updated agenda items.
-/// * `pool` - A reference to the SQLite connection pool.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
 ///
 /// # Returns
 ///
@@ -88,22 +161,105 @@ pub async fn create_agenda(
 ///
 /// # Errors
 ///
-/// Returns an error if any of the update queries fail during execution.
+/// Returns an error if any of the update queries fail during execution. Items that do not
+/// belong to the event are left untouched.
 pub async fn update_agenda(
+    event_id: i64,
     data: Vec<Agenda>, 
-    pool: &SqlitePool
+    conn: &mut SqliteConnection
 ) -> Result<(), sqlx::Error> {
+    debug!(target: "agenda", "Updating agenda items {:?}", data.iter().map(|agenda_item| agenda_item.id).collect::<Vec<_>>());
+
     for agenda_item in data {
         sqlx::query_as!(
             Agenda,
             "UPDATE agendas 
-             SET start_time = ?, title = ?, speaker = ? 
-             WHERE id = ?",
-            agenda_item.start_time, agenda_item.title, agenda_item.speaker, agenda_item.id
+             SET start_time = ?, title = ?, speaker = ?, speaker_id = ?, updated_at = CURRENT_TIMESTAMP
+             WHERE id = ? AND event_id = ?",
+            agenda_item.start_time, agenda_item.title, agenda_item.speaker, agenda_item.speaker_id, agenda_item.id, event_id
         )
-            .execute(pool)
+            .execute(&mut *conn)
             .await?;
     };
     
     Ok(())
+}
+
+
+/// Deletes several agenda items of an event by their IDs.
+///
+/// Only agenda items belonging to the event are deleted; other IDs are ignored.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id` and the IDs to delete.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` containing the number of deleted rows, or an `sqlx::Error` if the delete fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails or any constraint is violated.
+pub async fn delete_agenda(
+    data: DeleteAgendaData,
+    conn: &mut SqliteConnection
+) -> Result<u64, sqlx::Error> {
+    debug!(target: "agenda", "Deleting agenda items {:?} of event {}", data.ids, data.event_id);
+
+    let ids = serde_json::to_string(&data.ids).unwrap_or_else(|_| "[]".to_string());
+
+    let result = sqlx::query!(
+        "DELETE FROM agendas WHERE event_id = ? AND id IN (SELECT value FROM json_each(?))",
+        data.event_id, ids
+    )
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(result.rows_affected())
+}
+
+
+/// Copies every agenda item of one event onto another event.
+///
+/// Copied items are linked to the target event's speaker with the same name, if any,
+/// so speakers should be copied first.
+///
+/// # Arguments
+///
+/// * `source_event_id` - Unique identifier of the event to copy from.
+/// * `target_event_id` - Unique identifier of the event to copy onto.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` containing the number of copied rows, or an `sqlx::Error` if the insert fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails or any constraint is violated.
+pub async fn copy_agenda(
+    source_event_id: i64,
+    target_event_id: i64,
+    conn: &mut SqliteConnection
+) -> Result<u64, sqlx::Error> {
+    debug!(target: "agenda", "Copying agenda from event {} to event {}", source_event_id, target_event_id);
+
+    let result = sqlx::query!(
+        "INSERT INTO agendas (event_id, start_time, title, speaker, speaker_id)
+         SELECT ?1, start_time, title, speaker, (
+             SELECT speakers.id
+             FROM speakers
+             WHERE speakers.event_id = ?1 AND lower(speakers.name) = lower(agendas.speaker)
+             LIMIT 1
+         )
+         FROM agendas
+         WHERE event_id = ?2",
+        target_event_id, source_event_id
+    )
+        .execute(conn)
+        .await?;
+
+    Ok(result.rows_affected())
 }
\ No newline at end of file
@@ -1,3 +1,5 @@
 // Internal Modules
 pub mod mapper;
-pub mod models;
\ No newline at end of file
+pub mod models;
+pub mod routes;
+pub mod validation;
\ No newline at end of file
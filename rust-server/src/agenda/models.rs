@@ -1,6 +1,6 @@
 // External Libraries
 use serde::{Serialize, Deserialize};
-use chrono::{NaiveDateTime};
+use chrono::{NaiveDate, NaiveDateTime};
 
 
 /// Represents an agenda in the system.
@@ -20,6 +20,17 @@ pub struct Agenda {
 
     /// Speaker of the agenda
     pub speaker: String,
+
+    /// Unique identifier of the event speaker presenting the agenda, if linked.
+    pub speaker_id: Option<i64>,
+
+    /// Timestamp for when the agenda was created.
+    #[serde(skip_deserializing)]
+    pub created_at: NaiveDateTime,
+
+    /// Timestamp for the last update to the agenda.
+    #[serde(skip_deserializing)]
+    pub updated_at: NaiveDateTime,
 }
 
 
@@ -28,4 +39,25 @@ pub struct Agenda {
 pub struct GetAgendaData {
     /// Unique identifier for the event of the agenda.
     pub event_id: i64,
+}
+
+
+/// Data required to delete several agenda items of an event.
+pub struct DeleteAgendaData {
+    /// Unique identifier for the event of the agenda.
+    pub event_id: i64,
+
+    /// Unique identifiers of the agenda items to delete.
+    pub ids: Vec<i64>,
+}
+
+
+/// Represents the agenda items scheduled on a single day.
+#[derive(Serialize)]
+pub struct AgendaDay {
+    /// Date the agenda items start on.
+    pub date: NaiveDate,
+
+    /// Agenda items for the day, ordered by start time.
+    pub items: Vec<Agenda>,
 }
\ No newline at end of file
@@ -0,0 +1,60 @@
+// External Libraries
+use actix_web::{web, HttpRequest, HttpResponse};
+use sqlx::SqlitePool;
+
+// Internal Mappers
+use crate::agenda::mapper::fetch_agenda_item;
+use crate::event::mapper::fetch_event;
+
+// Internal Models
+use crate::event::models::GetEventData;
+
+// Internal Services
+use crate::auth::services::validate_session;
+
+// Internal Errors
+use crate::error::AppError;
+
+
+/// Handles retrieving a single agenda item of an event owned by the session's organizer.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `id` - The path parameter representing the agenda item's ID.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the agenda item, `404 Not Found` if it does not exist or the organizer
+/// does not own its event, or an error message.
+pub async fn get_agenda_item(
+    req: HttpRequest,
+    id: web::Path<i64>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let agenda_item = fetch_agenda_item(*id, &pool).await
+        .map_err(|e| AppError::lookup("Agenda item", e))?;
+
+    fetch_event(GetEventData {event_id: agenda_item.event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Agenda item", e))?;
+
+    Ok(HttpResponse::Ok().json(agenda_item))
+}
+
+
+/// Configures all routes related to agenda items.
+///
+/// # Arguments
+///
+/// * `cfg` - A mutable reference to the Actix service configuration.
+///
+/// # Returns
+///
+/// Adds all agenda item routes to the Actix web application.
+pub fn configure_agenda_routes(cfg: &mut web::ServiceConfig) {
+    cfg
+        .route("/agenda/{id}/", web::get().to(get_agenda_item));
+}
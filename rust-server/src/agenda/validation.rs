@@ -0,0 +1,114 @@
+// Internal Models
+use crate::agenda::models::Agenda;
+use crate::speaker::models::Speaker;
+
+// Internal Validation
+use crate::validation::ValidationErrors;
+
+
+/// Maximum number of speaker names suggested for an unknown agenda speaker.
+const MAX_SUGGESTIONS: usize = 3;
+
+
+/// Validates that every agenda item names one of the event's speakers.
+///
+/// Names are compared case-insensitively. Unknown speakers are reported with the
+/// closest matching speaker names as suggestions.
+///
+/// # Arguments
+///
+/// * `agenda` - The agenda items to validate.
+/// * `speaker_names` - Names of every speaker of the event.
+///
+/// # Returns
+///
+/// `Ok(())` if every agenda speaker is known, or `Err(ValidationErrors)` listing each unknown speaker.
+pub fn validate_agenda_speakers(
+    agenda: &[Agenda],
+    speaker_names: &[&str]
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+
+    for (index, agenda_item) in agenda.iter().enumerate() {
+        let speaker = agenda_item.speaker.trim();
+
+        if speaker_names.iter().any(|name| name.trim().eq_ignore_ascii_case(speaker)) {
+            continue;
+        }
+
+        let suggestions = suggest_speakers(speaker, speaker_names);
+        let message = if suggestions.is_empty() {
+            format!("'{}' is not a speaker of this event", speaker)
+        } else {
+            format!("'{}' is not a speaker of this event; did you mean: {}", speaker, suggestions.join(", "))
+        };
+
+        errors.add(&format!("agenda[{}].speaker", index), message);
+    }
+
+    errors.into_result()
+}
+
+
+/// Links agenda items without a `speaker_id` to the event speaker with the same name.
+///
+/// # Arguments
+///
+/// * `agenda` - The agenda items to link.
+/// * `speakers` - The speakers of the event.
+pub fn link_agenda_speakers(
+    agenda: &mut [Agenda],
+    speakers: &[Speaker]
+) {
+    for agenda_item in agenda.iter_mut().filter(|agenda_item| agenda_item.speaker_id.is_none()) {
+        agenda_item.speaker_id = speakers
+            .iter()
+            .find(|speaker| speaker.name.trim().eq_ignore_ascii_case(agenda_item.speaker.trim()))
+            .map(|speaker| speaker.id);
+    }
+}
+
+
+/// Returns the speaker names closest to `speaker`, best match first.
+fn suggest_speakers(
+    speaker: &str,
+    speaker_names: &[&str]
+) -> Vec<String> {
+    let speaker = speaker.to_lowercase();
+
+    let mut ranked: Vec<(usize, &str)> = speaker_names
+        .iter()
+        .map(|name| (edit_distance(&speaker, &name.trim().to_lowercase()), *name))
+        .collect();
+    ranked.sort();
+    ranked.dedup_by(|a, b| a.1 == b.1);
+
+    ranked
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+
+/// Computes the Levenshtein distance between two strings.
+fn edit_distance(
+    a: &str,
+    b: &str
+) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
@@ -0,0 +1,190 @@
+// External Libraries
+use chrono::Datelike;
+use sqlx::SqlitePool;
+use std::ops::AddAssign;
+
+// Internal Models
+use crate::analytics::models::{
+    GetCapacityData,
+    GetYearEventsData,
+    DailyEventTotals,
+    EventCapacity,
+    RemainingCapacity,
+};
+use crate::event::models::Event;
+use crate::overview::models::GetOverview;
+
+
+/// Fetches the seats left to sell across an organizer's upcoming, future-dated events.
+///
+/// Oversold events contribute zero to the total and are flagged in the breakdown.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `organizer_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing a `RemainingCapacity` struct with the total and per-event breakdown,
+/// or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query to fetch events fails.
+pub async fn fetch_remaining_capacity(
+    data: GetCapacityData,
+    pool: &SqlitePool
+) -> Result<RemainingCapacity, sqlx::Error> {
+    let organizer_id = data.organizer_id;
+
+    let rows = sqlx::query!(
+        "SELECT id, title, event_date, max_attendees, tickets_sold
+         FROM events
+         WHERE status = 'upcoming' AND event_date >= CURRENT_DATE AND organizer_id = ? AND is_template = 0 AND deleted_at IS NULL
+         ORDER BY event_date ASC",
+        organizer_id
+    )
+        .fetch_all(pool)
+        .await?;
+
+    let mut total_remaining = 0i64;
+
+    let events = rows.into_iter().map(|row| {
+        let remaining = row.max_attendees - row.tickets_sold;
+        total_remaining += remaining.max(0);
+
+        EventCapacity {
+            event_id: row.id,
+            title: row.title,
+            event_date: row.event_date,
+            max_attendees: row.max_attendees,
+            tickets_sold: row.tickets_sold,
+            remaining: remaining.max(0),
+            oversold: remaining < 0,
+        }
+    }).collect();
+
+    Ok(RemainingCapacity {
+        total_remaining,
+        events,
+    })
+}
+
+
+/// Fetches the events of a specific organizer that start in a given year, optionally
+/// restricted to a single category. Templates are excluded.
+///
+/// Shared by the yearly dashboards, which bucket the events with `sum_by_month`.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `organizer_id`, `year` and optional `category_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the matching events, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query to fetch events fails.
+pub async fn fetch_year_events(
+    data: GetYearEventsData,
+    pool: &SqlitePool
+) -> Result<Vec<Event>, sqlx::Error> {
+    let year = data.year.to_string();
+
+    sqlx::query_as!(
+        Event,
+        "SELECT id, title, description, event_date, end_date, start_time, end_time, location, category_id, status, organizer_id,
+                price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, registration_deadline,
+                is_virtual, image, map_embed, accessibility_info, safety_guidelines, is_template, approval_status, created_at, updated_at
+         FROM events
+         WHERE strftime('%Y', event_date) = ?1 AND organizer_id = ?2 AND (?3 IS NULL OR category_id = ?3) AND is_template = 0 AND deleted_at IS NULL",
+        year, data.organizer_id, data.category_id
+    )
+        .fetch_all(pool)
+        .await
+}
+
+
+/// Fetches the number of events and their attendees for each day of a year on which
+/// a specific organizer's events start.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `year` and `organizer_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the daily totals ordered by date, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query to fetch daily totals fails.
+pub async fn fetch_daily_event_totals(
+    data: GetOverview,
+    pool: &SqlitePool
+) -> Result<Vec<DailyEventTotals>, sqlx::Error> {
+    let year = data.year.to_string();
+    let organizer_id = data.organizer_id;
+
+    let daily_rows = sqlx::query!(
+        r#"
+        SELECT
+            strftime('%Y-%m-%d', event_date) AS day,
+            COUNT(*) AS event_count,
+            SUM(attendees) AS attendee_count
+        FROM events
+        WHERE strftime('%Y', event_date) = ? AND organizer_id = ? AND is_template = 0 AND deleted_at IS NULL
+        GROUP BY day
+        ORDER BY day
+        "#,
+        year,
+        organizer_id
+    )
+        .fetch_all(pool)
+        .await?;
+
+    Ok(daily_rows.into_iter().filter_map(|row| {
+        row.day.map(|day| DailyEventTotals { date: day, events: row.event_count, attendees: row.attendee_count })
+    }).collect())
+}
+
+
+/// Returns the zero-based month of the year an event starts in, for use as a `sum_by_month` bucket.
+pub fn month_of_year(
+    event: &Event
+) -> usize {
+    event.event_date.month0() as usize
+}
+
+
+/// Sums a value of each event into monthly buckets.
+///
+/// # Arguments
+///
+/// * `events` - The events to sum.
+/// * `bucket_count` - Number of monthly buckets.
+/// * `bucket_of` - Returns the index of the bucket an event belongs to, e.g. `month_of_year`.
+/// * `value` - Returns the value an event contributes to its bucket.
+///
+/// # Returns
+///
+/// A vector with `bucket_count` sums, in bucket order.
+pub fn sum_by_month<T: Default + Copy + AddAssign>(
+    events: &[Event],
+    bucket_count: usize,
+    bucket_of: impl Fn(&Event) -> usize,
+    value: impl Fn(&Event) -> T
+) -> Vec<T> {
+    let mut buckets = vec![T::default(); bucket_count];
+
+    for event in events {
+        buckets[bucket_of(event)] += value(event);
+    }
+
+    buckets
+}
@@ -0,0 +1,74 @@
+// External Libraries
+use serde::{Serialize, Deserialize};
+use chrono::NaiveDate;
+
+
+/// Data required to retrieve an organizer's remaining capacity.
+#[derive(Deserialize)]
+pub struct GetCapacityData {
+    /// Identifier for the event organizer.
+    pub organizer_id: i64,
+}
+
+
+/// Represents the remaining seats of a single upcoming event.
+#[derive(Serialize)]
+pub struct EventCapacity {
+    /// Unique identifier for the event.
+    pub event_id: i64,
+
+    /// Title of the event.
+    pub title: String,
+
+    /// The date of the event.
+    pub event_date: NaiveDate,
+
+    /// Maximum number of attendees allowed.
+    pub max_attendees: i64,
+
+    /// Number of tickets sold for the event.
+    pub tickets_sold: i64,
+
+    /// Seats left to sell, clamped to zero.
+    pub remaining: i64,
+
+    /// Flag indicating more tickets were sold than the event allows.
+    pub oversold: bool,
+}
+
+
+/// Represents the outstanding capacity across an organizer's upcoming events.
+#[derive(Serialize)]
+pub struct RemainingCapacity {
+    /// Total seats left to sell across all upcoming events.
+    pub total_remaining: i64,
+
+    /// Remaining seats per upcoming event.
+    pub events: Vec<EventCapacity>,
+}
+
+
+/// Data required to retrieve the events an organizer holds in a year.
+pub struct GetYearEventsData {
+    /// Identifier for the event organizer.
+    pub organizer_id: i64,
+
+    /// The year the events start in (e.g., 2025).
+    pub year: i64,
+
+    /// Optional category to restrict the events to.
+    pub category_id: Option<i64>,
+}
+
+
+/// Represents the number of events and their attendees on a single day.
+pub struct DailyEventTotals {
+    /// Date in "YYYY-MM-DD" format.
+    pub date: String,
+
+    /// Number of events starting on the date.
+    pub events: i64,
+
+    /// Number of attendees of those events.
+    pub attendees: i64,
+}
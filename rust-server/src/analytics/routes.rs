@@ -0,0 +1,56 @@
+// External Libraries
+use actix_web::{web, Responder, HttpResponse, HttpRequest, ResponseError};
+use sqlx::SqlitePool;
+
+// Internal Mappers
+use crate::analytics::mapper::fetch_remaining_capacity;
+
+// Internal Models
+use crate::analytics::models::GetCapacityData;
+
+// Internal Services
+use crate::auth::services::validate_session;
+
+// Internal Errors
+use crate::error::AppError;
+
+
+/// Retrieves the seats left to sell across the organizer's upcoming events.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `pool` - A reference to the SQLite database connection pool.
+///
+/// # Returns
+///
+/// A JSON response containing the total and per-event remaining capacity or an error message if the operation fails.
+pub async fn get_remaining_capacity(
+    req: HttpRequest,
+    pool: web::Data<SqlitePool>,
+) -> impl Responder {
+    let session = match validate_session(&req, &pool).await {
+        Ok(session) => session,
+        Err(e) => return e.error_response(),
+    };
+
+    match fetch_remaining_capacity(GetCapacityData {organizer_id: session.user_id}, &pool).await {
+        Ok(capacity) => HttpResponse::Ok().json(capacity),
+        Err(e) => AppError::Internal(format!("Failed to fetch remaining capacity: {}", e)).error_response(),
+    }
+}
+
+
+/// Configures the analytics-related routes for the application.
+///
+/// # Arguments
+///
+/// * `cfg` - A mutable reference to the service configuration.
+///
+/// # Returns
+///
+/// Configures the provided service with analytics routes.
+pub fn configure_analytics_routes(cfg: &mut web::ServiceConfig) {
+    cfg
+        .route("/analytics/capacity/remaining/", web::get().to(get_remaining_capacity));
+}
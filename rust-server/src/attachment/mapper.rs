@@ -1,8 +1,9 @@
 // External Libraries
-use sqlx::SqlitePool;
+use log::debug;
+use sqlx::{SqliteConnection, SqlitePool};
 
 // Internal Models
-use crate::attachment::models::{Attachment, GetAttachmentData};
+use crate::attachment::models::{Attachment, GetAttachmentData, DeleteAttachmentsData};
 
 
 /// Retrieves attachment items by their event ID.
@@ -27,7 +28,7 @@ pub async fn fetch_attachments(
 
     sqlx::query_as!(
         Attachment,
-        "SELECT id, event_id, name, url
+        "SELECT id, event_id, name, url, created_at, updated_at
          FROM attachments
          WHERE event_id = ?",
         event_id
@@ -42,7 +43,7 @@ pub async fn fetch_attachments(
 /// # Arguments
 ///
 /// * `data` - A vector of `Attachment` structs containing the created attachment items.
-/// * `pool` - A reference to the SQLite connection pool.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
 ///
 /// # Returns
 ///
@@ -53,8 +54,10 @@ pub async fn fetch_attachments(
 /// Returns an error if any of the creation queries fail during execution.
 pub async fn create_attachments(
     data: Vec<Attachment>,
-    pool: &SqlitePool
+    conn: &mut SqliteConnection
 ) -> Result<Vec<Attachment>, sqlx::Error> {
+    debug!(target: "attachment", "Creating {} attachment(s)", data.len());
+
     let mut attachments = Vec::new();
     
     for attachment_item in data {
@@ -62,10 +65,10 @@ pub async fn create_attachments(
             Attachment,
             "INSERT INTO attachments (event_id, name, url)
              VALUES (?, ?, ?)
-             RETURNING id, event_id, name, url",
+             RETURNING id, event_id, name, url, created_at, updated_at",
             attachment_item.event_id, attachment_item.name, attachment_item.url
         )
-            .fetch_one(pool)
+            .fetch_one(&mut *conn)
             .await?;
         
         attachments.push(rec);
@@ -79,8 +82,9 @@ pub async fn create_attachments(
 ///
 /// # Arguments
 ///
+/// * `event_id` - Unique identifier of the event the items belong to.
 /// * `data` - A vector of `Attachment` structs containing the updated attachment items.
-/// * `pool` - A reference to the SQLite connection pool.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
 ///
 /// # Returns
 ///
@@ -88,22 +92,97 @@ pub async fn create_attachments(
 ///
 /// # Errors
 ///
-/// Returns an error if any of the update queries fail during execution.
+/// Returns an error if any of the update queries fail during execution. Items that do not
+/// belong to the event are left untouched.
 pub async fn update_attachments(
+    event_id: i64,
     data: Vec<Attachment>, 
-    pool: &SqlitePool
+    conn: &mut SqliteConnection
 ) -> Result<(), sqlx::Error> {
+    debug!(target: "attachment", "Updating attachments {:?}", data.iter().map(|attachment_item| attachment_item.id).collect::<Vec<_>>());
+
     for attachment_item in data {
         sqlx::query_as!(
             Attachment,
             "UPDATE attachments 
-             SET name = ?, url = ? 
-             WHERE id = ?",
-            attachment_item.name, attachment_item.url, attachment_item.id
+             SET name = ?, url = ?, updated_at = CURRENT_TIMESTAMP
+             WHERE id = ? AND event_id = ?",
+            attachment_item.name, attachment_item.url, attachment_item.id, event_id
         )
-            .execute(pool)
+            .execute(&mut *conn)
             .await?;
     };
 
     Ok(())
-}
\ No newline at end of file
+}
+
+
+/// Copies every attachment of one event onto another event.
+///
+/// # Arguments
+///
+/// * `source_event_id` - Unique identifier of the event to copy from.
+/// * `target_event_id` - Unique identifier of the event to copy onto.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` containing the number of copied rows, or an `sqlx::Error` if the insert fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails or any constraint is violated.
+pub async fn copy_attachments(
+    source_event_id: i64,
+    target_event_id: i64,
+    conn: &mut SqliteConnection
+) -> Result<u64, sqlx::Error> {
+    debug!(target: "attachment", "Copying attachments from event {} to event {}", source_event_id, target_event_id);
+
+    let result = sqlx::query!(
+        "INSERT INTO attachments (event_id, name, url)
+         SELECT ?, name, url
+         FROM attachments
+         WHERE event_id = ?",
+        target_event_id, source_event_id
+    )
+        .execute(conn)
+        .await?;
+
+    Ok(result.rows_affected())
+}
+
+
+/// Deletes several attachments of an event by their IDs.
+///
+/// Only attachments belonging to the event are deleted; other IDs are ignored.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id` and the IDs to delete.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` containing the number of deleted rows, or an `sqlx::Error` if the delete fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails or any constraint is violated.
+pub async fn delete_attachments(
+    data: DeleteAttachmentsData,
+    conn: &mut SqliteConnection
+) -> Result<u64, sqlx::Error> {
+    debug!(target: "attachment", "Deleting attachments {:?} of event {}", data.ids, data.event_id);
+
+    let ids = serde_json::to_string(&data.ids).unwrap_or_else(|_| "[]".to_string());
+
+    let result = sqlx::query!(
+        "DELETE FROM attachments WHERE event_id = ? AND id IN (SELECT value FROM json_each(?))",
+        data.event_id, ids
+    )
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(result.rows_affected())
+}
@@ -1,5 +1,6 @@
 // External Libraries
 use serde::{Serialize, Deserialize};
+use chrono::NaiveDateTime;
 
 
 /// Represents an attachment in the system.
@@ -16,6 +17,14 @@ pub struct Attachment {
 
     /// Url of the attachment
     pub url: String,
+
+    /// Timestamp for when the attachment was created.
+    #[serde(skip_deserializing)]
+    pub created_at: NaiveDateTime,
+
+    /// Timestamp for the last update to the attachment.
+    #[serde(skip_deserializing)]
+    pub updated_at: NaiveDateTime,
 }
 
 
@@ -24,4 +33,14 @@ pub struct Attachment {
 pub struct GetAttachmentData {
     /// Unique identifier for the event of the attachment.
     pub event_id: i64,
-}
\ No newline at end of file
+}
+
+
+/// Data required to delete several attachments of an event.
+pub struct DeleteAttachmentsData {
+    /// Unique identifier for the event of the attachments.
+    pub event_id: i64,
+
+    /// Unique identifiers of the attachments to delete.
+    pub ids: Vec<i64>,
+}
@@ -1,16 +1,33 @@
 // External Libraries
-use chrono::Datelike;
-use sqlx::SqlitePool;
+use log::debug;
+use chrono::{Datelike, NaiveDate};
+use sqlx::{SqliteConnection, SqlitePool};
+
+// Internal Mappers
+use crate::analytics::mapper::{fetch_year_events, fetch_daily_event_totals, month_of_year, sum_by_month};
 
 // Internal Models
+use crate::analytics::models::GetYearEventsData;
 use crate::attendee::models::{
     Attendee,
     GetAttendeeData,
+    GetAttendeeListData,
+    AttendeePage,
     AttendeeTotals,
     AttendanceExtremes,
     AttendeeCounts,
     NoShowTotals,
-    TicketTypeTotals
+    TicketTypeTotals,
+    CheckInResult,
+    ToggleCheckInData,
+    AttendeeCountUpdate,
+    CreateAttendeeData,
+    ConfirmAttendeeData,
+    AttendeeConfirmation,
+    WaitlistEntry,
+    AddToWaitlistData,
+    DeleteAttendeeData,
+    AttendeeCancellation
 };
 use crate::event::models::Event;
 use crate::overview::models::{CountByDate, GetOverview};
@@ -35,35 +52,28 @@ pub async fn fetch_monthly_attendees(
     data: GetOverview,
     pool: &SqlitePool
 ) -> Result<AttendeeTotals, sqlx::Error> {
-    let year = data.year.to_string();
-    let organizer_id = data.organizer_id;
-
-    let events = sqlx::query_as!(
-        Event,
-        "SELECT id, title, description, event_date, start_time, end_time, location, category_id, status, organizer_id, 
-                price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, registration_deadline,
-                is_virtual, image, map_embed, accessibility_info, safety_guidelines, created_at, updated_at
-         FROM events 
-         WHERE strftime('%Y', event_date) = ? AND organizer_id = ?",
-        year, organizer_id
-    )
-        .fetch_all(pool)
-        .await?;
+    let events = fetch_year_events(GetYearEventsData {organizer_id: data.organizer_id, year: data.year, category_id: None}, pool).await?;
 
-    let mut attendees_by_month = vec![0i64; 12];
-    let mut total_attendees = 0i64;
+    Ok(tally_attendees(&events))
+}
 
-    for event in events {
-        let month = event.event_date.month() as usize - 1;
 
-        attendees_by_month[month] += event.attendees;
-        total_attendees += event.attendees;
+/// Tallies the attendees of a year's events by month.
+///
+/// # Arguments
+///
+/// * `events` - The events of a single year.
+///
+/// # Returns
+///
+/// An `AttendeeTotals` struct with monthly attendees and total attendees.
+pub fn tally_attendees(
+    events: &[Event]
+) -> AttendeeTotals {
+    AttendeeTotals {
+        attendees: sum_by_month(events, 12, month_of_year, |event| event.attendees),
+        total: events.iter().map(|event| event.attendees).sum(),
     }
-
-    Ok(AttendeeTotals {
-        attendees: attendees_by_month,
-        total: total_attendees,
-    })
 }
 
 
@@ -86,35 +96,12 @@ pub async fn fetch_daily_attendee_counts(
     data: GetOverview,
     pool: &SqlitePool
 ) -> Result<AttendeeCounts, sqlx::Error> {
-    let year = data.year.to_string();
-    let organizer_id = data.organizer_id;
-
-    let daily_rows = sqlx::query!(
-        r#"
-        SELECT
-            strftime('%Y-%m-%d', event_date) AS day,
-            SUM(attendees) AS attendee_count
-        FROM events
-        WHERE strftime('%Y', event_date) = ? AND organizer_id = ?
-        GROUP BY day
-        ORDER BY day
-        "#,
-        year,
-        organizer_id
-    )
-        .fetch_all(pool)
-        .await?;
-
-    let daily_totals = daily_rows.into_iter().filter_map(|row| {
-        if let Some(day) = row.day {
-            Some(CountByDate { date: day, count: row.attendee_count as usize })
-        } else {
-            None
-        }
-    }).collect();
+    let daily_totals = fetch_daily_event_totals(data, pool).await?;
 
     Ok(AttendeeCounts {
-        attendee_counts: daily_totals,
+        attendee_counts: daily_totals.into_iter()
+            .map(|day| CountByDate { date: day.date, count: day.attendees as usize })
+            .collect(),
     })
 }
 
@@ -147,14 +134,15 @@ pub async fn fetch_attendance_extremes(
 
     let most_attended = sqlx::query_as!(
         Event,
-        "SELECT id, title, description, event_date, start_time, end_time, location, category_id, status, organizer_id, 
+        "SELECT id, title, description, event_date, end_date, start_time, end_time, location, category_id, status, organizer_id, 
                 price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, registration_deadline,
-                is_virtual, image, map_embed, accessibility_info, safety_guidelines, created_at, updated_at
+                is_virtual, image, map_embed, accessibility_info, safety_guidelines, is_template, approval_status, created_at, updated_at
          FROM events 
          WHERE event_date < CURRENT_DATE 
            AND strftime('%Y', event_date) = ? 
            AND status = 'complete' 
            AND organizer_id = ?
+           AND is_template = 0 AND deleted_at IS NULL
          ORDER BY attendees DESC
          LIMIT 5",
         year, organizer_id
@@ -164,14 +152,15 @@ pub async fn fetch_attendance_extremes(
 
     let least_attended = sqlx::query_as!(
         Event,
-        "SELECT id, title, description, event_date, start_time, end_time, location, category_id, status, organizer_id, 
+        "SELECT id, title, description, event_date, end_date, start_time, end_time, location, category_id, status, organizer_id, 
                 price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, registration_deadline,
-                is_virtual, image, map_embed, accessibility_info, safety_guidelines, created_at, updated_at
+                is_virtual, image, map_embed, accessibility_info, safety_guidelines, is_template, approval_status, created_at, updated_at
          FROM events 
          WHERE event_date < CURRENT_DATE 
            AND strftime('%Y', event_date) = ? 
            AND status = 'complete' 
            AND organizer_id = ?
+           AND is_template = 0 AND deleted_at IS NULL
          ORDER BY attendees ASC
          LIMIT 5",
         year, organizer_id
@@ -188,6 +177,9 @@ pub async fn fetch_attendance_extremes(
 
 /// Fetches monthly no shows and total no shows for a specific organizer and year.
 ///
+/// Each confirmed attendee of a past event who was not checked in counts as one no-show, and
+/// the rates are the average number of no-shows per past event.
+///
 /// # Arguments
 ///
 /// * `data` - A struct containing the `year` and `organizer_id`.
@@ -208,19 +200,18 @@ pub async fn fetch_monthly_no_shows(
     let year = data.year.to_string();
     let organizer_id = data.organizer_id;
 
-    let events = sqlx::query_as!(
-        Event,
-        "SELECT id, title, description, event_date, start_time, end_time, location, category_id, status, organizer_id, 
-                price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, registration_deadline,
-                is_virtual, image, map_embed, accessibility_info, safety_guidelines, created_at, updated_at
-         FROM events 
-         WHERE strftime('%Y', event_date) = ? AND event_date < CURRENT_DATE AND organizer_id = ?",
+    let events = sqlx::query!(
+        r#"SELECT e.event_date AS "event_date!: NaiveDate", COUNT(a.id) AS "no_shows!: i64"
+           FROM events e
+           LEFT JOIN attendees a ON a.event_id = e.id AND a.confirmed = 1 AND a.checked_in = 0
+           WHERE strftime('%Y', e.event_date) = ? AND e.event_date < CURRENT_DATE AND e.organizer_id = ? AND e.is_template = 0 AND e.deleted_at IS NULL
+           GROUP BY e.id"#,
         year, organizer_id
     )
         .fetch_all(pool)
         .await?;
 
-    let mut event_counts_by_month = vec![0i64; 12];
+    let mut event_counts_by_month = [0i64; 12];
     let mut no_show_counts_by_month = vec![0i64; 12];
     let mut no_show_rates_by_month = vec![0f64; 12];
     let mut total_no_show_count = 0i64;
@@ -229,8 +220,8 @@ pub async fn fetch_monthly_no_shows(
     for event in &events {
         let month = event.event_date.month() as usize - 1;
 
-        no_show_counts_by_month[month] += event.tickets_sold - event.attendees;
-        total_no_show_count += event.tickets_sold - event.attendees;
+        no_show_counts_by_month[month] += event.no_shows;
+        total_no_show_count += event.no_shows;
         event_counts_by_month[month] += 1;
     }
     
@@ -256,6 +247,8 @@ pub async fn fetch_monthly_no_shows(
 /// Fetches monthly attendee counts grouped by ticket type (General, Student, Staff, VIP)
 /// for a specific organizer and year.
 ///
+/// Confirmed attendees are counted in the month they registered; other ticket types are ignored.
+///
 /// # Arguments
 ///
 /// * `data` - A struct containing the `year` and `organizer_id`.
@@ -267,23 +260,23 @@ pub async fn fetch_monthly_no_shows(
 ///
 /// # Errors
 ///
-/// Returns an error if the query to fetch attendees or events fails.
-pub async fn fetch_monthly_attendees_by_ticket_type(
+/// Returns an error if the query fails.
+pub async fn fetch_ticket_type_totals(
     data: GetOverview,
     pool: &SqlitePool
 ) -> Result<TicketTypeTotals, sqlx::Error> {
     let year = data.year.to_string();
     let organizer_id = data.organizer_id;
 
-    let attendees = sqlx::query_as!(
-        Attendee,
-        "SELECT id, event_id, name, email, ticket_type, registration_date
-         FROM attendees
-         WHERE strftime('%Y', registration_date) = ? AND event_id IN (
-            SELECT id
-            FROM events
-            WHERE organizer_id = ?
-        )",
+    let rows = sqlx::query!(
+        r#"SELECT CAST(strftime('%m', attendees.registration_date) AS INTEGER) AS "month!: i64",
+                  attendees.ticket_type AS "ticket_type!: String",
+                  COUNT(*) AS "count!: i64"
+           FROM attendees
+           JOIN events ON events.id = attendees.event_id
+           WHERE strftime('%Y', attendees.registration_date) = ? AND attendees.confirmed = 1
+             AND events.organizer_id = ? AND events.is_template = 0 AND events.deleted_at IS NULL
+           GROUP BY 1, 2"#,
         year, organizer_id
     )
         .fetch_all(pool)
@@ -294,13 +287,13 @@ pub async fn fetch_monthly_attendees_by_ticket_type(
     let mut staff_counts = vec![0i64; 12];
     let mut vip_counts = vec![0i64; 12];
 
-    for attendee in attendees {
-        let month = attendee.registration_date.month() as usize - 1;
-        match attendee.ticket_type.as_str() {
-            "General" => general_counts[month] += 1,
-            "Student" => student_counts[month] += 1,
-            "Staff" => staff_counts[month] += 1,
-            "VIP" => vip_counts[month] += 1,
+    for row in rows {
+        let month = row.month as usize - 1;
+        match row.ticket_type.as_str() {
+            "General" => general_counts[month] += row.count,
+            "Student" => student_counts[month] += row.count,
+            "Staff" => staff_counts[month] += row.count,
+            "VIP" => vip_counts[month] += row.count,
             _ => {}
         }
     }
@@ -336,11 +329,624 @@ pub async fn fetch_attendees_by_event(
 
     sqlx::query_as!(
         Attendee,
-        "SELECT id, event_id, name, email, ticket_type, registration_date
+        "SELECT id, event_id, name, email, ticket_type, registration_date, checked_in, checked_in_at, confirmed
          FROM attendees
          WHERE event_id = ?",
         event_id
     )
         .fetch_all(pool)
         .await
+}
+
+
+/// Retrieves a page of a specific event's attendees, optionally restricted to a registration
+/// date range and a ticket type, ordered by ID.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id`, the optional inclusive `from` and `to` dates,
+///   the optional `ticket_type`, and the `limit` and `offset` of the page.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing an `AttendeePage` with the matching attendees and their total count,
+/// or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn fetch_attendee_page(
+    data: GetAttendeeListData,
+    pool: &SqlitePool
+) -> Result<AttendeePage, sqlx::Error> {
+    let total = sqlx::query_scalar!(
+        "SELECT COUNT(*)
+         FROM attendees
+         WHERE event_id = ?1
+           AND (?2 IS NULL OR registration_date >= ?2)
+           AND (?3 IS NULL OR registration_date <= ?3)
+           AND (?4 IS NULL OR ticket_type = ?4)",
+        data.event_id,
+        data.from,
+        data.to,
+        data.ticket_type
+    )
+        .fetch_one(pool)
+        .await?;
+
+    let attendees = sqlx::query_as!(
+        Attendee,
+        "SELECT id, event_id, name, email, ticket_type, registration_date, checked_in, checked_in_at, confirmed
+         FROM attendees
+         WHERE event_id = ?1
+           AND (?2 IS NULL OR registration_date >= ?2)
+           AND (?3 IS NULL OR registration_date <= ?3)
+           AND (?4 IS NULL OR ticket_type = ?4)
+         ORDER BY id
+         LIMIT COALESCE(?5, -1) OFFSET ?6",
+        data.event_id,
+        data.from,
+        data.to,
+        data.ticket_type,
+        data.limit,
+        data.offset
+    )
+        .fetch_all(pool)
+        .await?;
+
+    Ok(AttendeePage {
+        attendees,
+        total,
+        limit: data.limit,
+        offset: data.offset,
+    })
+}
+
+
+/// Inserts attendees exactly as given, keeping their registration, confirmation and check-in state.
+///
+/// Used when importing an event; regular registrations go through `create_attendee`.
+///
+/// # Arguments
+///
+/// * `data` - A vector of `Attendee` structs containing the attendees to insert.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` containing the inserted attendees, or an `sqlx::Error` if any insert fails.
+///
+/// # Errors
+///
+/// Returns an error if any of the insert queries fail during execution.
+pub async fn import_attendees(
+    data: Vec<Attendee>,
+    conn: &mut SqliteConnection
+) -> Result<Vec<Attendee>, sqlx::Error> {
+    debug!(target: "attendee", "Importing {} attendee(s)", data.len());
+
+    let mut attendees = Vec::new();
+
+    for attendee in data {
+        let rec = sqlx::query_as!(
+            Attendee,
+            "INSERT INTO attendees (event_id, name, email, ticket_type, registration_date, checked_in, checked_in_at, confirmed)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+             RETURNING id, event_id, name, email, ticket_type, registration_date, checked_in, checked_in_at, confirmed",
+            attendee.event_id, attendee.name, attendee.email, attendee.ticket_type, attendee.registration_date,
+            attendee.checked_in, attendee.checked_in_at, attendee.confirmed
+        )
+            .fetch_one(&mut *conn)
+            .await?;
+
+        attendees.push(rec);
+    };
+
+    Ok(attendees)
+}
+
+
+/// Counts the registered, confirmed and checked-in attendees of an event.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the `AttendeeCountUpdate`, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn fetch_attendee_count_update(
+    data: GetAttendeeData,
+    pool: &SqlitePool
+) -> Result<AttendeeCountUpdate, sqlx::Error> {
+    sqlx::query_as!(
+        AttendeeCountUpdate,
+        r#"SELECT ?1 AS "event_id!: i64",
+                  COUNT(*) AS "registered!: i64",
+                  COALESCE(SUM(confirmed), 0) AS "confirmed!: i64",
+                  COALESCE(SUM(confirmed AND checked_in), 0) AS "checked_in!: i64"
+           FROM attendees
+           WHERE event_id = ?1"#,
+        data.event_id
+    )
+        .fetch_one(pool)
+        .await
+}
+
+
+/// Checks in every attendee of an event that has not yet checked in.
+///
+/// Attendees that are already checked in keep their original `checked_in_at`, and
+/// unconfirmed registrations are skipped. The event's `attendees` count grows by the number
+/// of attendees checked in, in the same transaction.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing a `CheckInResult` with the number of attendees checked in,
+/// or an `sqlx::Error` if the update fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn check_in_all_attendees(
+    data: GetAttendeeData,
+    pool: &SqlitePool
+) -> Result<CheckInResult, sqlx::Error> {
+    debug!(target: "attendee", "Checking in all attendees of event {}", data.event_id);
+
+    let event_id = data.event_id;
+
+    let mut tx = pool.begin().await?;
+
+    let result = sqlx::query!(
+        "UPDATE attendees
+         SET checked_in = 1, checked_in_at = CURRENT_TIMESTAMP
+         WHERE event_id = ? AND checked_in = 0 AND confirmed = 1",
+        event_id
+    )
+        .execute(&mut *tx)
+        .await?;
+
+    let checked_in = result.rows_affected() as i64;
+
+    sqlx::query!(
+        "UPDATE events SET attendees = attendees + ? WHERE id = ?",
+        checked_in, event_id
+    )
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(CheckInResult {
+        checked_in: result.rows_affected(),
+    })
+}
+
+
+/// Flips the check-in of a confirmed attendee of an event.
+///
+/// Checking in stamps `checked_in_at` with the current time; undoing a check-in clears it.
+/// The event's `attendees` count follows the check-in in the same transaction.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id` and `attendee_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the updated `Attendee`.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if the event has no confirmed attendee with the ID, or an
+/// error if the query fails.
+pub async fn toggle_attendee_check_in(
+    data: ToggleCheckInData,
+    pool: &SqlitePool
+) -> Result<Attendee, sqlx::Error> {
+    debug!(target: "attendee", "Toggling check-in of attendee {} of event {}", data.attendee_id, data.event_id);
+
+    let mut tx = pool.begin().await?;
+
+    let attendee = sqlx::query_as!(
+        Attendee,
+        "UPDATE attendees
+         SET checked_in = 1 - checked_in,
+             checked_in_at = CASE WHEN checked_in = 0 THEN CURRENT_TIMESTAMP ELSE NULL END
+         WHERE id = ? AND event_id = ? AND confirmed = 1
+         RETURNING id, event_id, name, email, ticket_type, registration_date, checked_in, checked_in_at, confirmed",
+        data.attendee_id, data.event_id
+    )
+        .fetch_one(&mut *tx)
+        .await?;
+
+    let delta: i64 = if attendee.checked_in == 1 { 1 } else { -1 };
+
+    sqlx::query!(
+        "UPDATE events SET attendees = MAX(attendees + ?, 0) WHERE id = ?",
+        delta, data.event_id
+    )
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(attendee)
+}
+
+
+/// Creates an unconfirmed attendee for an event that is open for registration.
+///
+/// The attendee does not count towards `tickets_sold` until confirmed.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the event ID, attendee details and confirmation token.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the new `Attendee`, or `None` if the event does not exist, is not
+/// upcoming and approved, or its registration deadline has passed.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn create_attendee(
+    data: CreateAttendeeData,
+    pool: &SqlitePool
+) -> Result<Option<Attendee>, sqlx::Error> {
+    debug!(target: "attendee", "Registering unconfirmed attendee for event {} (ticket type '{}')", data.event_id, data.ticket_type);
+
+    sqlx::query_as!(
+        Attendee,
+        "INSERT INTO attendees (event_id, name, email, ticket_type, registration_date, confirmed, confirmation_token)
+         SELECT id, ?, ?, ?, CURRENT_DATE, 0, ?
+         FROM events
+         WHERE id = ? AND status = 'upcoming' AND registration_deadline >= CURRENT_DATE AND is_template = 0 AND deleted_at IS NULL
+           AND approval_status = 'approved'
+         RETURNING id, event_id, name, email, ticket_type, registration_date, checked_in, checked_in_at, confirmed",
+        data.name, data.email, data.ticket_type, data.confirmation_token, data.event_id
+    )
+        .fetch_optional(pool)
+        .await
+}
+
+
+/// Confirms a pending registration and counts it towards the event's `tickets_sold`.
+///
+/// The confirmation token is cleared so it cannot be reused. If the event is already full,
+/// the attendee is put on its waitlist instead and stays unconfirmed until promoted.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the confirmation token.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing either the confirmed `Attendee` or the attendee's `WaitlistEntry`.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if no pending registration has the token, or an error
+/// if any query fails.
+pub async fn confirm_attendee(
+    data: ConfirmAttendeeData,
+    pool: &SqlitePool
+) -> Result<AttendeeConfirmation, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let attendee = sqlx::query_as!(
+        Attendee,
+        "SELECT id, event_id, name, email, ticket_type, registration_date, checked_in, checked_in_at, confirmed
+         FROM attendees
+         WHERE confirmation_token = ? AND confirmed = 0",
+        data.token
+    )
+        .fetch_one(&mut *tx)
+        .await?;
+
+    if !claim_seat(attendee.event_id, &mut tx).await? {
+        debug!(target: "attendee", "Event {} is full; waitlisting attendee {}", attendee.event_id, attendee.id);
+
+        let entry = add_to_waitlist(AddToWaitlistData { event_id: attendee.event_id, attendee_id: attendee.id }, &mut tx).await?;
+
+        tx.commit().await?;
+
+        return Ok(AttendeeConfirmation::Waitlisted(entry));
+    }
+
+    mark_confirmed(attendee.id, &mut tx).await?;
+
+    tx.commit().await?;
+
+    debug!(target: "attendee", "Confirmed attendee {} for event {}", attendee.id, attendee.event_id);
+
+    Ok(AttendeeConfirmation::Confirmed(Attendee { confirmed: 1, ..attendee }))
+}
+
+
+/// Puts an unconfirmed attendee at the end of an event's waitlist.
+///
+/// The attendee's confirmation token is cleared, since a waitlisted attendee is confirmed
+/// only by promotion.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id` and `attendee_id`.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` containing the new `WaitlistEntry`, or an `sqlx::Error` if the insert fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails or the attendee is already waitlisted.
+pub async fn add_to_waitlist(
+    data: AddToWaitlistData,
+    conn: &mut SqliteConnection
+) -> Result<WaitlistEntry, sqlx::Error> {
+    sqlx::query!(
+        "UPDATE attendees SET confirmation_token = NULL WHERE id = ?",
+        data.attendee_id
+    )
+        .execute(&mut *conn)
+        .await?;
+
+    let entry_id = sqlx::query_scalar!(
+        "INSERT INTO waitlist (event_id, attendee_id) VALUES (?, ?) RETURNING id",
+        data.event_id, data.attendee_id
+    )
+        .fetch_one(&mut *conn)
+        .await?;
+
+    sqlx::query_as!(
+        WaitlistEntry,
+        r#"SELECT w.id, w.event_id, w.attendee_id, a.name, a.email, a.ticket_type,
+                  (SELECT COUNT(*) FROM waitlist p WHERE p.event_id = w.event_id AND p.id <= w.id) AS "position!: i64",
+                  w.created_at
+           FROM waitlist w
+           JOIN attendees a ON a.id = w.attendee_id
+           WHERE w.id = ?"#,
+        entry_id
+    )
+        .fetch_one(&mut *conn)
+        .await
+}
+
+
+/// Retrieves the waitlist of an event, first in line first.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the event's `WaitlistEntry` list, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn fetch_waitlist(
+    data: GetAttendeeData,
+    pool: &SqlitePool
+) -> Result<Vec<WaitlistEntry>, sqlx::Error> {
+    sqlx::query_as!(
+        WaitlistEntry,
+        r#"SELECT w.id, w.event_id, w.attendee_id, a.name, a.email, a.ticket_type,
+                  ROW_NUMBER() OVER (ORDER BY w.id) AS "position!: i64",
+                  w.created_at
+           FROM waitlist w
+           JOIN attendees a ON a.id = w.attendee_id
+           WHERE w.event_id = ?
+           ORDER BY w.id"#,
+        data.event_id
+    )
+        .fetch_all(pool)
+        .await
+}
+
+
+/// Gives a free seat of an event to the first attendee on its waitlist.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id`.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` containing the promoted, now confirmed `Attendee`, or `None` if nobody is waiting
+/// or the event has no free seat.
+///
+/// # Errors
+///
+/// Returns an error if any query fails.
+pub async fn promote_from_waitlist(
+    data: GetAttendeeData,
+    conn: &mut SqliteConnection
+) -> Result<Option<Attendee>, sqlx::Error> {
+    let next = sqlx::query!(
+        "SELECT id, attendee_id FROM waitlist WHERE event_id = ? ORDER BY id LIMIT 1",
+        data.event_id
+    )
+        .fetch_optional(&mut *conn)
+        .await?;
+
+    let Some(next) = next else {
+        return Ok(None);
+    };
+
+    if !claim_seat(data.event_id, &mut *conn).await? {
+        return Ok(None);
+    }
+
+    sqlx::query!("DELETE FROM waitlist WHERE id = ?", next.id)
+        .execute(&mut *conn)
+        .await?;
+
+    mark_confirmed(next.attendee_id, &mut *conn).await?;
+
+    debug!(target: "attendee", "Promoted attendee {} from the waitlist of event {}", next.attendee_id, data.event_id);
+
+    sqlx::query_as!(
+        Attendee,
+        "SELECT id, event_id, name, email, ticket_type, registration_date, checked_in, checked_in_at, confirmed
+         FROM attendees
+         WHERE id = ?",
+        next.attendee_id
+    )
+        .fetch_optional(&mut *conn)
+        .await
+}
+
+
+/// Deletes an attendee's registration and gives a freed seat to the waitlist.
+///
+/// A confirmed attendee's seat is released from `tickets_sold` (and from `attendees` if they had
+/// checked in) and taken by the first waitlisted attendee, if any. A waitlisted attendee simply
+/// leaves the waitlist.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id` and `attendee_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the `AttendeeCancellation`, or an `sqlx::Error` if any query fails.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if the event has no attendee with the ID, or an error if
+/// any query fails.
+pub async fn delete_attendee(
+    data: DeleteAttendeeData,
+    pool: &SqlitePool
+) -> Result<AttendeeCancellation, sqlx::Error> {
+    debug!(target: "attendee", "Deleting attendee {} of event {}", data.attendee_id, data.event_id);
+
+    let mut tx = pool.begin().await?;
+
+    let attendee = sqlx::query!(
+        "SELECT confirmed, checked_in FROM attendees WHERE id = ? AND event_id = ?",
+        data.attendee_id, data.event_id
+    )
+        .fetch_one(&mut *tx)
+        .await?;
+
+    sqlx::query!("DELETE FROM waitlist WHERE attendee_id = ?", data.attendee_id)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query!("DELETE FROM attendees WHERE id = ?", data.attendee_id)
+        .execute(&mut *tx)
+        .await?;
+
+    let promoted = if attendee.confirmed == 1 {
+        sqlx::query!(
+            "UPDATE events
+             SET tickets_sold = MAX(tickets_sold - 1, 0), attendees = MAX(attendees - ?, 0)
+             WHERE id = ?",
+            attendee.checked_in, data.event_id
+        )
+            .execute(&mut *tx)
+            .await?;
+
+        promote_from_waitlist(GetAttendeeData { event_id: data.event_id }, &mut tx).await?
+    } else {
+        None
+    };
+
+    tx.commit().await?;
+
+    Ok(AttendeeCancellation { attendee_id: data.attendee_id, promoted })
+}
+
+
+/// Takes a seat of an event by counting one more ticket sold, if the event is not full.
+async fn claim_seat(
+    event_id: i64,
+    conn: &mut SqliteConnection
+) -> Result<bool, sqlx::Error> {
+    let seat = sqlx::query!(
+        "UPDATE events
+         SET tickets_sold = tickets_sold + 1
+         WHERE id = ? AND tickets_sold < max_attendees",
+        event_id
+    )
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(seat.rows_affected() > 0)
+}
+
+
+/// Marks an attendee as confirmed and clears their confirmation token.
+async fn mark_confirmed(
+    attendee_id: i64,
+    conn: &mut SqliteConnection
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "UPDATE attendees
+         SET confirmed = 1, confirmation_token = NULL
+         WHERE id = ?",
+        attendee_id
+    )
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
+}
+
+
+/// Deletes unconfirmed registrations older than the given number of days.
+///
+/// Waitlisted attendees are kept, since they stay unconfirmed until promoted.
+///
+/// # Arguments
+///
+/// * `max_age_days` - Number of days an unconfirmed registration is kept.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the number of deleted registrations.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn purge_unconfirmed_attendees(
+    max_age_days: i64,
+    pool: &SqlitePool
+) -> Result<u64, sqlx::Error> {
+    let cutoff = format!("-{} days", max_age_days);
+
+    let result = sqlx::query!(
+        "DELETE FROM attendees
+         WHERE confirmed = 0 AND registration_date < date('now', ?)
+           AND id NOT IN (SELECT attendee_id FROM waitlist)",
+        cutoff
+    )
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected())
 }
\ No newline at end of file
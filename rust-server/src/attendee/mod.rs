@@ -2,3 +2,5 @@
 pub mod mapper;
 pub mod models;
 pub mod routes;
+pub mod services;
+pub mod validation;
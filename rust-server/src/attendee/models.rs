@@ -1,6 +1,6 @@
 // External Libraries
 use serde::{Serialize, Deserialize};
-use chrono::{NaiveDate};
+use chrono::{NaiveDate, NaiveDateTime};
 use crate::event::models::Event;
 use crate::overview::models::CountByDate;
 
@@ -24,6 +24,15 @@ pub struct Attendee {
     
     /// Attendee registration date
     pub registration_date: NaiveDate,
+
+    /// Flag indicating whether the attendee has checked in.
+    pub checked_in: i64,
+
+    /// Timestamp for when the attendee checked in.
+    pub checked_in_at: Option<NaiveDateTime>,
+
+    /// Flag indicating whether the attendee has confirmed their registration.
+    pub confirmed: i64,
 }
 
 
@@ -35,6 +44,244 @@ pub struct GetAttendeeData {
 }
 
 
+/// Data required to toggle the check-in of a single attendee.
+pub struct ToggleCheckInData {
+    /// Unique identifier of the event the attendee registered for.
+    pub event_id: i64,
+
+    /// Unique identifier of the attendee.
+    pub attendee_id: i64,
+}
+
+
+/// Query parameters filtering and paginating an event's attendees.
+#[derive(Deserialize)]
+pub struct AttendeeListQuery {
+    /// Earliest registration date to include; open-ended when omitted.
+    pub from: Option<NaiveDate>,
+
+    /// Latest registration date to include; open-ended when omitted.
+    pub to: Option<NaiveDate>,
+
+    /// Ticket type to restrict the attendees to, e.g. `VIP`; all types when omitted.
+    pub ticket_type: Option<String>,
+
+    /// Maximum number of attendees to return; all remaining attendees when omitted.
+    pub limit: Option<i64>,
+
+    /// Number of attendees to skip.
+    pub offset: Option<i64>,
+}
+
+
+/// Data required to retrieve a filtered page of an event's attendees.
+pub struct GetAttendeeListData {
+    /// Unique identifier of the event to retrieve attendees for.
+    pub event_id: i64,
+
+    /// Earliest registration date to include, if any.
+    pub from: Option<NaiveDate>,
+
+    /// Latest registration date to include, if any.
+    pub to: Option<NaiveDate>,
+
+    /// Ticket type to restrict the attendees to, if any.
+    pub ticket_type: Option<String>,
+
+    /// Maximum number of attendees to return, or `None` for no limit.
+    pub limit: Option<i64>,
+
+    /// Number of attendees to skip.
+    pub offset: i64,
+}
+
+
+/// Represents a page of an event's attendees.
+#[derive(Serialize)]
+pub struct AttendeePage {
+    /// Attendees in the requested page.
+    pub attendees: Vec<Attendee>,
+
+    /// Total number of attendees matching the filters across all pages.
+    pub total: i64,
+
+    /// Maximum number of attendees returned in this page, or `None` if unlimited.
+    pub limit: Option<i64>,
+
+    /// Number of attendees skipped before this page.
+    pub offset: i64,
+}
+
+
+/// Live attendee counts of an event, pushed to WebSocket subscribers whenever they change.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct AttendeeCountUpdate {
+    /// Unique identifier of the event.
+    pub event_id: i64,
+
+    /// Number of registrations, including unconfirmed ones.
+    pub registered: i64,
+
+    /// Number of confirmed attendees.
+    pub confirmed: i64,
+
+    /// Number of confirmed attendees that have checked in.
+    pub checked_in: i64,
+}
+
+
+/// Request body for registering an attendee for an event.
+#[derive(Deserialize)]
+pub struct AttendeeRegistrationData {
+    /// Name of the attendee.
+    pub name: String,
+
+    /// Email of the attendee.
+    pub email: String,
+
+    /// Ticket type requested by the attendee.
+    pub ticket_type: String,
+}
+
+
+/// Data required to create an unconfirmed attendee.
+pub struct CreateAttendeeData {
+    /// Unique identifier of the event to register for.
+    pub event_id: i64,
+
+    /// Name of the attendee.
+    pub name: String,
+
+    /// Email of the attendee.
+    pub email: String,
+
+    /// Ticket type requested by the attendee.
+    pub ticket_type: String,
+
+    /// Token the attendee must present to confirm the registration.
+    pub confirmation_token: String,
+}
+
+
+/// Represents a pending registration awaiting confirmation.
+///
+/// The confirmation token is only ever emailed to the attendee, never returned to the client.
+#[derive(Serialize)]
+pub struct AttendeeRegistration {
+    /// Unique identifier of the unconfirmed attendee.
+    pub attendee_id: i64,
+}
+
+
+/// Settings for the live attendee count WebSocket.
+#[derive(Clone)]
+pub struct LiveCountsConfig {
+    /// The only `Origin` allowed to open the WebSocket, i.e. the frontend URL.
+    pub allowed_origin: String,
+}
+
+
+/// Settings for the confirmation emails sent on registration.
+#[derive(Clone)]
+pub struct RegistrationConfig {
+    /// URL of the confirmation endpoint; the token is appended as the `token` query parameter.
+    pub confirm_url: String,
+}
+
+
+/// Query parameters for confirming a registration.
+#[derive(Deserialize)]
+pub struct ConfirmAttendeeQuery {
+    /// Confirmation token issued at registration.
+    pub token: String,
+}
+
+
+/// Data required to confirm a registration.
+pub struct ConfirmAttendeeData {
+    /// Confirmation token issued at registration.
+    pub token: String,
+}
+
+
+/// Represents the outcome of confirming a registration.
+pub enum AttendeeConfirmation {
+    /// The attendee got a seat and counts towards `tickets_sold`.
+    Confirmed(Attendee),
+
+    /// The event was full, so the attendee was put on its waitlist.
+    Waitlisted(WaitlistEntry),
+}
+
+
+/// Represents an attendee waiting for a seat at a full event.
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct WaitlistEntry {
+    /// Unique identifier of the waitlist entry.
+    pub id: i64,
+
+    /// Unique identifier of the event waited for.
+    pub event_id: i64,
+
+    /// Unique identifier of the waiting, still unconfirmed attendee.
+    pub attendee_id: i64,
+
+    /// Name of the attendee.
+    pub name: String,
+
+    /// Email of the attendee.
+    pub email: String,
+
+    /// Ticket type requested by the attendee.
+    pub ticket_type: String,
+
+    /// One-based position in the event's waitlist; the first entry is promoted next.
+    pub position: i64,
+
+    /// Timestamp for when the attendee joined the waitlist.
+    pub created_at: NaiveDateTime,
+}
+
+
+/// Data required to put an attendee on an event's waitlist.
+pub struct AddToWaitlistData {
+    /// Unique identifier of the event waited for.
+    pub event_id: i64,
+
+    /// Unique identifier of the unconfirmed attendee.
+    pub attendee_id: i64,
+}
+
+
+/// Data required to delete an attendee's registration.
+pub struct DeleteAttendeeData {
+    /// Unique identifier of the event the attendee registered for.
+    pub event_id: i64,
+
+    /// Unique identifier of the attendee.
+    pub attendee_id: i64,
+}
+
+
+/// Represents a canceled registration and the waitlisted attendee promoted into its seat.
+#[derive(Serialize)]
+pub struct AttendeeCancellation {
+    /// Unique identifier of the canceled attendee.
+    pub attendee_id: i64,
+
+    /// Attendee promoted from the waitlist, if a seat was freed and someone was waiting.
+    pub promoted: Option<Attendee>,
+}
+
+
+/// Represents the result of a bulk check-in.
+#[derive(Serialize)]
+pub struct CheckInResult {
+    /// Number of attendees newly checked in.
+    pub checked_in: u64,
+}
+
+
 /// Represents aggregated totals for attendee metrics for a given year.
 #[derive(Serialize)]
 pub struct AttendeeTotals {
@@ -65,7 +312,26 @@ pub struct AttendeeCounts {
 }
 
 
+/// Represents the no-shows of a single past event.
+#[derive(Serialize)]
+pub struct EventNoShows {
+    /// Unique identifier of the event.
+    pub event_id: i64,
+
+    /// Number of no-shows computed as `tickets_sold - attendees`.
+    pub no_show_count: i64,
+
+    /// Whether any attendee of the event was checked in, i.e. whether `attendees` is meaningful.
+    pub check_in_recorded: bool,
+
+    /// Confirmed attendees who did not check in; empty when no check-ins were recorded.
+    pub attendees: Vec<Attendee>,
+}
+
+
 /// Represents aggregated totals for no show metrics for a given year.
+///
+/// A no-show is a confirmed attendee of a past event who was not checked in.
 #[derive(Serialize)]
 pub struct NoShowTotals {
     /// Monthly totals of no shows.
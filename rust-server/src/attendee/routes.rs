@@ -1,5 +1,7 @@
 // External Libraries
-use actix_web::{web, Responder, HttpResponse, HttpRequest};
+use actix_web::{web, rt, http::header, Responder, HttpResponse, HttpResponseBuilder, HttpRequest, ResponseError};
+use chrono::Utc;
+use log::{info, warn};
 use sqlx::SqlitePool;
 
 // Internal Mappers
@@ -8,25 +10,60 @@ use crate::attendee::mapper::{
     fetch_daily_attendee_counts,
     fetch_attendance_extremes,
     fetch_monthly_no_shows,
-    fetch_monthly_attendees_by_ticket_type,
-    fetch_attendees_by_event
+    fetch_ticket_type_totals,
+    fetch_attendees_by_event,
+    fetch_attendee_page,
+    check_in_all_attendees,
+    toggle_attendee_check_in,
+    create_attendee,
+    confirm_attendee,
+    fetch_waitlist,
+    delete_attendee,
+    fetch_attendee_count_update
 };
-use crate::event::mapper::{fetch_event};
+use crate::event::mapper::{fetch_event, fetch_capacity_warning};
 
 // Internal Models
 use crate::attendee::models::{
     GetAttendeeData,
+    ToggleCheckInData,
+    AttendeeListQuery,
+    GetAttendeeListData,
     AttendeeTotals,
     AttendanceExtremes,
     AttendeeCounts,
     NoShowTotals,
-    TicketTypeTotals
+    EventNoShows,
+    TicketTypeTotals,
+    AttendeeRegistrationData,
+    CreateAttendeeData,
+    AttendeeRegistration,
+    RegistrationConfig,
+    LiveCountsConfig,
+    ConfirmAttendeeQuery,
+    ConfirmAttendeeData,
+    AttendeeConfirmation,
+    DeleteAttendeeData
 };
-use crate::event::models::{GetEventData};
+use crate::event::models::{GetEventData, CapacityWarningConfig};
 use crate::overview::models::{YearQuery, GetOverview};
+use crate::mailer::models::Email;
 
 // Internal Services
-use crate::auth::services::validate_session;
+use crate::attendee::services::{AttendeeCountBroadcaster, publish_attendee_counts, stream_attendee_counts};
+use crate::audit::services::record_session_audit;
+use crate::auth::services::{validate_session, generate_session_token};
+use crate::mailer::services::Mailer;
+
+// Internal Routes
+use crate::event::routes::{CAPACITY_WARNING_HEADER, MAX_PAGE_LIMIT};
+
+// Internal Validation
+use crate::attendee::validation::validate_attendee_registration;
+use crate::validation::ValidationErrors;
+
+// Internal Errors
+use crate::error::{lookup_error_response, AppError};
 
 
 /// Retrieves aggregated attendee data including monthly attendees and total attendees
@@ -48,7 +85,7 @@ pub async fn get_monthly_attendees(
 ) -> impl Responder {
     let session = match validate_session(&req, &pool).await {
         Ok(session) => session,
-        Err(response) => return response,
+        Err(e) => return e.error_response(),
     };
 
     let year = query.year;
@@ -56,7 +93,7 @@ pub async fn get_monthly_attendees(
 
     match fetch_monthly_attendees(GetOverview {organizer_id, year}, &pool).await {
         Ok(totals) => HttpResponse::Ok().json(AttendeeTotals {..totals}),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Failed to fetch monthly attendee data: {}", e)),
+        Err(e) => AppError::Internal(format!("Failed to fetch monthly attendee data: {}", e)).error_response(),
     }
 }
 
@@ -79,7 +116,7 @@ pub async fn get_daily_attendee_counts(
 ) -> impl Responder {
     let session = match validate_session(&req, &pool).await {
         Ok(session) => session,
-        Err(response) => return response,
+        Err(e) => return e.error_response(),
     };
 
     let year = query.year;
@@ -87,7 +124,7 @@ pub async fn get_daily_attendee_counts(
 
     match fetch_daily_attendee_counts(GetOverview {organizer_id, year}, &pool).await {
         Ok(totals) => HttpResponse::Ok().json(AttendeeCounts {..totals}),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Failed to fetch daily attendee counts: {}", e)),
+        Err(e) => AppError::Internal(format!("Failed to fetch daily attendee counts: {}", e)).error_response(),
     }
 }
 
@@ -111,7 +148,7 @@ pub async fn get_attendance_extremes(
 ) -> impl Responder {
     let session = match validate_session(&req, &pool).await {
         Ok(session) => session,
-        Err(response) => return response,
+        Err(e) => return e.error_response(),
     };
 
     let year = query.year;
@@ -119,7 +156,7 @@ pub async fn get_attendance_extremes(
 
     match fetch_attendance_extremes(GetOverview {organizer_id, year}, &pool).await {
         Ok(totals) => HttpResponse::Ok().json(AttendanceExtremes {..totals}),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Failed to fetch attendance extremes: {}", e)),
+        Err(e) => AppError::Internal(format!("Failed to fetch attendance extremes: {}", e)).error_response(),
     }
 }
 
@@ -143,7 +180,7 @@ pub async fn get_monthly_no_shows(
 ) -> impl Responder {
     let session = match validate_session(&req, &pool).await {
         Ok(session) => session,
-        Err(response) => return response,
+        Err(e) => return e.error_response(),
     };
     
     let year = query.year;
@@ -151,7 +188,7 @@ pub async fn get_monthly_no_shows(
     
     match fetch_monthly_no_shows(GetOverview {organizer_id, year}, &pool).await {
         Ok(totals) => HttpResponse::Ok().json(NoShowTotals {..totals}),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Failed to fetch monthly no show rates: {}", e)),
+        Err(e) => AppError::Internal(format!("Failed to fetch monthly no show rates: {}", e)).error_response(),
     }
 }
 
@@ -175,15 +212,15 @@ pub async fn get_monthly_attendees_by_ticket_type(
 ) -> impl Responder {
     let session = match validate_session(&req, &pool).await {
         Ok(session) => session,
-        Err(response) => return response,
+        Err(e) => return e.error_response(),
     };
 
     let year = query.year;
     let organizer_id = session.user_id;
 
-    match fetch_monthly_attendees_by_ticket_type(GetOverview {organizer_id, year}, &pool).await {
+    match fetch_ticket_type_totals(GetOverview {organizer_id, year}, &pool).await {
         Ok(totals) => HttpResponse::Ok().json(TicketTypeTotals {..totals}),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Failed to fetch monthly ticket type totals: {}", e)),
+        Err(e) => AppError::Internal(format!("Failed to fetch monthly ticket type totals: {}", e)).error_response(),
     }
 }
 
@@ -194,33 +231,457 @@ pub async fn get_monthly_attendees_by_ticket_type(
 ///
 /// * `req` - The incoming HTTP request containing session data.
 /// * `event_id` - The path parameter representing the event's ID.
+/// * `query` - Optional `from` and `to` dates (`YYYY-MM-DD`, inclusive) bounding the registration date,
+///   an optional `ticket_type`, and optional `limit` and `offset` for pagination.
 /// * `pool` - The SQLite database connection pool.
 ///
 /// # Returns
 ///
-/// An HTTP response with the attendee information if found, or an error message.
+/// An HTTP response with a page of the matching attendees and their total count, `400 Bad Request`
+/// if `from` is after `to`, or an error message. Without a `limit`, every matching attendee is returned.
 pub async fn get_attendees_by_event(
     req: HttpRequest,
     event_id: web::Path<i64>,
+    query: web::Query<AttendeeListQuery>,
     pool: web::Data<SqlitePool>,
 ) -> impl Responder {
     let session = match validate_session(&req, &pool).await {
         Ok(session) => session,
-        Err(response) => return response,
+        Err(e) => return e.error_response(),
     };
 
+    let AttendeeListQuery { from, to, ticket_type, limit, offset } = query.into_inner();
+    if let (Some(from), Some(to)) = (from, to) && from > to {
+        return ValidationErrors::single("from", format!("must be on or before to ({})", to)).error_response();
+    }
+
     let event = match fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await {
         Ok(event) => event,
-        Err(e) => return HttpResponse::InternalServerError().body(format!("Event not found: {}", e)),
+        Err(e) => return lookup_error_response("Event", e),
+    };
+
+    let limit = limit.map(|limit| limit.clamp(1, MAX_PAGE_LIMIT));
+    let offset = offset.unwrap_or(0).max(0);
+
+    match fetch_attendee_page(GetAttendeeListData {event_id: event.id, from, to, ticket_type, limit, offset}, &pool).await {
+        Ok(page) => HttpResponse::Ok().json(page),
+        Err(e) => AppError::Internal(format!("Failed to fetch attendees: {}", e)).error_response(),
+    }
+}
+
+
+/// Handles checking in every attendee of a specific event, ensuring the organizer owns the event.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `pool` - The SQLite database connection pool.
+/// * `broadcaster` - Publishes the new attendee counts to live subscribers.
+///
+/// # Returns
+///
+/// An HTTP response with the number of attendees checked in, or an error message.
+pub async fn check_in_all(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    pool: web::Data<SqlitePool>,
+    broadcaster: web::Data<AttendeeCountBroadcaster>,
+) -> impl Responder {
+    let session = match validate_session(&req, &pool).await {
+        Ok(session) => session,
+        Err(e) => return e.error_response(),
+    };
+
+    let event = match fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await {
+        Ok(event) => event,
+        Err(e) => return lookup_error_response("Event", e),
+    };
+
+    match check_in_all_attendees(GetAttendeeData {event_id: event.id}, &pool).await {
+        Ok(result) => {
+            record_session_audit(&session, "event", event.id, "check_in_all", Some(format!("Checked in {} attendees", result.checked_in)), &pool).await;
+            publish_attendee_counts(event.id, &broadcaster, &pool).await;
+            HttpResponse::Ok().json(result)
+        },
+        Err(e) => AppError::Internal(format!("Failed to check in attendees: {}", e)).error_response(),
+    }
+}
+
+
+/// Handles checking in a single attendee, or undoing their check-in, ensuring the organizer owns the event.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `path` - The path parameters representing the event and attendee IDs.
+/// * `pool` - The SQLite database connection pool.
+/// * `broadcaster` - Publishes the new attendee counts to live subscribers.
+///
+/// # Returns
+///
+/// An HTTP response with the updated attendee, or `404 Not Found` if the organizer does not own
+/// the event or it has no confirmed attendee with the ID.
+pub async fn toggle_check_in(
+    req: HttpRequest,
+    path: web::Path<(i64, i64)>,
+    pool: web::Data<SqlitePool>,
+    broadcaster: web::Data<AttendeeCountBroadcaster>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let (event_id, attendee_id) = path.into_inner();
+
+    fetch_event(GetEventData {event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    let attendee = toggle_attendee_check_in(ToggleCheckInData {event_id, attendee_id}, &pool).await
+        .map_err(|e| AppError::lookup("Attendee", e))?;
+
+    let action = if attendee.checked_in == 1 { "check_in" } else { "undo_check_in" };
+    info!(target: "attendee", "Organizer {} toggled check-in of attendee {} of event {} ({})", session.user_id, attendee_id, event_id, action);
+    record_session_audit(&session, "event", event_id, action, Some(format!("Attendee {}", attendee_id)), &pool).await;
+    publish_attendee_counts(event_id, &broadcaster, &pool).await;
+
+    Ok(HttpResponse::Ok().json(attendee))
+}
+
+
+/// Registers an attendee for an upcoming event, pending confirmation.
+///
+/// The registration does not count towards the event's capacity until it is confirmed
+/// through `GET /attendees/confirm/?token=...`. The confirmation link is emailed to the
+/// attendee, so only the owner of the address can confirm it.
+///
+/// # Arguments
+///
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `data` - A JSON object containing the attendee's name, email and ticket type.
+/// * `pool` - A reference to the SQLite database connection pool.
+/// * `broadcaster` - Publishes the new attendee counts to live subscribers.
+/// * `mailer` - Delivers the confirmation email.
+/// * `registration_config` - Where the confirmation link points.
+/// * `capacity_config` - Settings for the capacity warning header.
+///
+/// # Returns
+///
+/// A JSON response containing the attendee ID and an `X-Capacity-Warning` header if the event is
+/// nearly full or full, `400 Bad Request` listing each invalid field,
+/// `404 Not Found` if the event is not open for registration, or an error message if the
+/// operation fails.
+pub async fn register_attendee(
+    event_id: web::Path<i64>,
+    data: web::Json<AttendeeRegistrationData>,
+    pool: web::Data<SqlitePool>,
+    broadcaster: web::Data<AttendeeCountBroadcaster>,
+    mailer: web::Data<dyn Mailer>,
+    registration_config: web::Data<RegistrationConfig>,
+    capacity_config: web::Data<CapacityWarningConfig>,
+) -> impl Responder {
+    let mut data = data.into_inner();
+    data.name = data.name.trim().to_string();
+    data.email = data.email.trim().to_string();
+    data.ticket_type = data.ticket_type.trim().to_string();
+
+    if let Err(errors) = validate_attendee_registration(&data) {
+        return errors.error_response();
+    }
+
+    let AttendeeRegistrationData { name, email, ticket_type } = data;
+    let confirmation_token = generate_session_token();
+
+    let create_data = CreateAttendeeData {
+        event_id: *event_id,
+        name: name.clone(),
+        email: email.clone(),
+        ticket_type,
+        confirmation_token: confirmation_token.clone(),
     };
 
-    match fetch_attendees_by_event(GetAttendeeData {event_id: event.id}, &pool).await {
-        Ok(attendees) => HttpResponse::Ok().json(attendees),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Attendees not found: {}", e)),
+    match create_attendee(create_data, &pool).await {
+        Ok(Some(attendee)) => {
+            mailer.send(Email {
+                to: email,
+                subject: "Confirm your registration".to_string(),
+                body: format!(
+                    "Hi {},\n\nPlease confirm your registration by opening this link:\n{}?token={}\n",
+                    name, registration_config.confirm_url, confirmation_token
+                ),
+            });
+            publish_attendee_counts(attendee.event_id, &broadcaster, &pool).await;
+            let mut response = HttpResponse::Ok();
+            insert_capacity_warning(&mut response, attendee.event_id, &capacity_config, &pool).await;
+            response.json(AttendeeRegistration {
+                attendee_id: attendee.id,
+            })
+        },
+        Ok(None) => AppError::NotFound("Event is not open for registration".to_string()).error_response(),
+        Err(e) => AppError::Internal(format!("Failed to register attendee: {}", e)).error_response(),
     }
 }
 
 
+/// Confirms a pending registration by its confirmation token.
+///
+/// # Arguments
+///
+/// * `query` - Query parameters containing the confirmation `token`.
+/// * `pool` - A reference to the SQLite database connection pool.
+/// * `broadcaster` - Publishes the new attendee counts to live subscribers.
+/// * `capacity_config` - Settings for the capacity warning header.
+///
+/// # Returns
+///
+/// A JSON response containing the confirmed attendee, `202 Accepted` with the attendee's waitlist
+/// entry if the event is full, either with an `X-Capacity-Warning` header if the event is nearly
+/// full or full, `404 Not Found` for an unknown or used token, or an error message
+/// if the operation fails.
+pub async fn confirm_registration(
+    query: web::Query<ConfirmAttendeeQuery>,
+    pool: web::Data<SqlitePool>,
+    broadcaster: web::Data<AttendeeCountBroadcaster>,
+    capacity_config: web::Data<CapacityWarningConfig>,
+) -> impl Responder {
+    let token = query.into_inner().token;
+
+    match confirm_attendee(ConfirmAttendeeData {token}, &pool).await {
+        Ok(AttendeeConfirmation::Confirmed(attendee)) => {
+            info!(target: "attendee", "Attendee {} confirmed for event {}", attendee.id, attendee.event_id);
+            publish_attendee_counts(attendee.event_id, &broadcaster, &pool).await;
+            let mut response = HttpResponse::Ok();
+            insert_capacity_warning(&mut response, attendee.event_id, &capacity_config, &pool).await;
+            response.json(attendee)
+        },
+        Ok(AttendeeConfirmation::Waitlisted(entry)) => {
+            info!(target: "attendee", "Attendee {} waitlisted for event {} at position {}", entry.attendee_id, entry.event_id, entry.position);
+            let mut response = HttpResponse::Accepted();
+            insert_capacity_warning(&mut response, entry.event_id, &capacity_config, &pool).await;
+            response.json(entry)
+        },
+        Err(sqlx::Error::RowNotFound) => AppError::NotFound("Invalid or already used confirmation token".to_string()).error_response(),
+        Err(e) => AppError::Internal(format!("Failed to confirm registration: {}", e)).error_response(),
+    }
+}
+
+
+/// Adds the `X-Capacity-Warning` header to a response if the event is nearly full or full.
+///
+/// A failure to read the event's counts is logged and leaves the header out, since the
+/// registration itself has already succeeded.
+///
+/// # Arguments
+///
+/// * `response` - The response to add the header to.
+/// * `event_id` - Unique identifier of the event.
+/// * `config` - The capacity warning settings.
+/// * `pool` - A reference to the SQLite database connection pool.
+async fn insert_capacity_warning(
+    response: &mut HttpResponseBuilder,
+    event_id: i64,
+    config: &CapacityWarningConfig,
+    pool: &SqlitePool,
+) {
+    match fetch_capacity_warning(event_id, config, pool).await {
+        Ok(Some(warning)) => { response.insert_header((CAPACITY_WARNING_HEADER, warning.as_str())); },
+        Ok(None) => {},
+        Err(e) => warn!(target: "attendee", "Failed to read capacity of event {}: {}", event_id, e),
+    }
+}
+
+
+/// Handles retrieving the waitlist of a specific event, ensuring the organizer owns the event.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the waitlisted attendees in the order they will be promoted, or an
+/// error message.
+pub async fn get_event_waitlist(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let event = fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    let waitlist = fetch_waitlist(GetAttendeeData {event_id: event.id}, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(waitlist))
+}
+
+
+/// Handles canceling an attendee's registration, ensuring the organizer owns the event.
+///
+/// A seat freed by a confirmed attendee goes to the first attendee on the event's waitlist.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `path` - The path parameters representing the event and attendee IDs.
+/// * `pool` - The SQLite database connection pool.
+/// * `broadcaster` - Publishes the new attendee counts to live subscribers.
+///
+/// # Returns
+///
+/// An HTTP response with the canceled attendee's ID and the promoted attendee, if any, or
+/// `404 Not Found` if the organizer does not own the event or it has no attendee with the ID.
+pub async fn cancel_registration(
+    req: HttpRequest,
+    path: web::Path<(i64, i64)>,
+    pool: web::Data<SqlitePool>,
+    broadcaster: web::Data<AttendeeCountBroadcaster>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let (event_id, attendee_id) = path.into_inner();
+
+    fetch_event(GetEventData {event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    let cancellation = delete_attendee(DeleteAttendeeData {event_id, attendee_id}, &pool).await
+        .map_err(|e| AppError::lookup("Attendee", e))?;
+
+    let detail = match &cancellation.promoted {
+        Some(promoted) => format!("Canceled attendee {}; promoted attendee {} from the waitlist", attendee_id, promoted.id),
+        None => format!("Canceled attendee {}", attendee_id),
+    };
+
+    info!(target: "attendee", "Organizer {} canceled attendee {} of event {}", session.user_id, attendee_id, event_id);
+    record_session_audit(&session, "event", event_id, "cancel_attendee", Some(detail), &pool).await;
+    publish_attendee_counts(event_id, &broadcaster, &pool).await;
+
+    Ok(HttpResponse::Ok().json(cancellation))
+}
+
+
+/// Opens a WebSocket that pushes an event's attendee counts whenever they change,
+/// ensuring the organizer owns the event.
+///
+/// The session cookie is checked during the handshake. Each message is a JSON object with the
+/// event's `registered`, `confirmed` and `checked_in` counts, starting with the current counts.
+///
+/// CORS does not apply to WebSocket handshakes and the session cookie is sent cross-site, so the
+/// handshake's `Origin` must be the frontend's; otherwise any site could open the socket with
+/// the organizer's cookie.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data and the WebSocket handshake.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `body` - The request payload carrying the client's WebSocket messages.
+/// * `pool` - A reference to the SQLite database connection pool.
+/// * `broadcaster` - The source of attendee count updates.
+/// * `live_counts_config` - The origin allowed to open the WebSocket.
+///
+/// # Returns
+///
+/// The `101 Switching Protocols` handshake response, `403 Forbidden` if the handshake comes from
+/// another origin, `400 Bad Request` if the request is not a WebSocket handshake, or an error message.
+pub async fn attendee_counts_socket(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    body: web::Payload,
+    pool: web::Data<SqlitePool>,
+    broadcaster: web::Data<AttendeeCountBroadcaster>,
+    live_counts_config: web::Data<LiveCountsConfig>,
+) -> impl Responder {
+    let origin = req.headers().get(header::ORIGIN).and_then(|origin| origin.to_str().ok());
+    if origin != Some(live_counts_config.allowed_origin.trim_end_matches('/')) {
+        warn!(target: "attendee", "Rejected attendee count WebSocket from origin {:?}", origin);
+        return AppError::Forbidden("WebSocket connections are only accepted from the frontend".to_string()).error_response();
+    }
+
+    let session = match validate_session(&req, &pool).await {
+        Ok(session) => session,
+        Err(e) => return e.error_response(),
+    };
+
+    let event = match fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await {
+        Ok(event) => event,
+        Err(e) => return lookup_error_response("Event", e),
+    };
+
+    // Subscribe before counting so no change between the two is missed
+    let updates = broadcaster.subscribe();
+    let initial = match fetch_attendee_count_update(GetAttendeeData {event_id: event.id}, &pool).await {
+        Ok(initial) => initial,
+        Err(e) => return AppError::Internal(format!("Failed to count attendees: {}", e)).error_response(),
+    };
+
+    let (response, ws_session, messages) = match actix_ws::handle(&req, body) {
+        Ok(handshake) => handshake,
+        Err(e) => return AppError::BadRequest(format!("WebSocket handshake failed: {}", e)).error_response(),
+    };
+
+    info!(target: "attendee", "Organizer {} subscribed to live attendee counts of event {}", session.user_id, event.id);
+    rt::spawn(stream_attendee_counts(initial, updates, ws_session, messages));
+
+    response
+}
+
+
+/// Handles retrieving the no-shows of a specific past event, ensuring the organizer owns the event.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `pool` - A reference to the SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the computed no-show count and, when check-ins were recorded, the
+/// attendees who did not check in; `400 Bad Request` if the event has not ended yet.
+pub async fn get_event_no_shows(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    pool: web::Data<SqlitePool>,
+) -> impl Responder {
+    let session = match validate_session(&req, &pool).await {
+        Ok(session) => session,
+        Err(e) => return e.error_response(),
+    };
+
+    let event = match fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await {
+        Ok(event) => event,
+        Err(e) => return lookup_error_response("Event", e),
+    };
+
+    if event.end_date >= Utc::now().date_naive() {
+        return AppError::BadRequest("Event has not ended yet".to_string()).error_response();
+    }
+
+    let attendees = match fetch_attendees_by_event(GetAttendeeData {event_id: event.id}, &pool).await {
+        Ok(attendees) => attendees,
+        Err(e) => return AppError::Internal(format!("Failed to fetch attendees: {}", e)).error_response(),
+    };
+
+    // Without any check-in every attendee would look like a no-show, so only list them once check-ins exist
+    let check_in_recorded = attendees.iter().any(|attendee| attendee.checked_in == 1);
+    let no_shows = if check_in_recorded {
+        attendees.into_iter()
+            .filter(|attendee| attendee.confirmed == 1 && attendee.checked_in == 0)
+            .collect()
+    } else {
+        vec![]
+    };
+
+    HttpResponse::Ok().json(EventNoShows {
+        event_id: event.id,
+        no_show_count: (event.tickets_sold - event.attendees).max(0),
+        check_in_recorded,
+        attendees: no_shows,
+    })
+}
+
+
 /// Configures the attendee-related routes for the application.
 ///
 /// # Arguments
@@ -236,6 +697,15 @@ pub fn configure_attendee_routes(cfg: &mut web::ServiceConfig) {
         .route("/attendees/counts/daily/", web::get().to(get_daily_attendee_counts))
         .route("/attendees/extremes/", web::get().to(get_attendance_extremes))
         .route("/attendees/no-shows/monthly/", web::get().to(get_monthly_no_shows))
+        .route("/attendees/no-shows/{event_id}/", web::get().to(get_event_no_shows))
         .route("/attendees/", web::get().to(get_monthly_attendees_by_ticket_type))
-        .route("/attendees/{event_id}/", web::get().to(get_attendees_by_event));
+        .route("/attendees/ticket-types/monthly/", web::get().to(get_monthly_attendees_by_ticket_type))
+        .route("/attendees/confirm/", web::get().to(confirm_registration))
+        .route("/attendees/{event_id}/", web::get().to(get_attendees_by_event))
+        .route("/attendees/{event_id}/waitlist/", web::get().to(get_event_waitlist))
+        .route("/ws/events/{id}/attendees/", web::get().to(attendee_counts_socket))
+        .route("/attendees/{event_id}/check-in/all/", web::post().to(check_in_all))
+        .route("/attendees/{event_id}/{attendee_id}/check-in/", web::patch().to(toggle_check_in))
+        .route("/attendees/{event_id}/", web::post().to(register_attendee))
+        .route("/attendees/{event_id}/{attendee_id}/", web::delete().to(cancel_registration));
 }
\ No newline at end of file
@@ -0,0 +1,148 @@
+// External Libraries
+use actix_ws::{Message, MessageStream, Session};
+use log::{debug, warn};
+use sqlx::SqlitePool;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+
+// Internal Mappers
+use crate::attendee::mapper::fetch_attendee_count_update;
+
+// Internal Models
+use crate::attendee::models::{AttendeeCountUpdate, GetAttendeeData};
+
+
+/// Number of updates buffered per subscriber before slow subscribers start missing updates.
+const ATTENDEE_COUNT_CHANNEL_CAPACITY: usize = 64;
+
+
+/// Broadcasts attendee count changes to every live subscriber.
+///
+/// Shared across workers through `web::Data`, so an update published by any worker reaches
+/// every WebSocket connection.
+pub struct AttendeeCountBroadcaster {
+    /// Sending half of the broadcast channel; subscribers are created from it.
+    sender: broadcast::Sender<AttendeeCountUpdate>,
+}
+
+
+impl AttendeeCountBroadcaster {
+    /// Creates a broadcaster without subscribers.
+    ///
+    /// # Returns
+    ///
+    /// A new `AttendeeCountBroadcaster`.
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(ATTENDEE_COUNT_CHANNEL_CAPACITY);
+        AttendeeCountBroadcaster { sender }
+    }
+
+
+    /// Subscribes to attendee count updates of every event.
+    ///
+    /// # Returns
+    ///
+    /// A receiver for all updates published after this call.
+    pub fn subscribe(&self) -> broadcast::Receiver<AttendeeCountUpdate> {
+        self.sender.subscribe()
+    }
+}
+
+
+impl Default for AttendeeCountBroadcaster {
+    fn default() -> Self {
+        AttendeeCountBroadcaster::new()
+    }
+}
+
+
+/// Recounts an event's attendees and publishes the result to live subscribers.
+///
+/// Publishing is best-effort: a failed recount is logged and otherwise ignored, so live
+/// updates never fail the operation that changed the counts.
+///
+/// # Arguments
+///
+/// * `event_id` - Unique identifier of the event whose attendees changed.
+/// * `broadcaster` - The broadcaster to publish the update on.
+/// * `pool` - A reference to the SQLite connection pool.
+pub async fn publish_attendee_counts(
+    event_id: i64,
+    broadcaster: &AttendeeCountBroadcaster,
+    pool: &SqlitePool,
+) {
+    match fetch_attendee_count_update(GetAttendeeData { event_id }, pool).await {
+        // Sending only fails when nobody is subscribed, which is fine
+        Ok(update) => { let _ = broadcaster.sender.send(update); },
+        Err(e) => warn!(target: "attendee", "Failed to count attendees of event {} for live update: {}", event_id, e),
+    }
+}
+
+
+/// Streams an event's attendee counts over a WebSocket until either side closes it.
+///
+/// The current counts are sent immediately, followed by every published update for the event.
+///
+/// # Arguments
+///
+/// * `initial` - The counts at the time the connection was opened.
+/// * `updates` - A receiver subscribed before `initial` was counted, so no update is missed.
+/// * `session` - The WebSocket session to send counts on.
+/// * `messages` - The stream of messages received from the client.
+pub async fn stream_attendee_counts(
+    initial: AttendeeCountUpdate,
+    mut updates: broadcast::Receiver<AttendeeCountUpdate>,
+    mut session: Session,
+    mut messages: MessageStream,
+) {
+    let event_id = initial.event_id;
+
+    if send_update(&mut session, &initial).await.is_err() {
+        return;
+    }
+
+    loop {
+        tokio::select! {
+            update = updates.recv() => match update {
+                Ok(update) if update.event_id == event_id => {
+                    if send_update(&mut session, &update).await.is_err() {
+                        break;
+                    }
+                },
+                Ok(_) => {},
+                Err(RecvError::Lagged(skipped)) => debug!(target: "attendee", "Live subscriber of event {} skipped {} update(s)", event_id, skipped),
+                Err(RecvError::Closed) => break,
+            },
+            message = messages.recv() => match message {
+                Some(Ok(Message::Ping(bytes))) => {
+                    if session.pong(&bytes).await.is_err() {
+                        break;
+                    }
+                },
+                Some(Ok(Message::Close(reason))) => {
+                    let _ = session.close(reason).await;
+                    return;
+                },
+                Some(Ok(_)) => {},
+                Some(Err(_)) | None => break,
+            },
+        }
+    }
+
+    let _ = session.close(None).await;
+}
+
+
+/// Sends one attendee count update as a JSON text message.
+async fn send_update(
+    session: &mut Session,
+    update: &AttendeeCountUpdate,
+) -> Result<(), actix_ws::Closed> {
+    match serde_json::to_string(update) {
+        Ok(json) => session.text(json).await,
+        Err(e) => {
+            warn!(target: "attendee", "Failed to serialize attendee counts of event {}: {}", update.event_id, e);
+            Ok(())
+        },
+    }
+}
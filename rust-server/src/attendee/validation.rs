@@ -0,0 +1,43 @@
+// Internal Models
+use crate::attendee::models::AttendeeRegistrationData;
+
+// Internal Validation
+use crate::validation::{validate_email, ValidationErrors};
+
+
+/// Maximum length of an attendee's name, in characters.
+pub const MAX_ATTENDEE_NAME_LENGTH: usize = 100;
+
+/// Maximum length of a ticket type, in characters.
+pub const MAX_TICKET_TYPE_LENGTH: usize = 100;
+
+
+/// Validates a registration for an event.
+///
+/// # Arguments
+///
+/// * `data` - The submitted registration, with its fields already trimmed.
+///
+/// # Returns
+///
+/// `Ok(())` if the name and ticket type are non-empty and at most their maximum length and the
+/// email is well-formed, or `Err(ValidationErrors)` listing each invalid field.
+pub fn validate_attendee_registration(
+    data: &AttendeeRegistrationData
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+
+    if data.name.is_empty() {
+        errors.add("name", "Name must not be empty");
+    } else if data.name.chars().count() > MAX_ATTENDEE_NAME_LENGTH {
+        errors.add("name", format!("Name must be at most {} characters", MAX_ATTENDEE_NAME_LENGTH));
+    }
+    validate_email(&data.email, "email", &mut errors);
+    if data.ticket_type.is_empty() {
+        errors.add("ticket_type", "Ticket type must not be empty");
+    } else if data.ticket_type.chars().count() > MAX_TICKET_TYPE_LENGTH {
+        errors.add("ticket_type", format!("Ticket type must be at most {} characters", MAX_TICKET_TYPE_LENGTH));
+    }
+
+    errors.into_result()
+}
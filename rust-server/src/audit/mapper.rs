@@ -0,0 +1,73 @@
+// External Libraries
+use sqlx::SqlitePool;
+
+// Internal Models
+use crate::audit::models::{AuditEntry, AuditEntryData, GetAuditLogData};
+
+
+/// Retrieves the most recent audit entries of an organizer, newest first.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `organizer_id` and the maximum number of entries.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing a list of `AuditEntry` records, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn fetch_audit_log(
+    data: GetAuditLogData,
+    pool: &SqlitePool
+) -> Result<Vec<AuditEntry>, sqlx::Error> {
+    sqlx::query_as!(
+        AuditEntry,
+        "SELECT id, organizer_id, actor_user_id, entity_type, entity_id, action, timestamp, detail
+        FROM audit_log
+        WHERE organizer_id = ?
+        ORDER BY timestamp DESC, id DESC
+        LIMIT ?",
+        data.organizer_id,
+        data.limit
+    )
+        .fetch_all(pool)
+        .await
+}
+
+
+/// Inserts a new audit entry.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the organizer, actor, entity and action of the entry.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` indicating success, or an `sqlx::Error` if the insert fails.
+///
+/// # Errors
+///
+/// Returns an error if the insert fails.
+pub async fn create_audit_entry(
+    data: AuditEntryData,
+    pool: &SqlitePool
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "INSERT INTO audit_log (organizer_id, actor_user_id, entity_type, entity_id, action, detail)
+        VALUES (?, ?, ?, ?, ?, ?)",
+        data.organizer_id,
+        data.actor_user_id,
+        data.entity_type,
+        data.entity_id,
+        data.action,
+        data.detail
+    )
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+// External Libraries
+use serde::{Serialize, Deserialize};
+use chrono::NaiveDateTime;
+
+
+/// Represents a recorded action in an organizer's audit log.
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AuditEntry {
+    /// Unique identifier for the audit entry.
+    pub id: i64,
+
+    /// Identifier of the organizer whose data was changed.
+    pub organizer_id: i64,
+
+    /// Identifier of the user who performed the action.
+    pub actor_user_id: i64,
+
+    /// Kind of entity that was changed (e.g. "event", "organizer", "attendee").
+    pub entity_type: String,
+
+    /// Identifier of the changed entity.
+    pub entity_id: i64,
+
+    /// Action performed on the entity (e.g. "create", "update").
+    pub action: String,
+
+    /// Timestamp for when the action was performed.
+    pub timestamp: NaiveDateTime,
+
+    /// Optional free-form description of the action.
+    pub detail: Option<String>,
+}
+
+
+/// Data required to record a new audit entry.
+pub struct AuditEntryData {
+    /// Identifier of the organizer whose data was changed.
+    pub organizer_id: i64,
+
+    /// Identifier of the user who performed the action.
+    pub actor_user_id: i64,
+
+    /// Kind of entity that was changed.
+    pub entity_type: &'static str,
+
+    /// Identifier of the changed entity.
+    pub entity_id: i64,
+
+    /// Action performed on the entity.
+    pub action: &'static str,
+
+    /// Optional free-form description of the action.
+    pub detail: Option<String>,
+}
+
+
+/// Query parameters for retrieving the audit log.
+#[derive(Deserialize)]
+pub struct AuditLogQuery {
+    /// Maximum number of entries to return.
+    pub limit: Option<i64>,
+}
+
+
+/// Data required to retrieve an organizer's most recent audit entries.
+pub struct GetAuditLogData {
+    /// Identifier of the organizer.
+    pub organizer_id: i64,
+
+    /// Maximum number of entries to return.
+    pub limit: i64,
+}
@@ -0,0 +1,63 @@
+// External Libraries
+use actix_web::{web, Responder, HttpResponse, HttpRequest, ResponseError};
+use sqlx::SqlitePool;
+
+// Internal Mappers
+use crate::audit::mapper::fetch_audit_log;
+
+// Internal Models
+use crate::audit::models::{AuditLogQuery, GetAuditLogData};
+
+// Internal Services
+use crate::auth::services::validate_session;
+
+// Internal Routes
+use crate::event::routes::{DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT};
+
+// Internal Errors
+use crate::error::AppError;
+
+
+/// Retrieves the most recent actions recorded for the authenticated organizer.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `query` - Query parameters containing the optional `limit`.
+/// * `pool` - A reference to the SQLite database connection pool.
+///
+/// # Returns
+///
+/// A JSON response containing the audit entries, newest first, or an error message.
+pub async fn get_audit_log(
+    req: HttpRequest,
+    query: web::Query<AuditLogQuery>,
+    pool: web::Data<SqlitePool>,
+) -> impl Responder {
+    let session = match validate_session(&req, &pool).await {
+        Ok(session) => session,
+        Err(e) => return e.error_response(),
+    };
+
+    let limit = query.limit.unwrap_or(DEFAULT_PAGE_LIMIT).clamp(1, MAX_PAGE_LIMIT);
+
+    match fetch_audit_log(GetAuditLogData {organizer_id: session.user_id, limit}, &pool).await {
+        Ok(entries) => HttpResponse::Ok().json(entries),
+        Err(e) => AppError::Internal(format!("Failed to fetch audit log: {}", e)).error_response(),
+    }
+}
+
+
+/// Configures all routes related to the audit log.
+///
+/// # Arguments
+///
+/// * `cfg` - A mutable reference to the Actix service configuration.
+///
+/// # Returns
+///
+/// Adds all audit-related routes to the Actix web application.
+pub fn configure_audit_routes(cfg: &mut web::ServiceConfig) {
+    cfg
+        .route("/audit/", web::get().to(get_audit_log));
+}
@@ -0,0 +1,63 @@
+// External Libraries
+use log::warn;
+use sqlx::SqlitePool;
+
+// Internal Mappers
+use crate::audit::mapper::create_audit_entry;
+
+// Internal Models
+use crate::audit::models::AuditEntryData;
+use crate::auth::models::Session;
+
+
+/// Records an action in the audit log.
+///
+/// Recording is best-effort: a failed insert is logged and otherwise ignored, so auditing
+/// never fails the operation being audited.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the organizer, actor, entity and action to record.
+/// * `pool` - A reference to the SQLite connection pool.
+pub async fn record_audit(
+    data: AuditEntryData,
+    pool: &SqlitePool,
+) {
+    let (entity_type, entity_id, action) = (data.entity_type, data.entity_id, data.action);
+
+    if let Err(e) = create_audit_entry(data, pool).await {
+        warn!(target: "audit", "Failed to record {} of {} {}: {}", action, entity_type, entity_id, e);
+    }
+}
+
+
+
+/// Records an action the signed-in organizer performed on their own data.
+///
+/// Shorthand for `record_audit` with the session's user as both the organizer and the actor.
+///
+/// # Arguments
+///
+/// * `session` - The validated session of the acting organizer.
+/// * `entity_type` - Kind of entity that was changed.
+/// * `entity_id` - Identifier of the changed entity.
+/// * `action` - Action performed on the entity.
+/// * `detail` - Optional free-form description of the action.
+/// * `pool` - A reference to the SQLite connection pool.
+pub async fn record_session_audit(
+    session: &Session,
+    entity_type: &'static str,
+    entity_id: i64,
+    action: &'static str,
+    detail: Option<String>,
+    pool: &SqlitePool,
+) {
+    record_audit(AuditEntryData {
+        organizer_id: session.user_id,
+        actor_user_id: session.user_id,
+        entity_type,
+        entity_id,
+        action,
+        detail,
+    }, pool).await;
+}
\ No newline at end of file
@@ -1,5 +1,6 @@
 // External Libraries
-use sqlx::SqlitePool;
+use log::debug;
+use sqlx::{SqliteConnection, SqlitePool};
 
 // Internal Models
 use crate::auth::models::{
@@ -8,11 +9,15 @@ use crate::auth::models::{
     GetUserData,
     GetUserIDData,
     UpdatePasswordData,
+    UpdateUserRoleData,
     DeleteUserData,
     Session,
     SessionData,
+    ExtendSessionData,
+    RotateSessionData,
     GetSessionData,
-    DeleteSessionData
+    DeleteSessionData,
+    DeleteUserSessionsData
 };
 
 
@@ -32,7 +37,7 @@ pub async fn fetch_user_by_username(
 ) -> Result<User, sqlx::Error> {
     sqlx::query_as!(
         User,
-        "SELECT id, username, password FROM users WHERE username = ?",
+        "SELECT id, username, password, role FROM users WHERE username = ?",
         data.username
     )
         .fetch_one(pool)
@@ -56,7 +61,7 @@ pub async fn fetch_user_by_id(
 ) -> Result<User, sqlx::Error> {
     sqlx::query_as!(
         User,
-        "SELECT id, username, password FROM users WHERE id = ?",
+        "SELECT id, username, password, role FROM users WHERE id = ?",
         data.id
     )
         .fetch_one(pool)
@@ -69,22 +74,24 @@ pub async fn fetch_user_by_id(
 /// # Arguments
 ///
 /// * `data` - A struct containing the username and password of the user to create.
-/// * `pool` - A reference to the SQLite connection pool.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
 ///
 /// # Returns
 ///
 /// A `Result` containing the `User` struct representing the newly created user, or an error if the query fails.
 pub async fn create_user(
     data: AuthData,
-    pool: &SqlitePool
+    conn: &mut SqliteConnection
 ) -> Result<User, sqlx::Error> {
+    debug!(target: "auth", "Creating user '{}'", data.username);
+
     let rec = sqlx::query_as!(
         User,
-        "INSERT INTO users (username, password) VALUES (?, ?) RETURNING *",
+        "INSERT INTO users (username, password) VALUES (?, ?) RETURNING id, username, password, role",
         data.username,
         data.password 
     )
-        .fetch_one(pool)
+        .fetch_one(&mut *conn)
         .await?;
 
     Ok(rec)
@@ -96,23 +103,61 @@ pub async fn create_user(
 /// # Arguments
 ///
 /// * `data` - A struct containing the user ID and the new password to update.
-/// * `pool` - A reference to the SQLite connection pool.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
 ///
 /// # Returns
 ///
 /// A `Result` indicating success or failure of the password update.
 pub async fn update_user_password(
     data: UpdatePasswordData,
-    pool: &SqlitePool
+    conn: &mut SqliteConnection
 ) -> Result<(), sqlx::Error> {
+    debug!(target: "auth", "Updating password for user {}", data.user_id);
+
     sqlx::query!(
         "UPDATE users SET password = ? WHERE id = ?",
         data.new_password,
         data.user_id
+    )
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
+}
+
+
+/// Changes the role of an existing user, e.g. to promote them to admin.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the user ID and the new role.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` indicating success or failure of the role update.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if no user has the given ID.
+pub async fn update_user_role(
+    data: UpdateUserRoleData,
+    pool: &SqlitePool
+) -> Result<(), sqlx::Error> {
+    debug!(target: "auth", "Setting role of user {} to '{}'", data.user_id, data.role);
+
+    let result = sqlx::query!(
+        "UPDATE users SET role = ? WHERE id = ?",
+        data.role,
+        data.user_id
     )
         .execute(pool)
         .await?;
 
+    if result.rows_affected() == 0 {
+        return Err(sqlx::Error::RowNotFound);
+    }
+
     Ok(())
 }
 
@@ -131,6 +176,8 @@ pub async fn delete_user(
     data: DeleteUserData,
     pool: &SqlitePool
 ) -> Result<(), sqlx::Error> {
+    debug!(target: "auth", "Deleting user {}", data.user_id);
+
     sqlx::query!(
         "DELETE FROM users WHERE id = ?",
         data.user_id
@@ -158,7 +205,10 @@ pub async fn fetch_session_by_token(
 ) -> Result<Session, sqlx::Error> {
     sqlx::query_as!(
         Session,
-        "SELECT id, user_id, token FROM sessions WHERE token = ?",
+        "SELECT sessions.id, sessions.user_id, sessions.token, users.role, sessions.created_at, sessions.expires_at
+         FROM sessions
+         JOIN users ON users.id = sessions.user_id
+         WHERE sessions.token = ?",
         data.token
     )
         .fetch_one(pool)
@@ -170,28 +220,89 @@ pub async fn fetch_session_by_token(
 ///
 /// # Arguments
 ///
-/// * `data` - A struct containing the user ID and session token.
-/// * `pool` - A reference to the SQLite connection pool.
+/// * `data` - A struct containing the user ID, session token and lifetime in days.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
 ///
 /// # Returns
 ///
-/// A `Result` containing the `Session` struct for the newly created session, or an error if the query fails.
+/// A `Result` indicating success or failure of the session creation.
 pub async fn create_session(
     data: SessionData,
+    conn: &mut SqliteConnection
+) -> Result<(), sqlx::Error> {
+    debug!(target: "auth", "Creating session for user {}", data.user_id);
+
+    sqlx::query!(
+        "INSERT INTO sessions (user_id, token, expires_at) VALUES (?, ?, datetime('now', '+' || ? || ' days'));",
+        data.user_id, data.token, data.lifetime_days
+    )
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
+}
+
+
+/// Pushes back the expiry of a session to the given number of days from now.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the session token and lifetime in days.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` indicating success or failure of the update.
+pub async fn extend_session(
+    data: ExtendSessionData,
     pool: &SqlitePool
 ) -> Result<(), sqlx::Error> {
-    sqlx::query_as!(
-        Session,
-        "INSERT INTO sessions (user_id, token) VALUES (?, ?) RETURNING *;",
-        data.user_id, data.token
+    sqlx::query!(
+        "UPDATE sessions SET expires_at = datetime('now', '+' || ? || ' days') WHERE token = ?",
+        data.lifetime_days, data.token
     )
-        .fetch_one(pool)
+        .execute(pool)
         .await?;
 
     Ok(())
 }
 
 
+/// Replaces the token of a session and pushes back its expiry.
+///
+/// The old token stops working as soon as the update is committed.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the old and new session tokens and the lifetime in days.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` indicating success or failure of the update.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if no session has the old token, or an error if the
+/// query fails.
+pub async fn rotate_session(
+    data: RotateSessionData,
+    pool: &SqlitePool
+) -> Result<(), sqlx::Error> {
+    let result = sqlx::query!(
+        "UPDATE sessions SET token = ?, expires_at = datetime('now', '+' || ? || ' days') WHERE token = ?",
+        data.new_token, data.lifetime_days, data.old_token
+    )
+        .execute(pool)
+        .await?;
+
+    match result.rows_affected() {
+        0 => Err(sqlx::Error::RowNotFound),
+        _ => Ok(()),
+    }
+}
+
+
 /// Deletes a session from the database based on the provided session token.
 ///
 /// # Arguments
@@ -206,6 +317,8 @@ pub async fn delete_session(
     data: DeleteSessionData,
     pool: &SqlitePool
 ) -> Result<(), sqlx::Error> {
+    debug!(target: "auth", "Deleting session");
+
     sqlx::query!(
         "DELETE FROM sessions WHERE token = ?",
         data.token
@@ -216,3 +329,53 @@ pub async fn delete_session(
     Ok(())
 }
 
+
+/// Deletes every session of a user, signing them out on all devices.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the ID of the user whose sessions are deleted.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` containing the number of deleted sessions, or an `sqlx::Error` if the delete fails.
+pub async fn delete_sessions_by_user(
+    data: DeleteUserSessionsData,
+    conn: &mut SqliteConnection
+) -> Result<u64, sqlx::Error> {
+    debug!(target: "auth", "Deleting all sessions of user {}", data.user_id);
+
+    let result = sqlx::query!(
+        "DELETE FROM sessions WHERE user_id = ?",
+        data.user_id
+    )
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(result.rows_affected())
+}
+
+
+/// Deletes every session whose expiry has passed.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the number of deleted sessions, or an `sqlx::Error` if the delete fails.
+pub async fn purge_expired_sessions(
+    pool: &SqlitePool
+) -> Result<u64, sqlx::Error> {
+    debug!(target: "auth", "Purging expired sessions");
+
+    let result = sqlx::query!(
+        "DELETE FROM sessions WHERE expires_at <= datetime('now')"
+    )
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected())
+}
@@ -1,7 +1,15 @@
 // External Libraries
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
 
+/// Role of a regular event organizer; the default for new users.
+pub const ORGANIZER_ROLE: &str = "organizer";
+
+/// Role of a support/admin user with access to admin-only endpoints.
+pub const ADMIN_ROLE: &str = "admin";
+
+
 /// Represents a user in the system.
 #[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
 pub struct User {
@@ -13,6 +21,9 @@ pub struct User {
 
     /// Hashed password associated with the user.
     pub password: String,
+
+    /// Role of the user, either `organizer` or `admin`.
+    pub role: String,
 }
 
 
@@ -22,6 +33,9 @@ pub struct UserData {
     /// Username chosen by the user.
     pub username: String,
 
+    /// Role of the user, either `organizer` or `admin`.
+    pub role: String,
+
     /// Name of the user organizer
     pub name: String,
 
@@ -44,6 +58,23 @@ pub struct AuthData {
 }
 
 
+/// Data submitted to register a new user and their organizer profile.
+#[derive(Deserialize)]
+pub struct RegisterData {
+    /// Username of the new user.
+    pub username: String,
+
+    /// Password of the new user.
+    pub password: String,
+
+    /// Optional organizer name; defaults to the username.
+    pub name: Option<String>,
+
+    /// Optional organizer website URL.
+    pub website: Option<String>,
+}
+
+
 /// Data required to retrieve a user by username.
 #[derive(Serialize, Deserialize)]
 pub struct GetUserData {
@@ -63,6 +94,9 @@ pub struct GetUserIDData {
 /// Data required to update a user's password.
 #[derive(Deserialize)]
 pub struct UpdatePasswordRequestData {
+    /// The user's current password, required to confirm the change.
+    pub current_password: String,
+
     /// New password to set for the user.
     pub new_password: String,
 }
@@ -79,6 +113,25 @@ pub struct UpdatePasswordData {
 }
 
 
+/// Request body for changing a user's role.
+#[derive(Deserialize)]
+pub struct UpdateUserRoleRequestData {
+    /// New role to assign to the user, either `organizer` or `admin`.
+    pub role: String,
+}
+
+
+/// Data required to change a user's role.
+#[derive(Deserialize)]
+pub struct UpdateUserRoleData {
+    /// Unique identifier of the user whose role is to be changed.
+    pub user_id: i64,
+
+    /// New role to assign to the user.
+    pub role: String,
+}
+
+
 /// Data required to delete a user.
 #[derive(Deserialize)]
 pub struct DeleteUserData {
@@ -98,6 +151,15 @@ pub struct Session {
 
     /// Session token used for authentication.
     pub token: String,
+
+    /// Role of the user associated with the session.
+    pub role: String,
+
+    /// Timestamp for when the session was created.
+    pub created_at: NaiveDateTime,
+
+    /// Timestamp after which the session is no longer accepted.
+    pub expires_at: NaiveDateTime,
 }
 
 
@@ -109,6 +171,32 @@ pub struct SessionData {
 
     /// Session token to associate with the user.
     pub token: String,
+
+    /// Number of days until the session expires.
+    pub lifetime_days: i64,
+}
+
+
+/// Data required to push back the expiry of a session.
+pub struct ExtendSessionData {
+    /// Session token of the session to extend.
+    pub token: String,
+
+    /// Number of days from now until the session expires.
+    pub lifetime_days: i64,
+}
+
+
+/// Data required to replace the token of a session.
+pub struct RotateSessionData {
+    /// Current session token, which stops working once replaced.
+    pub old_token: String,
+
+    /// New session token.
+    pub new_token: String,
+
+    /// Number of days from now until the session expires.
+    pub lifetime_days: i64,
 }
 
 
@@ -126,3 +214,44 @@ pub struct DeleteSessionData {
     /// Session token of the session to delete.
     pub token: String,
 }
+
+
+/// Data required to delete every session of a user.
+#[derive(Deserialize)]
+pub struct DeleteUserSessionsData {
+    /// ID of the user whose sessions are deleted.
+    pub user_id: i64,
+}
+
+
+/// Requirements a new password must meet.
+#[derive(Clone, Copy)]
+pub struct PasswordPolicy {
+    /// Minimum number of characters.
+    pub min_length: usize,
+
+    /// Whether the password must contain at least one letter and at least one digit.
+    pub require_letter_and_digit: bool,
+}
+
+
+/// Settings controlling how long sessions stay valid.
+#[derive(Clone, Copy)]
+pub struct SessionConfig {
+    /// Number of days a session stays valid after it is created (or last used, if sliding).
+    pub lifetime_days: i64,
+
+    /// Whether each successfully validated request pushes the expiry back by `lifetime_days`.
+    pub sliding_expiration: bool,
+}
+
+
+/// Sessions last a week and do not slide by default.
+impl Default for SessionConfig {
+    fn default() -> Self {
+        SessionConfig {
+            lifetime_days: 7,
+            sliding_expiration: false,
+        }
+    }
+}
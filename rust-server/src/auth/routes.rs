@@ -1,19 +1,29 @@
 // External Libraries
-use actix_web::{web, Responder, HttpRequest, HttpResponse, cookie};
+use actix_web::{web, HttpRequest, HttpResponse, cookie, http::header};
 use cookie::Cookie;
+use log::{info, warn};
 use sqlx::SqlitePool;
 use time::Duration;
 
 // Internal Mappers
-use crate::auth::mapper::{fetch_user_by_username, fetch_user_by_id, create_user, update_user_password, delete_user, create_session, delete_session};
-use crate::organizer::mapper::{delete_organizer, fetch_organizer};
+use crate::auth::mapper::{fetch_user_by_username, fetch_user_by_id, create_user, update_user_password, delete_user, create_session, rotate_session, delete_session, delete_sessions_by_user};
+use crate::organizer::mapper::{delete_organizer, fetch_organizer, create_organizer};
 
 // Internal Models
-use crate::auth::models::{UserData, AuthData, GetUserData, GetUserIDData, UpdatePasswordRequestData, UpdatePasswordData, DeleteUserData, SessionData, DeleteSessionData};
+use crate::auth::models::{UserData, AuthData, RegisterData, GetUserData, GetUserIDData, UpdatePasswordRequestData, UpdatePasswordData, DeleteUserData, SessionData, DeleteSessionData, DeleteUserSessionsData, RotateSessionData, SessionConfig, PasswordPolicy};
 use crate::organizer::models::{DeleteOrganizerData, GetOrganizerData, Organizer};
+use crate::audit::models::AuditEntryData;
 
 // Internal Services
-use crate::auth::services::{generate_session_token, hash_password, validate_session, verify_password};
+use crate::audit::services::record_audit;
+use crate::auth::services::{generate_session_token, hash_password, validate_password_strength, validate_session, verify_password, verify_legacy_password, LoginRateLimiter, RateLimiter};
+
+// Internal Validation
+use crate::organizer::validation::validate_organizer_website;
+use crate::validation::ValidationErrors;
+
+// Internal Errors
+use crate::error::{AppError, ErrorResponse};
 
 
 /// Handles retrieving a specific user by session token.
@@ -29,16 +39,13 @@ use crate::auth::services::{generate_session_token, hash_password, validate_sess
 pub async fn get_user(
     req: HttpRequest,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
-    let session = match validate_session(&req, &pool).await {
-        Ok(session) => session,
-        Err(response) => return response,
-    };
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
 
-    match fetch_user_by_id(GetUserIDData {id: session.user_id}, &pool).await {
-        Ok(user) => HttpResponse::Ok().json(GetUserData {username: user.username}),
-        Err(e) => HttpResponse::Unauthorized().body(format!("User not found: {}", e)),
-    }
+    let user = fetch_user_by_id(GetUserIDData {id: session.user_id}, &pool).await
+        .map_err(|e| AppError::Unauthorized(format!("User not found: {}", e)))?;
+
+    Ok(HttpResponse::Ok().json(GetUserData {username: user.username}))
 }
 
 
@@ -55,97 +62,178 @@ pub async fn get_user(
 pub async fn check_auth_status(
     req: HttpRequest,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
-    let session = match validate_session(&req, &pool).await {
-        Ok(session) => session,
-        Err(response) => return response,
-    };
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
 
     let organizer_info = fetch_organizer(GetOrganizerData { organizer_id: session.user_id }, &pool)
         .await.unwrap_or_else(|_| Organizer::default());
     
-    match fetch_user_by_id(GetUserIDData {id: session.user_id}, &pool).await {
-        Ok(user) => HttpResponse::Ok().json(UserData {
-            username: user.username,
-            name: organizer_info.name,
-            logo: organizer_info.logo,
-            website: organizer_info.website,
-        }),
-        Err(e) => HttpResponse::Unauthorized().body(format!("User not found: {}", e)),
-    }
+    let user = fetch_user_by_id(GetUserIDData {id: session.user_id}, &pool).await
+        .map_err(|e| AppError::Unauthorized(format!("User not found: {}", e)))?;
+
+    Ok(HttpResponse::Ok().json(UserData {
+        username: user.username,
+        role: user.role,
+        name: organizer_info.name,
+        logo: organizer_info.logo,
+        website: organizer_info.website,
+    }))
 }
 
 
 /// Logs in a user by verifying their credentials and creating a session.
 ///
+/// Every attempt is counted per client IP before the password is verified, so a burst of
+/// parallel attempts cannot slip past the limit; a successful login clears the count.
+///
 /// # Arguments
 ///
+/// * `req` - The incoming HTTP request, providing the client IP.
 /// * `data` - A JSON object containing the user's username and password.
 /// * `pool` - A reference to the SQLite database connection pool.
+/// * `session_config` - Settings providing the lifetime of the new session.
+/// * `limiter` - The rate limiter shared by all login attempts.
 ///
 /// # Returns
 ///
-/// A response indicating the result of the login attempt.
+/// A response indicating the result of the login attempt, or `429 Too Many Requests` with a
+/// `Retry-After` header if the client IP has too many recent failed attempts.
 pub async fn login_user(
+    req: HttpRequest,
     data: web::Json<AuthData>,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
+    session_config: web::Data<SessionConfig>,
+    limiter: web::Data<LoginRateLimiter>,
+) -> Result<HttpResponse, AppError> {
+    let client_ip = req.peer_addr().map(|addr| addr.ip().to_string()).unwrap_or_default();
+
+    if let Err(retry_after) = limiter.0.check(&client_ip) {
+        warn!(target: "auth", "Login rate limit exceeded for {}", client_ip);
+        return Ok(HttpResponse::TooManyRequests()
+            .insert_header((header::RETRY_AFTER, retry_after.as_secs().max(1).to_string()))
+            .json(ErrorResponse::new("Too many failed login attempts, please try again later", "too_many_requests")));
+    }
+
     let auth_data = data.into_inner();
 
-    let user = match fetch_user_by_username(GetUserData {username: auth_data.username}, &pool).await {
+    let user = match fetch_user_by_username(GetUserData {username: auth_data.username.clone()}, &pool).await {
         Ok(user) => user,
-        Err(e) => return HttpResponse::Unauthorized().body(format!("Username not found: {}", e)),
+        Err(e) => {
+            warn!(target: "auth", "Login failed for unknown username '{}'", auth_data.username);
+            return Err(AppError::Unauthorized(format!("Username not found: {}", e)));
+        },
     };
     
-    match verify_password(&user.password, &auth_data.password) {
-        Err(e) => return HttpResponse::Unauthorized().body(format!("Invalid password: {}", e)),
-        _ => {},
+    if let Err(e) = verify_password(&user.password, &auth_data.password) {
+        if verify_legacy_password(&user.password, &auth_data.password).is_err() {
+            warn!(target: "auth", "Login failed for user {}: invalid password", user.id);
+            return Err(AppError::Unauthorized(format!("Invalid password: {}", e)));
+        }
+
+        // Upgrade a hash created before the pepper was introduced
+        let upgraded = match hash_password(&auth_data.password) {
+            Ok(new_password) => match pool.acquire().await {
+                Ok(mut conn) => update_user_password(UpdatePasswordData {user_id: user.id, new_password}, &mut conn)
+                    .await
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            },
+            Err(e) => Err(e.to_string()),
+        };
+        match upgraded {
+            Ok(()) => info!(target: "auth", "Upgraded password hash of user {} to the peppered scheme", user.id),
+            Err(e) => warn!(target: "auth", "Failed to upgrade password hash of user {}: {}", user.id, e),
+        }
     }
 
     let token = generate_session_token();
 
-    let cookie = Cookie::build("session_token", token.clone())
-        .path("/")
-        .http_only(true)
-        .same_site(cookie::SameSite::None)
-        .secure(true)
-        .finish();
-
     let mut response = HttpResponse::Ok();
-    response.cookie(cookie);
+    response.cookie(session_cookie(token.clone()));
 
-    match create_session(SessionData {user_id: user.id, token: token.clone()}, &pool).await {
-        Ok(()) => response.body(format!("Session created: {}", token)),
-        Err(e) => HttpResponse::Unauthorized().body(format!("Failed to create session: {}", e)),
-    }
+    let mut conn = pool.acquire().await?;
+    create_session(SessionData {user_id: user.id, token: token.clone(), lifetime_days: session_config.lifetime_days}, &mut conn).await?;
+
+    limiter.0.reset(&client_ip);
+
+    info!(target: "auth", "User {} logged in", user.id);
+
+    Ok(response.body(format!("Session created: {}", token)))
 }
 
 
-/// Registers a new user with the provided username and password.
+/// Registers a new user with the provided username and password, together with their
+/// organizer profile.
+///
+/// The user and organizer are created in one transaction, so a failure leaves neither behind.
+/// Registration attempts are rate limited per client IP.
 ///
 /// # Arguments
 ///
-/// * `data` - A JSON object containing the user's username and password.
+/// * `req` - The incoming HTTP request, used to identify the client IP.
+/// * `data` - A JSON object containing the user's username and password, and optionally the
+///   organizer's `name` (defaults to the username) and `website`.
 /// * `pool` - A reference to the SQLite database connection pool.
+/// * `limiter` - The rate limiter shared by all registration attempts.
+/// * `password_policy` - The requirements the password must meet.
 ///
 /// # Returns
 ///
-/// A response indicating the result of the registration attempt.
+/// A response indicating the result of the registration attempt, `400 Bad Request` if the
+/// password is too weak or the website is not a valid URL, `409 Conflict` if the username is already taken,
+/// or `429 Too Many Requests` with a `Retry-After` header if the client IP has exceeded its limit.
 pub async fn register_user(
-    data: web::Json<AuthData>,
+    req: HttpRequest,
+    data: web::Json<RegisterData>,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
-    let password = match hash_password(&data.password) {
+    limiter: web::Data<RateLimiter>,
+    password_policy: web::Data<PasswordPolicy>,
+) -> Result<HttpResponse, AppError> {
+    let client_ip = req.peer_addr().map(|addr| addr.ip().to_string()).unwrap_or_default();
+
+    if let Err(retry_after) = limiter.check(&client_ip) {
+        warn!(target: "auth", "Registration rate limit exceeded for {}", client_ip);
+        return Ok(HttpResponse::TooManyRequests()
+            .insert_header((header::RETRY_AFTER, retry_after.as_secs().max(1).to_string()))
+            .json(ErrorResponse::new("Too many registration attempts, please try again later", "too_many_requests")));
+    }
+
+    let RegisterData { username, password, name, website } = data.into_inner();
+
+    validate_password_strength(&password, &password_policy)
+        .map_err(|message| ValidationErrors::single("password", message))?;
+    validate_organizer_website(website.as_deref())?;
+
+    let password = match hash_password(&password) {
         Ok(password) => password,
-        Err(e) => return HttpResponse::Unauthorized().body(format!("Error hashing password: {}", e)),
+        Err(e) => return Err(AppError::Internal(format!("Error hashing password: {}", e))),
     };
-    
-    match create_user(AuthData {username: data.username.clone(), password}, &pool).await {
-        Ok(user) => HttpResponse::Ok().body(format!("User {} registered", user.username)),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Failed to register user: {}", e)),
-    }
-    
-    // TODO Create organizer (possibly separate route)
+
+    let mut tx = pool.begin().await?;
+
+    let user = create_user(AuthData {username: username.clone(), password}, &mut tx).await
+        .map_err(|e| match e {
+            sqlx::Error::Database(e) if e.is_unique_violation() => AppError::Conflict("Username already taken".to_string()),
+            e => AppError::Db(e),
+        })?;
+
+    let name = name.filter(|name| !name.trim().is_empty()).unwrap_or(username);
+
+    let organizer = create_organizer(Organizer {id: user.id, name, logo: None, website}, &mut tx).await?;
+
+    tx.commit().await?;
+
+    info!(target: "auth", "Registered user {} ('{}')", user.id, user.username);
+    record_audit(AuditEntryData {
+        organizer_id: organizer.id,
+        actor_user_id: user.id,
+        entity_type: "organizer",
+        entity_id: organizer.id,
+        action: "create",
+        detail: None,
+    }, &pool).await;
+
+    Ok(HttpResponse::Ok().body(format!("User {} registered", user.username)))
 }
 
 
@@ -162,57 +250,133 @@ pub async fn register_user(
 pub async fn logout_user(
     req: HttpRequest,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
-    let session = match validate_session(&req, &pool).await {
-        Ok(session) => session,
-        Err(response) => return response,
-    };
-    
-    let expired_cookie = Cookie::build("session_token", "")
-        .path("/")
-        .http_only(true)
-        .same_site(cookie::SameSite::None)
-        .secure(true)
-        .expires(time::OffsetDateTime::now_utc() - Duration::days(1))
-        .finish();
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
 
-    match delete_session(DeleteSessionData {token: session.token}, &pool).await {
-        Ok(()) => HttpResponse::Ok().cookie(expired_cookie).body("Logged out successfully"),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Failed to log out user: {}", e)),
-    }
+    delete_session(DeleteSessionData {token: session.token}, &pool).await?;
+
+    info!(target: "auth", "User {} logged out", session.user_id);
+
+    Ok(HttpResponse::Ok().cookie(expired_session_cookie()).body("Logged out successfully"))
+}
+
+
+/// Logs out a user on every device by deleting all of their sessions.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `pool` - A reference to the SQLite database connection pool.
+///
+/// # Returns
+///
+/// A response indicating the result of the logout attempt.
+pub async fn logout_all_sessions(
+    req: HttpRequest,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let mut conn = pool.acquire().await?;
+    let deleted = delete_sessions_by_user(DeleteUserSessionsData {user_id: session.user_id}, &mut conn).await?;
+
+    info!(target: "auth", "User {} logged out of {} sessions", session.user_id, deleted);
+
+    Ok(HttpResponse::Ok().cookie(expired_session_cookie()).body("Logged out of all sessions"))
+}
+
+
+/// Renews the current session under a new token.
+///
+/// The session's token is replaced and its expiry pushed back to the configured lifetime, so
+/// the old token stops working immediately.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `pool` - A reference to the SQLite database connection pool.
+/// * `session_config` - Settings providing the lifetime of the renewed session.
+///
+/// # Returns
+///
+/// A response carrying the new session cookie, or `401 Unauthorized` if the current session
+/// is missing, invalid or expired.
+pub async fn refresh_session(
+    req: HttpRequest,
+    pool: web::Data<SqlitePool>,
+    session_config: web::Data<SessionConfig>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let token = generate_session_token();
+    rotate_session(RotateSessionData {old_token: session.token, new_token: token.clone(), lifetime_days: session_config.lifetime_days}, &pool).await
+        .map_err(|e| AppError::Unauthorized(format!("Session not authenticated: {}", e)))?;
+
+    info!(target: "auth", "User {} refreshed their session", session.user_id);
+
+    Ok(HttpResponse::Ok().cookie(session_cookie(token)).body("Session refreshed"))
 }
 
 
 /// Changes the password of an existing user.
 ///
+/// The password change, the deletion of the user's sessions and the replacement session are
+/// written in one transaction, so a failure leaves the old password and sessions in place.
+///
 /// # Arguments
 ///
 /// * `req` - The incoming HTTP request containing session data.
-/// * `data` - A JSON object containing the user's ID and new password.
+/// * `data` - A JSON object containing the user's current and new password.
 /// * `pool` - A reference to the SQLite database connection pool.
+/// * `password_policy` - The requirements the new password must meet.
+/// * `session_config` - Settings providing the lifetime of the replacement session.
 ///
 /// # Returns
 ///
-/// A response indicating the result of the password change attempt.
+/// A response carrying a fresh session cookie, since every existing session of the user
+/// is deleted once the password is changed, `401 Unauthorized`
+/// if the current password is wrong, or `400 Bad Request` if the new password is too weak.
 pub async fn change_password(
     req: HttpRequest,
     data: web::Json<UpdatePasswordRequestData>,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
-    let session = match validate_session(&req, &pool).await {
-        Ok(session) => session,
-        Err(response) => return response,
-    };
+    password_policy: web::Data<PasswordPolicy>,
+    session_config: web::Data<SessionConfig>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let user = fetch_user_by_id(GetUserIDData {id: session.user_id}, &pool).await
+        .map_err(|e| AppError::Unauthorized(format!("User not found: {}", e)))?;
+
+    if let Err(e) = verify_password(&user.password, &data.current_password)
+        && verify_legacy_password(&user.password, &data.current_password).is_err() {
+        warn!(target: "auth", "Password change rejected for user {}: invalid current password", user.id);
+        return Err(AppError::Unauthorized(format!("Invalid current password: {}", e)));
+    }
+
+    validate_password_strength(&data.new_password, &password_policy)
+        .map_err(|message| ValidationErrors::single("new_password", message))?;
     
     let new_password = match hash_password(&data.new_password) {
         Ok(new_password) => new_password,
-        Err(e) => return HttpResponse::Unauthorized().body(format!("Error hashing password: {}", e)),
+        Err(e) => return Err(AppError::Internal(format!("Error hashing password: {}", e))),
     };
     
-    match update_user_password(UpdatePasswordData {user_id: session.user_id, new_password}, &pool).await {
-        Ok(()) => HttpResponse::Ok().body("Password updated"),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Failed to update password: {}", e)),
-    }
+    let token = generate_session_token();
+
+    let mut tx = pool.begin().await?;
+
+    update_user_password(UpdatePasswordData {user_id: session.user_id, new_password}, &mut tx).await?;
+
+    // Sign out every other device and keep this one logged in with a new session
+    delete_sessions_by_user(DeleteUserSessionsData {user_id: session.user_id}, &mut tx).await?;
+    create_session(SessionData {user_id: session.user_id, token: token.clone(), lifetime_days: session_config.lifetime_days}, &mut tx).await?;
+
+    tx.commit().await?;
+
+    info!(target: "auth", "User {} changed their password", session.user_id);
+
+    Ok(HttpResponse::Ok().cookie(session_cookie(token)).body("Password updated"))
 }
 
 
@@ -229,21 +393,42 @@ pub async fn change_password(
 pub async fn remove_user(
     req: HttpRequest,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
-    let session = match validate_session(&req, &pool).await {
-        Ok(session) => session,
-        Err(response) => return response,
-    };
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
     
-    match delete_user(DeleteUserData {user_id: session.user_id}, &pool).await {
-        Err(e) => return HttpResponse::InternalServerError().body(format!("Failed to delete user: {}", e)),
-        _ => {},
-    };
+    delete_user(DeleteUserData {user_id: session.user_id}, &pool).await?;
     
-    match delete_organizer(DeleteOrganizerData {organizer_id: session.user_id}, &pool).await {
-        Ok(()) => HttpResponse::Ok().body("User deleted"),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Failed to delete organizer: {}", e)),
-    }
+    let mut conn = pool.acquire().await?;
+    delete_organizer(DeleteOrganizerData {organizer_id: session.user_id}, &mut conn).await?;
+
+    info!(target: "auth", "User {} deleted", session.user_id);
+
+    Ok(HttpResponse::Ok().body("User deleted"))
+}
+
+
+/// Builds the cookie carrying a session token.
+fn session_cookie(
+    token: String
+) -> Cookie<'static> {
+    Cookie::build("session_token", token)
+        .path("/")
+        .http_only(true)
+        .same_site(cookie::SameSite::None)
+        .secure(true)
+        .finish()
+}
+
+
+/// Builds an already expired session cookie, which makes the browser drop its session token.
+fn expired_session_cookie() -> Cookie<'static> {
+    Cookie::build("session_token", "")
+        .path("/")
+        .http_only(true)
+        .same_site(cookie::SameSite::None)
+        .secure(true)
+        .expires(time::OffsetDateTime::now_utc() - Duration::days(1))
+        .finish()
 }
 
 
@@ -263,6 +448,40 @@ pub fn configure_auth_routes(cfg: &mut web::ServiceConfig) {
         .route("/login/", web::post().to(login_user))
         .route("/register/", web::post().to(register_user))
         .route("/logout/", web::post().to(logout_user))
+        .route("/logout_all/", web::post().to(logout_all_sessions))
+        .route("/refresh/", web::post().to(refresh_session))
         .route("/update_password/", web::put().to(change_password))
         .route("/delete_user/", web::delete().to(remove_user));
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{test, App, http::StatusCode};
+    use std::time::Duration;
+
+    use crate::test_utils::test_pool;
+
+    #[actix_web::test]
+    async fn register_rejects_taken_username() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(test_pool().await))
+                .app_data(web::Data::new(RateLimiter::new(10, Duration::from_secs(60))))
+                .app_data(web::Data::new(PasswordPolicy { min_length: 8, require_letter_and_digit: true }))
+                .configure(configure_auth_routes)
+        ).await;
+
+        let register = || test::TestRequest::post()
+            .uri("/register/")
+            .set_json(serde_json::json!({"username": "organizer", "password": "password123"}))
+            .to_request();
+
+        let first = test::call_service(&app, register()).await;
+        assert_eq!(first.status(), StatusCode::OK);
+
+        let second = test::call_service(&app, register()).await;
+        assert_eq!(second.status(), StatusCode::CONFLICT);
+    }
+}
@@ -1,24 +1,47 @@
 // External Libraries
-use actix_web::{HttpRequest, HttpResponse};
-use argon2::{password_hash, Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
+use actix_web::{web, HttpRequest};
+use argon2::{password_hash, Algorithm, Argon2, Params, PasswordHash, PasswordHasher, PasswordVerifier, Version};
 use argon2::password_hash::SaltString;
+use log::warn;
 use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use chrono::Utc;
 use rand::{RngCore};
 use rand::rngs::OsRng;
 use sqlx::SqlitePool;
+use std::collections::{HashMap, VecDeque};
+use std::env;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::time::{Duration, Instant};
 
 // Internal Mappers
-use crate::auth::mapper::fetch_session_by_token;
+use crate::auth::mapper::{fetch_session_by_token, extend_session, delete_session};
 
 // Internal Models
 use crate::auth::models::{
     GetSessionData,
-    Session
+    ExtendSessionData,
+    DeleteSessionData,
+    Session,
+    SessionConfig,
+    PasswordPolicy
 };
 
+// Internal Errors
+use crate::error::AppError;
+
+
+/// Server-side secret mixed into every password hash as the Argon2 secret.
+static PASSWORD_PEPPER: OnceLock<Vec<u8>> = OnceLock::new();
+
+/// Pepper used by debug builds when `PASSWORD_PEPPER` is not set.
+const DEV_PASSWORD_PEPPER: &str = "dev-password-pepper";
+
 
 /// Validates the user session from the HTTP request cookies by checking the session token.
 ///
+/// Expired sessions are deleted and rejected. With sliding expiration enabled in the app's
+/// `SessionConfig`, each accepted session's expiry is pushed back.
+///
 /// # Arguments
 ///
 /// * `req` - A reference to the incoming HTTP request, from which the session cookie is extracted.
@@ -26,22 +49,91 @@ use crate::auth::models::{
 ///
 /// # Returns
 ///
-/// A `Result<Session, HttpResponse>` which is:
+/// A `Result<Session, AppError>` which is:
 /// - `Ok(Session)` if the session token is found in cookies and successfully validated against the database.
-/// - `Err(HttpResponse)` containing a `401 Unauthorized` response if the session token is missing or invalid.
+/// - `Err(AppError::Unauthorized)` if the session token is missing, invalid or expired.
 pub async fn validate_session(
     req: &HttpRequest,
     pool: &SqlitePool,
-) -> Result<Session, HttpResponse> {
+) -> Result<Session, AppError> {
     let cookie = req
         .cookie("session_token")
-        .ok_or_else(|| HttpResponse::Unauthorized().body("No session token found in cookies"))?;
+        .ok_or_else(|| AppError::Unauthorized("No session token found in cookies".to_string()))?;
 
     let token = cookie.value().to_string();
 
-    fetch_session_by_token(GetSessionData { token }, pool)
+    let session = fetch_session_by_token(GetSessionData { token }, pool)
         .await
-        .map_err(|e| HttpResponse::Unauthorized().body(format!("Session not authenticated: {}", e)))
+        .map_err(|e| AppError::Unauthorized(format!("Session not authenticated: {}", e)))?;
+
+    if session.expires_at <= Utc::now().naive_utc() {
+        if let Err(e) = delete_session(DeleteSessionData { token: session.token }, pool).await {
+            warn!(target: "auth", "Failed to delete expired session of user {}: {}", session.user_id, e);
+        }
+        return Err(AppError::Unauthorized("Session expired".to_string()));
+    }
+
+    let config = req.app_data::<web::Data<SessionConfig>>()
+        .map(|config| *config.get_ref())
+        .unwrap_or_default();
+
+    if config.sliding_expiration
+        && let Err(e) = extend_session(ExtendSessionData { token: session.token.clone(), lifetime_days: config.lifetime_days }, pool).await {
+        warn!(target: "auth", "Failed to extend session of user {}: {}", session.user_id, e);
+    }
+
+    Ok(session)
+}
+
+
+/// Ensures the session's user has the given role.
+///
+/// # Arguments
+///
+/// * `session` - The validated session of the requesting user.
+/// * `role` - The role required to access the endpoint.
+///
+/// # Returns
+///
+/// A `Result<(), AppError>` which is:
+/// - `Ok(())` if the user has the required role.
+/// - `Err(AppError::Forbidden)` otherwise.
+pub fn require_role(
+    session: &Session,
+    role: &str,
+) -> Result<(), AppError> {
+    if session.role == role {
+        Ok(())
+    } else {
+        Err(AppError::Forbidden(format!("The '{}' role is required", role)))
+    }
+}
+
+
+/// Checks a new password against the password policy.
+///
+/// # Arguments
+///
+/// * `password` - The plain-text password to check.
+/// * `policy` - The requirements the password must meet.
+///
+/// # Returns
+///
+/// `Ok(())` if the password meets the policy, or `Err(String)` describing the first unmet requirement.
+pub fn validate_password_strength(
+    password: &str,
+    policy: &PasswordPolicy,
+) -> Result<(), String> {
+    if password.chars().count() < policy.min_length {
+        return Err(format!("must be at least {} characters long", policy.min_length));
+    }
+
+    if policy.require_letter_and_digit
+        && !(password.chars().any(char::is_alphabetic) && password.chars().any(|c| c.is_ascii_digit())) {
+        return Err("must contain at least one letter and one digit".to_string());
+    }
+
+    Ok(())
 }
 
 
@@ -53,7 +145,39 @@ pub async fn validate_session(
 pub fn generate_session_token() -> String {
     let mut bytes = [0u8; 32];
     rand::thread_rng().fill_bytes(&mut bytes);
-    URL_SAFE_NO_PAD.encode(&bytes)
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+
+/// Loads the password pepper from the `PASSWORD_PEPPER` environment variable.
+///
+/// Called at startup so that a missing pepper fails fast rather than on the first login.
+///
+/// # Panics
+///
+/// Panics in release builds if `PASSWORD_PEPPER` is not set or is empty. Debug builds fall
+/// back to a fixed development pepper instead.
+pub fn init_password_pepper() {
+    password_pepper();
+}
+
+
+/// Returns the password pepper, loading it on first use.
+fn password_pepper() -> &'static [u8] {
+    PASSWORD_PEPPER.get_or_init(|| match env::var("PASSWORD_PEPPER") {
+        Ok(pepper) if !pepper.is_empty() => pepper.into_bytes(),
+        _ if cfg!(debug_assertions) => {
+            warn!(target: "auth", "PASSWORD_PEPPER is not set; using the development pepper");
+            DEV_PASSWORD_PEPPER.as_bytes().to_vec()
+        },
+        _ => panic!("PASSWORD_PEPPER must be set"),
+    })
+}
+
+
+/// Returns an Argon2id hasher keyed with the password pepper.
+fn peppered_argon2() -> Result<Argon2<'static>, password_hash::Error> {
+    Ok(Argon2::new_with_secret(password_pepper(), Algorithm::Argon2id, Version::V0x13, Params::default())?)
 }
 
 
@@ -71,7 +195,7 @@ pub fn hash_password(
     password: &str
 ) -> Result<String, password_hash::Error> {
     let salt = SaltString::generate(&mut OsRng);
-    let argon2 = Argon2::default();
+    let argon2 = peppered_argon2()?;
     let hash = argon2.hash_password(password.as_bytes(), &salt)?;
     let hash = hash.to_string();
     Ok(hash)
@@ -94,6 +218,165 @@ pub fn verify_password(
     password: &str
 ) -> password_hash::Result<()> {
     let hash = PasswordHash::new(hash)?;
-    let argon2 = Argon2::default();
+    let argon2 = peppered_argon2()?;
     argon2.verify_password(password.as_bytes(), &hash)
-}
\ No newline at end of file
+}
+
+
+/// Verifies a password against a hash created before the pepper was introduced.
+///
+/// Callers should re-hash the password with `hash_password` once this succeeds.
+///
+/// # Arguments
+///
+/// * `hash` - A string slice that holds the unpeppered hashed password.
+/// * `password` - A string slice of the plaintext password to verify.
+///
+/// # Returns
+///
+/// `Ok(())` if the password matches, or an error if it does not.
+pub fn verify_legacy_password(
+    hash: &str,
+    password: &str
+) -> password_hash::Result<()> {
+    let hash = PasswordHash::new(hash)?;
+    Argon2::default().verify_password(password.as_bytes(), &hash)
+}
+
+
+/// Limits how often a key (e.g. a client IP) may perform an action within a sliding window.
+///
+/// Shared across workers through `web::Data`, so every worker counts against the same limit.
+pub struct RateLimiter {
+    /// Maximum number of attempts allowed per key within the window.
+    max_attempts: usize,
+
+    /// Length of the sliding window.
+    window: Duration,
+
+    /// Times of the attempts made by each key within the current window.
+    attempts: Mutex<HashMap<String, VecDeque<Instant>>>,
+}
+
+
+impl RateLimiter {
+    /// Creates a rate limiter allowing `max_attempts` per key within `window`.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_attempts` - Maximum number of attempts allowed per key within the window.
+    /// * `window` - Length of the sliding window.
+    ///
+    /// # Returns
+    ///
+    /// A `RateLimiter` with no recorded attempts.
+    pub fn new(
+        max_attempts: usize,
+        window: Duration
+    ) -> Self {
+        RateLimiter { max_attempts, window, attempts: Mutex::new(HashMap::new()) }
+    }
+
+
+    /// Records an attempt for a key if it is still within its limit.
+    ///
+    /// Attempts older than the window are forgotten, and keys without recent attempts are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key the attempt is counted against.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the attempt is allowed, or `Err(Duration)` with the time until the key may try again.
+    pub fn check(
+        &self,
+        key: &str
+    ) -> Result<(), Duration> {
+        let now = Instant::now();
+        let mut attempts = self.recent_attempts(now);
+
+        let times = attempts.entry(key.to_string()).or_default();
+        if times.len() >= self.max_attempts {
+            let oldest = times.front().copied().unwrap_or(now);
+            return Err(self.window.saturating_sub(now.duration_since(oldest)));
+        }
+
+        times.push_back(now);
+        Ok(())
+    }
+
+
+    /// Forgets every recorded attempt of a key, e.g. after a successful login.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key whose attempts are forgotten.
+    pub fn reset(
+        &self,
+        key: &str
+    ) {
+        self.recent_attempts(Instant::now()).remove(key);
+    }
+
+
+    /// Locks the recorded attempts, forgetting those older than the window and dropping keys
+    /// without recent attempts.
+    fn recent_attempts(
+        &self,
+        now: Instant
+    ) -> MutexGuard<'_, HashMap<String, VecDeque<Instant>>> {
+        let mut attempts = self.attempts.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        attempts.retain(|_, times| {
+            while times.front().is_some_and(|time| now.duration_since(*time) >= self.window) {
+                times.pop_front();
+            }
+            !times.is_empty()
+        });
+
+        attempts
+    }
+}
+
+
+/// Limits logins per client IP; attempts are forgotten once a login succeeds.
+///
+/// Kept apart from the registration `RateLimiter` so both can be shared through `web::Data`.
+pub struct LoginRateLimiter(pub RateLimiter);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limiter_rejects_attempt_over_limit() {
+        let limiter = RateLimiter::new(3, Duration::from_secs(60));
+
+        for _ in 0..3 {
+            assert!(limiter.check("10.0.0.1").is_ok());
+        }
+
+        let retry_after = limiter.check("10.0.0.1").unwrap_err();
+        assert!(retry_after > Duration::ZERO && retry_after <= Duration::from_secs(60));
+    }
+
+    #[test]
+    fn rate_limiter_counts_keys_separately() {
+        let limiter = RateLimiter::new(1, Duration::from_secs(60));
+
+        assert!(limiter.check("10.0.0.1").is_ok());
+        assert!(limiter.check("10.0.0.1").is_err());
+        assert!(limiter.check("10.0.0.2").is_ok());
+    }
+
+    #[test]
+    fn rate_limiter_allows_key_again_after_reset() {
+        let limiter = RateLimiter::new(1, Duration::from_secs(60));
+
+        assert!(limiter.check("10.0.0.1").is_ok());
+        limiter.reset("10.0.0.1");
+        assert!(limiter.check("10.0.0.1").is_ok());
+    }
+}
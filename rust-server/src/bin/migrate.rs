@@ -0,0 +1,30 @@
+// External Libraries
+use dotenv::dotenv;
+use sqlx::migrate::MigrateError;
+use sqlx::sqlite::SqlitePoolOptions;
+use std::env;
+
+
+/// Applies every pending migration in `migrations/` to the database at `DATABASE_URL`.
+#[actix_web::main]
+async fn main() -> Result<(), MigrateError> {
+    // Load environment variables from the .env file
+    dotenv().ok();
+
+    // Retrieve the database URL from the environment
+    let database_url = env::var("DATABASE_URL")
+        .expect("DATABASE_URL must be set in the .env file");
+
+    // Create a connection pool for SQLite
+    let pool = SqlitePoolOptions::new()
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to database");
+
+    // Run all migrations that have not yet been applied
+    sqlx::migrate!("./migrations").run(&pool).await?;
+
+    println!("Migrations applied");
+
+    Ok(())
+}
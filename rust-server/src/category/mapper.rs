@@ -1,11 +1,12 @@
 // External Libraries
+use log::debug;
 use sqlx::SqlitePool;
 
 // Internal Models
-use crate::category::models::Category;
+use crate::category::models::{Category, GetCategoriesData, CreateCategoryData, UpdateCategoryData};
 
 
-/// Retrieves all categories created by a specific organizer.
+/// Retrieves the categories visible to a specific organizer: their own and the shared ones.
 ///
 /// # Arguments
 ///
@@ -20,12 +21,111 @@ use crate::category::models::Category;
 ///
 /// Returns an error if the query fails or no category is found.
 pub async fn fetch_categories(
+    data: GetCategoriesData,
     pool: &SqlitePool
 ) -> Result<Vec<Category>, sqlx::Error> {
     sqlx::query_as!(
         Category,
-        "SELECT id, name, description FROM categories"
+        "SELECT id, name, description, organizer_id FROM categories WHERE organizer_id IS NULL OR organizer_id = ?",
+        data.organizer_id
     )
         .fetch_all(pool)
         .await
-}
\ No newline at end of file
+}
+
+
+/// Retrieves the IDs of the categories visible to a specific organizer, i.e. those their events may use.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `organizer_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the IDs of the organizer's own and the shared categories, or an
+/// `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn fetch_category_ids(
+    data: GetCategoriesData,
+    pool: &SqlitePool
+) -> Result<Vec<i64>, sqlx::Error> {
+    sqlx::query_scalar!(
+        "SELECT id FROM categories WHERE organizer_id IS NULL OR organizer_id = ?",
+        data.organizer_id
+    )
+        .fetch_all(pool)
+        .await
+}
+
+
+/// Creates a new category.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the owning organizer and the name and description of the category.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the created `Category`, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns a unique-violation database error if the organizer already has a category with the same name.
+pub async fn create_category(
+    data: CreateCategoryData,
+    pool: &SqlitePool
+) -> Result<Category, sqlx::Error> {
+    debug!(target: "category", "Creating category '{}' for organizer {}", data.name, data.organizer_id);
+
+    sqlx::query_as!(
+        Category,
+        "INSERT INTO categories (name, description, organizer_id) VALUES (?, ?, ?) RETURNING id, name, description, organizer_id",
+        data.name,
+        data.description,
+        data.organizer_id
+    )
+        .fetch_one(pool)
+        .await
+}
+
+
+/// Updates the name and description of a category owned by an organizer.
+///
+/// Shared categories cannot be updated.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the category and organizer IDs and the new name and description.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the updated `Category`, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if the organizer owns no category with the given ID, or a
+/// unique-violation database error if another of their categories already has the new name.
+pub async fn update_category(
+    data: UpdateCategoryData,
+    pool: &SqlitePool
+) -> Result<Category, sqlx::Error> {
+    debug!(target: "category", "Updating category {}: name='{}'", data.category_id, data.name);
+
+    sqlx::query_as!(
+        Category,
+        "UPDATE categories SET name = ?, description = ? WHERE id = ? AND organizer_id = ?
+         RETURNING id, name, description, organizer_id",
+        data.name,
+        data.description,
+        data.category_id,
+        data.organizer_id
+    )
+        .fetch_one(pool)
+        .await
+}
@@ -1,4 +1,5 @@
 // Internal Modules
 pub mod mapper;
 pub mod models;
-pub mod routes;
\ No newline at end of file
+pub mod routes;
+pub mod validation;
\ No newline at end of file
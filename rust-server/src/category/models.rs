@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 
 /// Represents a category in the system.
-#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Hash, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Category {
     /// Unique identifier for the category.
     pub id: i64,
@@ -13,4 +13,62 @@ pub struct Category {
 
     /// Description of the category.
     pub description: String,
-}
\ No newline at end of file
+
+    /// Organizer who owns the category, or `None` for a category shared by everyone.
+    pub organizer_id: Option<i64>,
+}
+
+
+/// Data required to retrieve the categories visible to an organizer.
+pub struct GetCategoriesData {
+    /// Unique identifier of the organizer whose own categories are included alongside the shared ones.
+    pub organizer_id: i64,
+}
+
+
+/// Settings controlling how clients may cache the category list.
+#[derive(Clone, Copy)]
+pub struct CategoryCacheConfig {
+    /// Number of seconds browsers may privately cache the category list; `0` makes them revalidate every time.
+    pub max_age: u32,
+}
+
+
+/// Data submitted to create or update a category.
+#[derive(Deserialize)]
+pub struct CategoryRequestData {
+    /// Name of the category, unique among the organizer's categories regardless of case.
+    pub name: String,
+
+    /// Description of the category.
+    pub description: String,
+}
+
+
+/// Data required to create a category.
+pub struct CreateCategoryData {
+    /// Unique identifier of the organizer creating the category.
+    pub organizer_id: i64,
+
+    /// Name of the category.
+    pub name: String,
+
+    /// Description of the category.
+    pub description: String,
+}
+
+
+/// Data required to update a category.
+pub struct UpdateCategoryData {
+    /// Unique identifier of the category to update.
+    pub category_id: i64,
+
+    /// Unique identifier of the organizer who owns the category.
+    pub organizer_id: i64,
+
+    /// New name of the category.
+    pub name: String,
+
+    /// New description of the category.
+    pub description: String,
+}
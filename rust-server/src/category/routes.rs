@@ -1,26 +1,165 @@
 // External Libraries
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use actix_web::http::header::{CacheControl, CacheDirective, ETag, EntityTag, IfNoneMatch};
+use log::info;
 use sqlx::SqlitePool;
+use std::hash::{DefaultHasher, Hash, Hasher};
 
 // Internal Mappers
-use crate::category::mapper::fetch_categories;
+use crate::category::mapper::{fetch_categories, create_category, update_category};
 
+// Internal Models
+use crate::category::models::{CategoryCacheConfig, GetCategoriesData, CategoryRequestData, CreateCategoryData, UpdateCategoryData};
 
-/// Handles retrieving all categories.
+// Internal Services
+use crate::auth::services::validate_session;
+
+// Internal Validation
+use crate::category::validation::validate_category_name;
+
+// Internal Errors
+use crate::error::AppError;
+
+
+/// Handles retrieving the categories visible to the session's organizer: their own and the shared ones.
+///
+/// The response carries a `Cache-Control: private, max-age=...` header and an `ETag` derived
+/// from the category rows; a request whose `If-None-Match` matches gets `304 Not Modified`.
 ///
 /// # Arguments
 ///
+/// * `req` - The incoming HTTP request containing session data, checked for an `If-None-Match` header.
 /// * `pool` - The SQLite database connection pool.
+/// * `cache_config` - Settings for the `Cache-Control` header.
 ///
 /// # Returns
 ///
-/// An HTTP response with category data if successful, or an error message.
+/// An HTTP response with category data if successful, `304 Not Modified` if the client's copy
+/// is current, or an error message.
 pub async fn get_categories(
+    req: HttpRequest,
+    pool: web::Data<SqlitePool>,
+    cache_config: web::Data<CategoryCacheConfig>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let categories = fetch_categories(GetCategoriesData { organizer_id: session.user_id }, &pool).await?;
+
+    let mut hasher = DefaultHasher::new();
+    categories.hash(&mut hasher);
+    let etag = EntityTag::new_strong(format!("{:016x}", hasher.finish()));
+
+    let not_modified = match req.get_header::<IfNoneMatch>() {
+        Some(IfNoneMatch::Any) => true,
+        Some(IfNoneMatch::Items(tags)) => tags.iter().any(|tag| tag.weak_eq(&etag)),
+        None => false,
+    };
+
+    // Without a max age the browser revalidates every time, so new or renamed categories show at once
+    let freshness = if cache_config.max_age == 0 {
+        CacheDirective::NoCache
+    } else {
+        CacheDirective::MaxAge(cache_config.max_age)
+    };
+
+    let mut response = if not_modified {
+        HttpResponse::NotModified()
+    } else {
+        HttpResponse::Ok()
+    };
+    response
+        .insert_header(CacheControl(vec![CacheDirective::Private, freshness]))
+        .insert_header(ETag(etag));
+
+    if not_modified {
+        Ok(response.finish())
+    } else {
+        Ok(response.json(categories))
+    }
+}
+
+
+/// Handles creating a new category owned by the session's organizer.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `data` - The JSON body containing the category name and description.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the created category, `400 Bad Request` for an invalid name,
+/// `409 Conflict` if the organizer already has a category with the name, or an error message.
+pub async fn post_category(
+    req: HttpRequest,
+    data: web::Json<CategoryRequestData>,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
-    match fetch_categories(&pool).await {
-        Ok(categories) => HttpResponse::Ok().json(categories),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Categories not found: {}", e)),
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let CategoryRequestData { name, description } = data.into_inner();
+    let name = name.trim().to_string();
+    validate_category_name(&name)?;
+
+    let category = create_category(CreateCategoryData { organizer_id: session.user_id, name: name.clone(), description }, &pool)
+        .await
+        .map_err(|e| category_write_error(e, &name))?;
+
+    info!(target: "category", "User {} created category {}", session.user_id, category.id);
+
+    Ok(HttpResponse::Ok().json(category))
+}
+
+
+/// Handles updating the name and description of a category owned by the session's organizer.
+///
+/// Shared categories cannot be updated.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `category_id` - The path parameter representing the category's ID.
+/// * `data` - The JSON body containing the new category name and description.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the updated category, `400 Bad Request` for an invalid name,
+/// `404 Not Found` if the organizer owns no such category, `409 Conflict` if another of their
+/// categories already has the name, or an error message.
+pub async fn put_category(
+    req: HttpRequest,
+    category_id: web::Path<i64>,
+    data: web::Json<CategoryRequestData>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let category_id = category_id.into_inner();
+    let CategoryRequestData { name, description } = data.into_inner();
+    let name = name.trim().to_string();
+    validate_category_name(&name)?;
+
+    let category = update_category(UpdateCategoryData { category_id, organizer_id: session.user_id, name: name.clone(), description }, &pool)
+        .await
+        .map_err(|e| category_write_error(e, &name))?;
+
+    info!(target: "category", "User {} updated category {}", session.user_id, category_id);
+
+    Ok(HttpResponse::Ok().json(category))
+}
+
+
+/// Converts a failed category insert or update into an error, reporting a duplicate name as a conflict.
+fn category_write_error(
+    error: sqlx::Error,
+    name: &str
+) -> AppError {
+    match error {
+        sqlx::Error::Database(e) if e.is_unique_violation() =>
+            AppError::Conflict(format!("A category named '{}' already exists", name)),
+        e => AppError::lookup("Category", e),
     }
 }
 
@@ -36,5 +175,7 @@ pub async fn get_categories(
 /// Adds all event-related routes to the Actix web application.
 pub fn configure_category_routes(cfg: &mut web::ServiceConfig) {
     cfg
-        .route("/categories/", web::get().to(get_categories));
+        .route("/categories/", web::get().to(get_categories))
+        .route("/categories/", web::post().to(post_category))
+        .route("/categories/{id}/", web::put().to(put_category));
 }
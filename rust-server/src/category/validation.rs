@@ -0,0 +1,31 @@
+// Internal Validation
+use crate::validation::ValidationErrors;
+
+
+/// Maximum length of a category name, in characters.
+pub const MAX_CATEGORY_NAME_LENGTH: usize = 100;
+
+
+/// Validates the name of a category before it is created or renamed.
+///
+/// # Arguments
+///
+/// * `name` - The category name to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the name is non-empty and at most `MAX_CATEGORY_NAME_LENGTH` characters long,
+/// or `Err(ValidationErrors)` describing the failure.
+pub fn validate_category_name(
+    name: &str
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+
+    if name.trim().is_empty() {
+        errors.add("name", "Name must not be empty");
+    } else if name.chars().count() > MAX_CATEGORY_NAME_LENGTH {
+        errors.add("name", format!("Name must be at most {} characters", MAX_CATEGORY_NAME_LENGTH));
+    }
+
+    errors.into_result()
+}
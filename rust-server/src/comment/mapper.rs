@@ -1,11 +1,12 @@
 // External Libraries
-use sqlx::SqlitePool;
+use log::debug;
+use sqlx::{SqliteConnection, SqlitePool};
 
 // Internal Models
-use crate::comment::models::{Comment, GetCommentData};
+use crate::comment::models::{Comment, CommentData, GetCommentData, DeleteCommentData};
 
 
-/// Retrieves comment items by their event ID.
+/// Retrieves comment items by their event ID, newest first.
 ///
 /// # Arguments
 ///
@@ -27,11 +28,125 @@ pub async fn fetch_comments(
 
     sqlx::query_as!(
         Comment,
-        "SELECT *
+        "SELECT id, event_id, message, author_name, author_email, created_at, updated_at
          FROM comments
-         WHERE event_id = ?",
+         WHERE event_id = ?
+         ORDER BY created_at DESC, id DESC",
         event_id
     )
         .fetch_all(pool)
         .await
-}
\ No newline at end of file
+}
+
+
+/// Creates a comment on a public event.
+///
+/// Only events that are approved and not templates accept comments.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id`, `message` and optional author of the comment.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the created `Comment`, or an `sqlx::Error` if the insert fails.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if the event does not exist or does not accept comments,
+/// or an error if the query fails.
+pub async fn create_comment(
+    data: CommentData,
+    pool: &SqlitePool
+) -> Result<Comment, sqlx::Error> {
+    debug!(target: "comment", "Creating comment for event {}", data.event_id);
+
+    sqlx::query_as!(
+        Comment,
+        "INSERT INTO comments (event_id, message, author_name, author_email)
+         SELECT id, ?, ?, ?
+         FROM events
+         WHERE id = ? AND is_template = 0 AND deleted_at IS NULL AND approval_status = 'approved'
+         RETURNING id, event_id, message, author_name, author_email, created_at, updated_at",
+        data.message, data.author_name, data.author_email, data.event_id
+    )
+        .fetch_one(pool)
+        .await
+}
+
+
+/// Creates multiple comments in the database.
+///
+/// # Arguments
+///
+/// * `data` - A vector of `Comment` structs containing the new comments.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` containing the created comments, or an `sqlx::Error` if any insert fails.
+///
+/// # Errors
+///
+/// Returns an error if any of the creation queries fail during execution.
+pub async fn create_comments(
+    data: Vec<Comment>,
+    conn: &mut SqliteConnection
+) -> Result<Vec<Comment>, sqlx::Error> {
+    debug!(target: "comment", "Creating {} comment(s)", data.len());
+
+    let mut comments = Vec::new();
+
+    for comment_item in data {
+        let rec = sqlx::query_as!(
+            Comment,
+            "INSERT INTO comments (event_id, message, author_name, author_email)
+             VALUES (?, ?, ?, ?)
+             RETURNING id, event_id, message, author_name, author_email, created_at, updated_at",
+            comment_item.event_id, comment_item.message, comment_item.author_name, comment_item.author_email
+        )
+            .fetch_one(&mut *conn)
+            .await?;
+
+        comments.push(rec);
+    };
+
+    Ok(comments)
+}
+
+
+/// Deletes a comment of an event.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id` and `comment_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` indicating success or failure of the deletion.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if the event has no comment with the given ID,
+/// or an error if the query fails.
+pub async fn delete_comment(
+    data: DeleteCommentData,
+    pool: &SqlitePool
+) -> Result<(), sqlx::Error> {
+    debug!(target: "comment", "Deleting comment {} of event {}", data.comment_id, data.event_id);
+
+    let result = sqlx::query!(
+        "DELETE FROM comments WHERE id = ? AND event_id = ?",
+        data.comment_id, data.event_id
+    )
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(sqlx::Error::RowNotFound);
+    }
+
+    Ok(())
+}
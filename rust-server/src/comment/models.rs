@@ -1,5 +1,6 @@
 // External Libraries
 use serde::{Serialize, Deserialize};
+use chrono::NaiveDateTime;
 
 
 /// Represents a comment in the system.
@@ -13,6 +14,20 @@ pub struct Comment {
 
     /// Message of the comment.
     pub message: String,
+
+    /// Name the comment was posted under, if any.
+    pub author_name: Option<String>,
+
+    /// Contact email of the author, if given.
+    pub author_email: Option<String>,
+
+    /// Timestamp for when the comment was created.
+    #[serde(skip_deserializing)]
+    pub created_at: NaiveDateTime,
+
+    /// Timestamp for the last update to the comment.
+    #[serde(skip_deserializing)]
+    pub updated_at: NaiveDateTime,
 }
 
 
@@ -21,4 +36,45 @@ pub struct Comment {
 pub struct GetCommentData {
     /// Unique identifier for the event of the comment.
     pub event_id: i64,
-}
\ No newline at end of file
+}
+
+/// Request body for posting a comment on an event.
+#[derive(Deserialize)]
+pub struct CreateCommentData {
+    /// Message of the comment.
+    pub message: String,
+
+    /// Name to post the comment under.
+    #[serde(default)]
+    pub author_name: Option<String>,
+
+    /// Contact email of the author.
+    #[serde(default)]
+    pub author_email: Option<String>,
+}
+
+
+/// Data required to create a single comment.
+pub struct CommentData {
+    /// Unique identifier of the event for the comment.
+    pub event_id: i64,
+
+    /// Message of the comment.
+    pub message: String,
+
+    /// Name the comment is posted under, if any.
+    pub author_name: Option<String>,
+
+    /// Contact email of the author, if given.
+    pub author_email: Option<String>,
+}
+
+
+/// Data required to delete a single comment of an event.
+pub struct DeleteCommentData {
+    /// Unique identifier of the event the comment must belong to.
+    pub event_id: i64,
+
+    /// Unique identifier of the comment to delete.
+    pub comment_id: i64,
+}
@@ -0,0 +1,105 @@
+// External Libraries
+use actix_web::{web, HttpRequest, HttpResponse};
+use log::info;
+use sqlx::SqlitePool;
+
+// Internal Mappers
+use crate::comment::mapper::{create_comment, delete_comment};
+use crate::event::mapper::fetch_event;
+
+// Internal Models
+use crate::comment::models::{CreateCommentData, CommentData, DeleteCommentData};
+use crate::event::models::GetEventData;
+
+// Internal Services
+use crate::audit::services::record_session_audit;
+use crate::auth::services::validate_session;
+
+// Internal Validation
+use crate::comment::validation::validate_comment;
+
+// Internal Errors
+use crate::error::AppError;
+
+
+/// Posts a comment on a public event.
+///
+/// # Arguments
+///
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `data` - The JSON body containing the comment message and optional author name and email.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the created comment, `400 Bad Request` for an empty or overly long
+/// message, an overly long author name or a malformed author email, `404 Not Found` if the event does not accept comments, or an error message.
+pub async fn post_comment(
+    event_id: web::Path<i64>,
+    data: web::Json<CreateCommentData>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let CreateCommentData { message, author_name, author_email } = data.into_inner();
+    let message = message.trim().to_string();
+    let author_name = author_name.map(|name| name.trim().to_string()).filter(|name| !name.is_empty());
+    let author_email = author_email.map(|email| email.trim().to_string()).filter(|email| !email.is_empty());
+
+    validate_comment(&message, author_name.as_deref(), author_email.as_deref())?;
+
+    let comment = create_comment(CommentData {event_id: *event_id, message, author_name, author_email}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    info!(target: "comment", "Comment {} posted on event {}", comment.id, comment.event_id);
+
+    Ok(HttpResponse::Ok().json(comment))
+}
+
+
+/// Deletes a comment of an event owned by the authenticated organizer.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `path` - The path parameters representing the event and comment IDs.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response indicating success, `404 Not Found` if the organizer does not own the event
+/// or the comment does not belong to it, or an error message.
+pub async fn delete_event_comment(
+    req: HttpRequest,
+    path: web::Path<(i64, i64)>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let (event_id, comment_id) = path.into_inner();
+
+    fetch_event(GetEventData {event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    delete_comment(DeleteCommentData {event_id, comment_id}, &pool).await
+        .map_err(|e| AppError::lookup("Comment", e))?;
+
+    info!(target: "comment", "Organizer {} deleted comment {} of event {}", session.user_id, comment_id, event_id);
+    record_session_audit(&session, "event", event_id, "delete_comment", Some(format!("Deleted comment {}", comment_id)), &pool).await;
+
+    Ok(HttpResponse::Ok().body("Comment deleted"))
+}
+
+
+/// Configures all routes related to event comments.
+///
+/// # Arguments
+///
+/// * `cfg` - A mutable reference to the Actix service configuration.
+///
+/// # Returns
+///
+/// Adds all comment-related routes to the Actix web application.
+pub fn configure_comment_routes(cfg: &mut web::ServiceConfig) {
+    cfg
+        .route("/events/{id}/comments/", web::post().to(post_comment))
+        .route("/events/{id}/comments/{comment_id}/", web::delete().to(delete_event_comment));
+}
@@ -0,0 +1,45 @@
+// Internal Validation
+use crate::validation::{validate_email, ValidationErrors};
+
+
+/// Maximum length of a comment message, in characters.
+pub const MAX_COMMENT_LENGTH: usize = 1000;
+
+/// Maximum length of a comment author's name, in characters.
+pub const MAX_AUTHOR_NAME_LENGTH: usize = 100;
+
+
+/// Validates a new comment.
+///
+/// # Arguments
+///
+/// * `message` - The comment message to validate.
+/// * `author_name` - The optional name the comment is posted under.
+/// * `author_email` - The optional contact email of the author.
+///
+/// # Returns
+///
+/// `Ok(())` if the message is non-empty and at most `MAX_COMMENT_LENGTH` characters long, the
+/// author name is at most `MAX_AUTHOR_NAME_LENGTH` characters long and the email, when given,
+/// is well-formed, or `Err(ValidationErrors)` describing each failure.
+pub fn validate_comment(
+    message: &str,
+    author_name: Option<&str>,
+    author_email: Option<&str>
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+
+    if message.trim().is_empty() {
+        errors.add("message", "Message must not be empty");
+    } else if message.chars().count() > MAX_COMMENT_LENGTH {
+        errors.add("message", format!("Message must be at most {} characters", MAX_COMMENT_LENGTH));
+    }
+    if author_name.is_some_and(|name| name.chars().count() > MAX_AUTHOR_NAME_LENGTH) {
+        errors.add("author_name", format!("Name must be at most {} characters", MAX_AUTHOR_NAME_LENGTH));
+    }
+    if let Some(author_email) = author_email {
+        validate_email(author_email, "author_email", &mut errors);
+    }
+
+    errors.into_result()
+}
@@ -0,0 +1,291 @@
+// External Libraries
+use actix_web::{error::{JsonPayloadError, PathError, QueryPayloadError}, http::StatusCode, HttpRequest, HttpResponse, ResponseError};
+use serde::Serialize;
+use std::fmt;
+
+// Internal Validation
+use crate::validation::ValidationErrors;
+
+
+/// JSON body of every error response except field validation failures.
+///
+/// Serializes as `{"error": "...", "code": "..."}`; clients branch on the stable `code`
+/// and may show `error` to the user.
+#[derive(Debug, Serialize)]
+pub struct ErrorResponse {
+    /// Human-readable description of the failure.
+    pub error: String,
+
+    /// Machine-readable error kind, e.g. `not_found`.
+    pub code: &'static str,
+}
+
+
+impl ErrorResponse {
+    /// Creates an error body.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - Human-readable description of the failure.
+    /// * `code` - Machine-readable error kind.
+    ///
+    /// # Returns
+    ///
+    /// The `ErrorResponse` to send as JSON.
+    pub fn new(
+        error: impl Into<String>,
+        code: &'static str
+    ) -> Self {
+        ErrorResponse { error: error.into(), code }
+    }
+}
+
+
+/// Represents every way a request handler can fail.
+///
+/// Handlers return `Result<HttpResponse, AppError>` and propagate failures with `?`;
+/// the `ResponseError` implementation turns each variant into the matching HTTP response.
+#[derive(Debug)]
+pub enum AppError {
+    /// The requested resource does not exist or is not owned by the caller (`404 Not Found`).
+    NotFound(String),
+
+    /// The request has no valid session (`401 Unauthorized`).
+    Unauthorized(String),
+
+    /// The session's user lacks a required role (`403 Forbidden`).
+    Forbidden(String),
+
+    /// The request cannot be processed as sent (`400 Bad Request`).
+    BadRequest(String),
+
+    /// The request conflicts with existing data, e.g. a duplicate unique value (`409 Conflict`).
+    Conflict(String),
+
+    /// The request body exceeds the configured size limit (`413 Payload Too Large`).
+    PayloadTooLarge(String),
+
+    /// One or more request fields are invalid (`400 Bad Request` with a JSON error body).
+    Validation(ValidationErrors),
+
+    /// A database query failed (`500 Internal Server Error`, or `404 Not Found` for a missing row).
+    Db(sqlx::Error),
+
+    /// Any other server-side failure (`500 Internal Server Error`).
+    Internal(String),
+}
+
+
+impl AppError {
+    /// Converts a failed lookup of a single row into an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - Name of what was looked up, e.g. `"Event"`.
+    /// * `error` - The error returned by the lookup.
+    ///
+    /// # Returns
+    ///
+    /// `AppError::NotFound` naming the entity if the row is missing, otherwise `AppError::Db`.
+    pub fn lookup(
+        entity: &str,
+        error: sqlx::Error
+    ) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => AppError::NotFound(format!("{} not found", entity)),
+            e => AppError::Db(e),
+        }
+    }
+
+
+    /// Returns the machine-readable `code` sent in the error body.
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::NotFound(_) | AppError::Db(sqlx::Error::RowNotFound) => "not_found",
+            AppError::Unauthorized(_) => "unauthorized",
+            AppError::Forbidden(_) => "forbidden",
+            AppError::BadRequest(_) => "bad_request",
+            AppError::Conflict(_) => "conflict",
+            AppError::PayloadTooLarge(_) => "payload_too_large",
+            AppError::Validation(_) => "validation",
+            AppError::Db(_) => "database",
+            AppError::Internal(_) => "internal",
+        }
+    }
+}
+
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::NotFound(message)
+            | AppError::Unauthorized(message)
+            | AppError::Forbidden(message)
+            | AppError::BadRequest(message)
+            | AppError::Conflict(message)
+            | AppError::PayloadTooLarge(message)
+            | AppError::Internal(message) => write!(f, "{}", message),
+            AppError::Validation(errors) => write!(f, "{}", errors),
+            AppError::Db(sqlx::Error::RowNotFound) => write!(f, "Not found"),
+            AppError::Db(e) => write!(f, "Database error: {}", e),
+        }
+    }
+}
+
+
+impl ResponseError for AppError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            AppError::NotFound(_) | AppError::Db(sqlx::Error::RowNotFound) => StatusCode::NOT_FOUND,
+            AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::BadRequest(_) | AppError::Validation(_) => StatusCode::BAD_REQUEST,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            AppError::Db(_) | AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            AppError::Validation(errors) => errors.error_response(),
+            e => HttpResponse::build(e.status_code()).json(ErrorResponse::new(e.to_string(), e.code())),
+        }
+    }
+}
+
+
+impl From<sqlx::Error> for AppError {
+    fn from(error: sqlx::Error) -> Self {
+        AppError::Db(error)
+    }
+}
+
+
+impl From<ValidationErrors> for AppError {
+    fn from(errors: ValidationErrors) -> Self {
+        AppError::Validation(errors)
+    }
+}
+
+
+/// Converts JSON body extraction failures into structured errors.
+///
+/// A body over the size limit is rejected with `413 Payload Too Large`. Missing or unknown
+/// fields are reported against the field name serde provides; any other failure (malformed
+/// JSON, wrong content type) is reported against `body`.
+///
+/// # Arguments
+///
+/// * `err` - The JSON payload error raised by the `web::Json` extractor.
+/// * `_req` - The incoming HTTP request.
+///
+/// # Returns
+///
+/// An `actix_web::Error` that renders as a `413 Payload Too Large` or `400 Bad Request` with a JSON error body.
+pub fn json_error_handler(
+    err: JsonPayloadError,
+    _req: &HttpRequest
+) -> actix_web::Error {
+    let (field, message) = match &err {
+        JsonPayloadError::OverflowKnownLength { length, limit } =>
+            return AppError::PayloadTooLarge(format!("Request body of {} bytes exceeds the limit of {} bytes", length, limit)).into(),
+        JsonPayloadError::Overflow { limit } =>
+            return AppError::PayloadTooLarge(format!("Request body exceeds the limit of {} bytes", limit)).into(),
+        JsonPayloadError::Deserialize(e) => {
+            let message = e.to_string();
+            (extract_field_name(&message), message)
+        },
+        e => (None, e.to_string()),
+    };
+
+    ValidationErrors::single(field.as_deref().unwrap_or("body"), message).into()
+}
+
+
+/// Converts query string extraction failures into structured validation errors.
+///
+/// Missing or unknown parameters are reported against the name serde provides; any
+/// other failure is reported against `query`.
+///
+/// # Arguments
+///
+/// * `err` - The query payload error raised by the `web::Query` extractor.
+/// * `_req` - The incoming HTTP request.
+///
+/// # Returns
+///
+/// An `actix_web::Error` that renders as a `400 Bad Request` with a JSON error body.
+pub fn query_error_handler(
+    err: QueryPayloadError,
+    _req: &HttpRequest
+) -> actix_web::Error {
+    let message = err.to_string();
+
+    ValidationErrors::single(extract_field_name(&message).as_deref().unwrap_or("query"), message).into()
+}
+
+
+/// Converts path segment extraction failures, e.g. a non-numeric ID, into structured
+/// validation errors reported against `path`.
+///
+/// # Arguments
+///
+/// * `err` - The path error raised by the `web::Path` extractor.
+/// * `_req` - The incoming HTTP request.
+///
+/// # Returns
+///
+/// An `actix_web::Error` that renders as a `400 Bad Request` with a JSON error body.
+pub fn path_error_handler(
+    err: PathError,
+    _req: &HttpRequest
+) -> actix_web::Error {
+    ValidationErrors::single("path", err.to_string()).into()
+}
+
+
+/// Converts a failed lookup of a single row into an HTTP response, for handlers that
+/// return `HttpResponse` directly rather than `Result<HttpResponse, AppError>`.
+///
+/// A missing row becomes `404 Not Found`; any other database failure stays a
+/// `500 Internal Server Error`.
+///
+/// # Arguments
+///
+/// * `entity` - Name of what was looked up, e.g. `"Event"`.
+/// * `error` - The error returned by the lookup.
+///
+/// # Returns
+///
+/// The HTTP response describing the failure.
+pub fn lookup_error_response(
+    entity: &str,
+    error: sqlx::Error
+) -> HttpResponse {
+    AppError::lookup(entity, error).error_response()
+}
+
+
+/// Extracts the first backtick-quoted field name from a serde error message,
+/// e.g. ``missing field `title` at line 1 column 2``.
+///
+/// # Arguments
+///
+/// * `message` - The serde error message.
+///
+/// # Returns
+///
+/// The field name if the message references one.
+fn extract_field_name(
+    message: &str
+) -> Option<String> {
+    if !message.contains("field `") {
+        return None;
+    }
+
+    let start = message.find('`')? + 1;
+    let end = start + message[start..].find('`')?;
+
+    Some(message[start..end].to_string())
+}
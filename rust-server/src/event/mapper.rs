@@ -1,20 +1,84 @@
 // External Libraries
-use chrono::Datelike;
-use sqlx::SqlitePool;
+use log::debug;
+use sqlx::{SqliteConnection, SqlitePool};
+use std::collections::{BTreeMap, HashMap};
+
+// Internal Mappers
+use crate::analytics::mapper::{fetch_year_events, fetch_daily_event_totals, month_of_year, sum_by_month};
+use crate::organizer::mapper::fetch_organizer;
+use crate::agenda::mapper::{fetch_agenda, create_agenda, copy_agenda};
+use crate::speaker::mapper::{fetch_speakers, create_speakers, copy_speakers};
+use crate::faq::mapper::{fetch_faqs, create_faqs, copy_faqs};
+use crate::attachment::mapper::{fetch_attachments, create_attachments, copy_attachments};
+use crate::comment::mapper::{fetch_comments, create_comments};
+use crate::attendee::mapper::{fetch_attendees_by_event, import_attendees};
+use crate::ticket_tier::mapper::fetch_tier_sales;
 
 // Internal Models
+use crate::analytics::models::GetYearEventsData;
 use crate::event::models::{
     Event,
     EventData,
     GetUserEventsData,
+    GetAllUserEventsData,
+    SearchEventsData,
     GetEventData,
+    GetPublicEventData,
+    PublicEvent,
+    GetEventTemplatesData,
+    UpdateEventApprovalData,
+    GetOwnedEventIdsData,
+    UpdateEventStatusesData,
+    DeleteEventData,
+    GetDeletedEventsData,
+    DeletedEvent,
+    EventDetails,
+    EventExport,
+    EventLocation,
+    EventSummary,
+    EventSummaryPage,
+    LiveAttendeeCounts,
+    CapacityWarning,
+    CapacityWarningConfig,
     TicketTotals, 
     EventCounts,
+    EventStatusCounts,
+    EVENT_STATUSES,
 };
 use crate::overview::models::{
     CountByDate, 
     GetOverview,
 };
+use crate::organizer::models::{Organizer, PublicOrganizer, GetOrganizerData};
+use crate::agenda::models::GetAgendaData;
+use crate::speaker::models::{Speaker, GetSpeakerData};
+use crate::faq::models::{Faq, GetFaqData};
+use crate::attachment::models::{Attachment, GetAttachmentData};
+use crate::comment::models::{Comment, GetCommentData};
+use crate::attendee::models::{Attendee, GetAttendeeData};
+use crate::ticket_tier::models::{TierSales, GetTierSalesData};
+
+// Internal Validation
+use crate::agenda::validation::link_agenda_speakers;
+
+
+/// Score added to a related event candidate that shares the event's category.
+const RELATED_CATEGORY_WEIGHT: i64 = 3;
+
+/// Score added to a related event candidate for each speaker name it shares with the event.
+const RELATED_SPEAKER_WEIGHT: i64 = 2;
+
+/// Number of candidates, closest in date first, that are scored when finding related events.
+const RELATED_CANDIDATE_POOL: i64 = 50;
+
+/// Maximum number of related events returned.
+const RELATED_EVENTS_LIMIT: i64 = 5;
+
+/// Maximum number of locations returned for location autocomplete.
+const EVENT_LOCATIONS_LIMIT: i64 = 20;
+
+/// Maximum number of events returned by a keyword search.
+const EVENT_SEARCH_LIMIT: i64 = 50;
 
 
 /// Fetches monthly ticket revenue and total profit for a specific organizer and year.
@@ -31,40 +95,40 @@ use crate::overview::models::{
 ///
 /// # Errors
 ///
-/// Returns an error if the query to fetch events fails.
+/// Returns an error if the query to fetch events or their ticket tier sales fails.
 pub async fn fetch_monthly_ticket_sales(
     data: GetOverview, 
     pool: &SqlitePool
 ) -> Result<TicketTotals, sqlx::Error> {
-    let year = data.year.to_string();
-    let organizer_id = data.organizer_id;
+    let events = fetch_year_events(GetYearEventsData {organizer_id: data.organizer_id, year: data.year, category_id: None}, pool).await?;
+    let tier_sales = fetch_tier_sales(GetTierSalesData {event_ids: events.iter().map(|event| event.id).collect()}, pool).await?;
 
-    let events = sqlx::query_as!(
-        Event,
-        "SELECT id, title, description, event_date, start_time, end_time, location, category_id, status, organizer_id,
-                price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, registration_deadline,
-                is_virtual, image, map_embed, accessibility_info, safety_guidelines, created_at, updated_at
-         FROM events
-         WHERE strftime('%Y', event_date) = ? AND organizer_id = ?",
-        year, organizer_id
-    )
-        .fetch_all(pool)
-        .await?;
+    Ok(tally_ticket_sales(&events, &tier_sales))
+}
 
-    let mut tickets_by_month = vec![0f64; 12];
-    let mut total_profit = 0f64;
 
-    for event in events {
-        let month = event.event_date.month() as usize - 1;
+/// Tallies the ticket revenue of a year's events by month.
+///
+/// Events with ticket tiers earn each tier's price for the tickets sold in it.
+///
+/// # Arguments
+///
+/// * `events` - The events of a single year.
+/// * `tier_sales` - The ticket sales of the events' tiers, keyed by event ID.
+///
+/// # Returns
+///
+/// A `TicketTotals` struct with monthly ticket revenue and total profit.
+pub fn tally_ticket_sales(
+    events: &[Event],
+    tier_sales: &HashMap<i64, Vec<TierSales>>
+) -> TicketTotals {
+    let revenue = |event: &Event| event.ticket_revenue(tier_sales.get(&event.id).map_or(&[], Vec::as_slice));
 
-        tickets_by_month[month] += event.tickets_sold as f64 * event.price;
-        total_profit += event.tickets_sold as f64 * event.price;
+    TicketTotals {
+        tickets: sum_by_month(events, 12, month_of_year, revenue),
+        profit: events.iter().map(revenue).sum(),
     }
-
-    Ok(TicketTotals {
-        tickets: tickets_by_month,
-        profit: total_profit,
-    })
 }
 
 
@@ -87,18 +151,44 @@ pub async fn fetch_daily_event_counts(
     data: GetOverview, 
     pool: &SqlitePool
 ) -> Result<EventCounts, sqlx::Error> {
+    let daily_totals = fetch_daily_event_totals(data, pool).await?;
+
+    Ok(EventCounts {
+        event_counts: daily_totals.into_iter()
+            .map(|day| CountByDate { date: day.date, count: day.events as usize })
+            .collect(),
+    })
+}
+
+
+/// Fetches the number of events per status for a specific organizer and year.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `year` and `organizer_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing an `EventStatusCounts` struct in which every known status is present,
+/// or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query to fetch status counts fails.
+pub async fn fetch_event_status_counts(
+    data: GetOverview,
+    pool: &SqlitePool
+) -> Result<EventStatusCounts, sqlx::Error> {
     let year = data.year.to_string();
     let organizer_id = data.organizer_id;
 
-    let daily_rows = sqlx::query!(
+    let status_rows = sqlx::query!(
         r#"
-        SELECT
-            strftime('%Y-%m-%d', event_date) AS day,
-            COUNT(*) AS event_count
+        SELECT status, COUNT(*) AS event_count
         FROM events
-        WHERE strftime('%Y', event_date) = ? AND organizer_id = ?
-        GROUP BY day
-        ORDER BY day
+        WHERE strftime('%Y', event_date) = ? AND organizer_id = ? AND is_template = 0 AND deleted_at IS NULL
+        GROUP BY status
         "#,
         year,
         organizer_id
@@ -106,25 +196,31 @@ pub async fn fetch_daily_event_counts(
         .fetch_all(pool)
         .await?;
 
-    let daily_totals = daily_rows.into_iter().filter_map(|row| {
-        if let Some(day) = row.day {
-            Some(CountByDate { date: day, count: row.event_count as usize })
-        } else {
-            None
-        }
-    }).collect();
+    let mut status_counts: BTreeMap<String, usize> = EVENT_STATUSES
+        .iter()
+        .map(|status| (status.to_string(), 0))
+        .collect();
+    for row in status_rows {
+        status_counts.insert(row.status, row.event_count as usize);
+    }
 
-    Ok(EventCounts {
-        event_counts: daily_totals,
-    })
+    Ok(EventStatusCounts { status_counts })
 }
 
 
-/// Retrieves all events created by a specific organizer.
+/// Retrieves all events created by a specific organizer that start in the given year.
+///
+/// With `include_spanning`, multi-day events that end in the year but start in the
+/// previous one (e.g. New Year's Eve events) are included as well.
+///
+/// The events are sorted by `sort_by`, which must be one of `EVENT_SORT_COLUMNS`, with ties
+/// broken by date. The column is bound as a parameter and selected by a `CASE` expression
+/// rather than spliced into the SQL.
 ///
 /// # Arguments
 ///
-/// * `data` - A struct containing the `organizer_id`.
+/// * `data` - A struct containing the `organizer_id`, `year`, `include_spanning` flag, the
+///   optional `status` and `category_id` filters, and the sort column and direction.
 /// * `pool` - A reference to the SQLite connection pool.
 ///
 /// # Returns
@@ -140,16 +236,238 @@ pub async fn fetch_events(
 ) -> Result<Vec<Event>, sqlx::Error> {
     let year = data.year.to_string();
     let organizer_id = data.organizer_id;
+    let direction = if data.descending { -1 } else { 1 };
+
+    sqlx::query_as!(
+        Event,
+        "SELECT id, title, description, event_date, end_date, start_time, end_time, location, category_id, status, organizer_id,
+                price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, registration_deadline,
+                is_virtual, image, map_embed, accessibility_info, safety_guidelines, is_template, approval_status, created_at, updated_at
+         FROM events
+         WHERE (strftime('%Y', event_date) = ?1 OR (?2 AND strftime('%Y', end_date) = ?1)) AND organizer_id = ?3 AND is_template = 0 AND deleted_at IS NULL
+           AND (?4 IS NULL OR status = ?4) AND (?5 IS NULL OR category_id = ?5)
+         ORDER BY CASE ?6
+                      WHEN 'tickets_sold' THEN tickets_sold
+                      WHEN 'attendees' THEN attendees
+                      WHEN 'price' THEN price
+                      ELSE julianday(event_date)
+                  END * ?7,
+                  event_date ASC, id ASC",
+        year, data.include_spanning, organizer_id, data.status, data.category_id, data.sort_by, direction
+    )
+        .fetch_all(pool)
+        .await
+}
+
+
+/// Retrieves a page of event summaries created by a specific organizer across all years.
+///
+/// Events in the trash are left out unless `include_deleted` is set.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `organizer_id`, `limit`, `offset` and `include_deleted`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing an `EventSummaryPage` ordered by most recent event date,
+/// or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if either the count or page query fails.
+pub async fn fetch_all_events(
+    data: GetAllUserEventsData,
+    pool: &SqlitePool
+) -> Result<EventSummaryPage, sqlx::Error> {
+    let organizer_id = data.organizer_id;
+
+    let total = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM events WHERE organizer_id = ? AND is_template = 0 AND (? OR deleted_at IS NULL)",
+        organizer_id, data.include_deleted
+    )
+        .fetch_one(pool)
+        .await?;
+
+    let events = sqlx::query_as!(
+        EventSummary,
+        "SELECT id, title, event_date, end_date, start_time, end_time, location, category_id, status, tickets_sold, max_attendees, deleted_at
+         FROM events
+         WHERE organizer_id = ? AND is_template = 0 AND (? OR deleted_at IS NULL)
+         ORDER BY event_date DESC
+         LIMIT ? OFFSET ?",
+        organizer_id, data.include_deleted, data.limit, data.offset
+    )
+        .fetch_all(pool)
+        .await?;
+
+    Ok(EventSummaryPage {
+        events,
+        total,
+        limit: data.limit,
+        offset: data.offset,
+    })
+}
+
+
+/// Searches an organizer's events for a keyword in the title, description or location.
+///
+/// The keyword is matched case-insensitively as a literal substring, so `%` and `_` in it
+/// match only themselves. Templates are excluded.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `organizer_id` and the `query` keyword.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing at most `EVENT_SEARCH_LIMIT` matching events ordered by `event_date`,
+/// or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn search_events(
+    data: SearchEventsData,
+    pool: &SqlitePool
+) -> Result<Vec<Event>, sqlx::Error> {
+    let pattern = format!("%{}%", escape_like(&data.query));
 
     sqlx::query_as!(
         Event,
-        "SELECT id, title, description, event_date, start_time, end_time, location, category_id, status, organizer_id,
+        r#"SELECT id, title, description, event_date, end_date, start_time, end_time, location, category_id, status, organizer_id,
                 price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, registration_deadline,
-                is_virtual, image, map_embed, accessibility_info, safety_guidelines, created_at, updated_at
+                is_virtual, image, map_embed, accessibility_info, safety_guidelines, is_template, approval_status, created_at, updated_at
+         FROM events
+         WHERE organizer_id = ?1 AND is_template = 0 AND deleted_at IS NULL
+           AND (title LIKE ?2 ESCAPE '\' OR description LIKE ?2 ESCAPE '\' OR location LIKE ?2 ESCAPE '\')
+         ORDER BY event_date ASC
+         LIMIT ?3"#,
+        data.organizer_id, pattern, EVENT_SEARCH_LIMIT
+    )
+        .fetch_all(pool)
+        .await
+}
+
+
+/// Escapes the `LIKE` wildcards `%` and `_`, and the escape character `\` itself,
+/// for use with `ESCAPE '\'`.
+fn escape_like(
+    input: &str
+) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+
+/// Retrieves the event templates of a specific organizer, ordered by title.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `organizer_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing a list of `EventSummary` projections of the templates,
+/// or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn fetch_event_templates(
+    data: GetEventTemplatesData,
+    pool: &SqlitePool
+) -> Result<Vec<EventSummary>, sqlx::Error> {
+    sqlx::query_as!(
+        EventSummary,
+        "SELECT id, title, event_date, end_date, start_time, end_time, location, category_id, status, tickets_sold, max_attendees, deleted_at
+         FROM events
+         WHERE organizer_id = ? AND is_template = 1 AND deleted_at IS NULL
+         ORDER BY title",
+        data.organizer_id
+    )
+        .fetch_all(pool)
+        .await
+}
+
+
+/// Retrieves the distinct locations used by an organizer's events, most used first.
+///
+/// Empty locations (e.g. of virtual events) and templates are ignored.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `organizer_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing up to `EVENT_LOCATIONS_LIMIT` locations with their event counts,
+/// or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn fetch_event_locations(
+    data: GetOrganizerData,
+    pool: &SqlitePool
+) -> Result<Vec<EventLocation>, sqlx::Error> {
+    sqlx::query_as!(
+        EventLocation,
+        r#"SELECT trim(location) AS "location!: String", COUNT(*) AS "event_count!: i64"
          FROM events
-         WHERE strftime('%Y', event_date) = ? AND organizer_id = ?
-         ORDER BY event_date ASC",
-        year, organizer_id
+         WHERE organizer_id = ? AND is_template = 0 AND deleted_at IS NULL AND trim(location) != ''
+         GROUP BY trim(location)
+         ORDER BY COUNT(*) DESC, trim(location)
+         LIMIT ?"#,
+        data.organizer_id,
+        EVENT_LOCATIONS_LIMIT
+    )
+        .fetch_all(pool)
+        .await
+}
+
+
+/// Retrieves the upcoming events an organizer has published, soonest first.
+///
+/// Only approved events with the `upcoming` status that have not yet taken place are returned;
+/// templates are ignored.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `organizer_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing a list of upcoming `Event`s, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn fetch_upcoming_events(
+    data: GetOrganizerData,
+    pool: &SqlitePool
+) -> Result<Vec<Event>, sqlx::Error> {
+    sqlx::query_as!(
+        Event,
+        "SELECT id, title, description, event_date, end_date, start_time, end_time, location, category_id, status, organizer_id, 
+                price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, registration_deadline,
+                is_virtual, image, map_embed, accessibility_info, safety_guidelines, is_template, approval_status, created_at, updated_at
+         FROM events 
+         WHERE organizer_id = ? AND status = 'upcoming' AND event_date >= CURRENT_DATE AND is_template = 0 AND deleted_at IS NULL
+           AND approval_status = 'approved'
+         ORDER BY event_date, start_time",
+        data.organizer_id
     )
         .fetch_all(pool)
         .await
@@ -169,7 +487,7 @@ pub async fn fetch_events(
 ///
 /// # Errors
 ///
-/// Returns an error if the query fails or the event does not match the provided IDs.
+/// Returns an error if the query fails or the event does not match the provided IDs or is deleted.
 pub async fn fetch_event(
     data: GetEventData, 
     pool: &SqlitePool
@@ -179,11 +497,11 @@ pub async fn fetch_event(
 
     sqlx::query_as!(
         Event,
-        "SELECT id, title, description, event_date, start_time, end_time, location, category_id, status, organizer_id, 
+        "SELECT id, title, description, event_date, end_date, start_time, end_time, location, category_id, status, organizer_id, 
                 price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, registration_deadline,
-                is_virtual, image, map_embed, accessibility_info, safety_guidelines, created_at, updated_at
+                is_virtual, image, map_embed, accessibility_info, safety_guidelines, is_template, approval_status, created_at, updated_at
          FROM events 
-         WHERE id = ? AND organizer_id = ?",
+         WHERE id = ? AND organizer_id = ? AND deleted_at IS NULL",
         event_id, organizer_id
     )
         .fetch_one(pool)
@@ -191,79 +509,853 @@ pub async fn fetch_event(
 }
 
 
-/// Inserts a new event into the database.
+/// Retrieves the public view of an event together with its organizer, agenda, speakers and faqs.
+///
+/// Only approved events that are not templates or canceled are public.
 ///
 /// # Arguments
 ///
-/// * `data` - A struct containing all the event data.
+/// * `data` - A struct containing the `event_id`.
 /// * `pool` - A reference to the SQLite connection pool.
 ///
 /// # Returns
 ///
-/// A `Result` containing the newly created `Event`, or an `sqlx::Error` if the insert fails.
+/// A `Result` containing the `PublicEvent`, or an `sqlx::Error` if any query fails.
 ///
 /// # Errors
 ///
-/// Returns an error if the query fails or any constraint is violated.
-pub async fn create_event(
-    data: EventData, 
+/// Returns `sqlx::Error::RowNotFound` if the event does not exist or is not public, or an error
+/// if any query fails.
+pub async fn fetch_public_event(
+    data: GetPublicEventData,
     pool: &SqlitePool
-) -> Result<Event, sqlx::Error> {
-    let rec = sqlx::query_as!(
+) -> Result<PublicEvent, sqlx::Error> {
+    let event = sqlx::query_as!(
         Event,
-        "INSERT INTO events (title, description, event_date, start_time, end_time, location, category_id, status, organizer_id, 
-                     price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, registration_deadline,
-                     is_virtual, image, map_embed, accessibility_info, safety_guidelines, created_at, updated_at) 
-         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-         RETURNING id, title, description, event_date, start_time, end_time, location, category_id, status, 
-                   organizer_id, price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, 
-                   registration_deadline, is_virtual, image, map_embed, accessibility_info, safety_guidelines,
-                   created_at, updated_at",
-        data.title, data.description, data.event_date, data.start_time, data.end_time, data.location, data.category_id, 
-        data.status, data.organizer_id, data.price, data.tickets_sold, data.attendees, data.max_attendees,
-        data.contact_email, data.contact_phone, data.registration_deadline, data.is_virtual, data.image, data.map_embed,
-        data.accessibility_info, data.safety_guidelines, data.created_at, data.updated_at
+        "SELECT id, title, description, event_date, end_date, start_time, end_time, location, category_id, status, organizer_id,
+                price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, registration_deadline,
+                is_virtual, image, map_embed, accessibility_info, safety_guidelines, is_template, approval_status, created_at, updated_at
+         FROM events
+         WHERE id = ? AND status != 'canceled' AND is_template = 0 AND deleted_at IS NULL AND approval_status = 'approved'",
+        data.event_id
     )
         .fetch_one(pool)
         .await?;
 
-    Ok(rec)
+    let (organizer, agenda, speakers, faqs) = tokio::try_join!(
+        fetch_organizer(GetOrganizerData { organizer_id: event.organizer_id }, pool),
+        fetch_agenda(GetAgendaData { event_id: event.id }, pool),
+        fetch_speakers(GetSpeakerData { event_id: event.id }, pool),
+        fetch_faqs(GetFaqData { event_id: event.id }, pool),
+    )?;
+
+    Ok(PublicEvent {
+        id: event.id,
+        title: event.title,
+        description: event.description,
+        event_date: event.event_date,
+        end_date: event.end_date,
+        start_time: event.start_time,
+        end_time: event.end_time,
+        location: event.location,
+        category_id: event.category_id,
+        status: event.status,
+        price: event.price,
+        registration_deadline: event.registration_deadline,
+        is_virtual: event.is_virtual,
+        image: event.image,
+        map_embed: event.map_embed,
+        accessibility_info: event.accessibility_info,
+        safety_guidelines: event.safety_guidelines,
+        organizer: PublicOrganizer { name: organizer.name, logo: organizer.logo },
+        agenda,
+        speakers,
+        faqs,
+    })
 }
 
 
-/// Updates an event in the database.
+/// Retrieves events of the same organizer related to the given event.
+///
+/// Candidates share the event's category or at least one speaker name (case-insensitive);
+/// the current event and draft or canceled events are excluded. Only the
+/// `RELATED_CANDIDATE_POOL` candidates closest in date are scored, with
+/// `RELATED_CATEGORY_WEIGHT` for a shared category plus `RELATED_SPEAKER_WEIGHT` per shared
+/// speaker. The top `RELATED_EVENTS_LIMIT` are returned by score, then by date proximity.
 ///
 /// # Arguments
 ///
-/// * `data` - A struct containing all the event data.
+/// * `event` - The event to find related events for.
 /// * `pool` - A reference to the SQLite connection pool.
 ///
 /// # Returns
 ///
-/// A `Result` containing the newly updated `Event`, or an `sqlx::Error` if the update fails.
+/// A `Result` containing the related `Events`, or an `sqlx::Error` if the query fails.
 ///
 /// # Errors
 ///
-/// Returns an error if the query fails or any constraint is violated.
-pub async fn update_event(
-    data: Event, 
+/// Returns an error if the query fails.
+pub async fn fetch_related_events(
+    event: &Event,
     pool: &SqlitePool
-) -> Result<(), sqlx::Error> {
+) -> Result<Vec<Event>, sqlx::Error> {
     sqlx::query_as!(
         Event,
-        "UPDATE events 
-         SET title = ?, description = ?, event_date = ?, start_time = ?, end_time = ?, location = ?, category_id = ?,
-             status = ?, organizer_id = ?, price = ?, tickets_sold = ?, attendees = ?, max_attendees = ?, 
-             contact_email = ?, contact_phone = ?, registration_deadline = ?, is_virtual = ?, image = ?, map_embed = ?, 
-             accessibility_info = ?, safety_guidelines = ?, updated_at = CURRENT_TIMESTAMP
-         WHERE id = ?",
-        data.title, data.description, data.event_date, data.start_time, data.end_time, data.location, data.category_id, 
-        data.status, data.organizer_id, data.price, data.tickets_sold, data.attendees, data.max_attendees,
-        data.contact_email, data.contact_phone, data.registration_deadline, data.is_virtual, data.image, data.map_embed,
-        data.accessibility_info, data.safety_guidelines, data.id
+        r#"WITH current_speakers AS (
+             SELECT DISTINCT lower(name) AS name FROM speakers WHERE event_id = ?1
+         ),
+         candidates AS (
+             SELECT *
+             FROM events
+             WHERE organizer_id = ?2 AND id != ?1 AND status NOT IN ('draft', 'canceled') AND is_template = 0 AND deleted_at IS NULL
+               AND (category_id = ?3
+                    OR id IN (SELECT event_id FROM speakers WHERE lower(name) IN (SELECT name FROM current_speakers)))
+             ORDER BY ABS(julianday(event_date) - julianday(?4))
+             LIMIT ?5
+         )
+         SELECT id AS "id!", title AS "title!", description, event_date AS "event_date!: _", end_date AS "end_date!: _",
+                start_time AS "start_time!", end_time AS "end_time!", location AS "location!",
+                category_id AS "category_id!", status AS "status!", organizer_id AS "organizer_id!",
+                price AS "price!", tickets_sold AS "tickets_sold!", attendees AS "attendees!",
+                max_attendees AS "max_attendees!", contact_email, contact_phone,
+                registration_deadline AS "registration_deadline!: _", is_virtual AS "is_virtual!",
+                image, map_embed, accessibility_info, safety_guidelines, is_template AS "is_template!", approval_status AS "approval_status!",
+                created_at AS "created_at!: _", updated_at AS "updated_at!: _"
+         FROM candidates
+         ORDER BY (CASE WHEN category_id = ?3 THEN ?6 ELSE 0 END)
+                  + ?7 * (SELECT COUNT(DISTINCT lower(speakers.name))
+                          FROM speakers
+                          WHERE speakers.event_id = candidates.id
+                            AND lower(speakers.name) IN (SELECT name FROM current_speakers)) DESC,
+                  ABS(julianday(event_date) - julianday(?4)),
+                  id
+         LIMIT ?8"#,
+        event.id, event.organizer_id, event.category_id, event.event_date, RELATED_CANDIDATE_POOL,
+        RELATED_CATEGORY_WEIGHT, RELATED_SPEAKER_WEIGHT, RELATED_EVENTS_LIMIT
     )
-        .execute(pool)
-        .await?;
+        .fetch_all(pool)
+        .await
+}
 
-    Ok(())
-}
\ No newline at end of file
+
+/// Retrieves the related detail information of an event.
+///
+/// Each detail collection is fetched independently; a collection that fails to load
+/// is returned empty rather than failing the whole request.
+///
+/// # Arguments
+///
+/// * `event` - The event to fetch details for.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// The `EventDetails` of the event.
+pub async fn fetch_event_details(
+    event: &Event,
+    pool: &SqlitePool
+) -> EventDetails {
+    // The collections are independent, so fetch them concurrently: the total latency is that of
+    // the slowest query rather than the sum. Each query holds one pooled connection only while it
+    // runs, so a busy pool makes them wait for a connection instead of failing.
+    let (
+        organizer_info,
+        agenda_items,
+        speaker_items,
+        faq_items,
+        attachment_items,
+        comment_items,
+        related_events,
+    ) = tokio::join!(
+        fetch_organizer(GetOrganizerData { organizer_id: event.organizer_id }, pool),
+        fetch_agenda(GetAgendaData { event_id: event.id }, pool),
+        fetch_speakers(GetSpeakerData { event_id: event.id }, pool),
+        fetch_faqs(GetFaqData { event_id: event.id }, pool),
+        fetch_attachments(GetAttachmentData { event_id: event.id }, pool),
+        fetch_comments(GetCommentData { event_id: event.id }, pool),
+        fetch_related_events(event, pool),
+    );
+
+    EventDetails {
+        organizer: organizer_info.unwrap_or_else(|_| Organizer::default()),
+        agenda: agenda_items.unwrap_or_else(|_| vec![]),
+        speakers: speaker_items.unwrap_or_else(|_| vec![]),
+        faqs: faq_items.unwrap_or_else(|_| vec![]),
+        attachments: attachment_items.unwrap_or_else(|_| vec![]),
+        comments: comment_items.unwrap_or_else(|_| vec![]),
+        related_events: related_events.unwrap_or_else(|_| vec![]),
+    }
+}
+
+
+/// Retrieves the revision of everything the event details are built from.
+///
+/// The revision is kept on the event's organizer and bumped by triggers on every write to the
+/// organizer, their events and the events' detail rows, so it changes whenever the details or
+/// the related events could, and can be compared instead of reloading the details.
+///
+/// # Arguments
+///
+/// * `event` - The event the details belong to.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the revision, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn fetch_event_details_revision(
+    event: &Event,
+    pool: &SqlitePool
+) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar!(
+        "SELECT details_revision FROM organizers WHERE id = ?",
+        event.organizer_id
+    )
+        .fetch_one(pool)
+        .await
+}
+
+
+/// Retrieves the capacity warning of an event, if any.
+///
+/// # Arguments
+///
+/// * `event_id` - Unique identifier of the event.
+/// * `config` - The capacity warning settings.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the `CapacityWarning`, or `None` if the event is not near capacity.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if the event does not exist, or an error if the query fails.
+pub async fn fetch_capacity_warning(
+    event_id: i64,
+    config: &CapacityWarningConfig,
+    pool: &SqlitePool
+) -> Result<Option<CapacityWarning>, sqlx::Error> {
+    let event = sqlx::query!(
+        "SELECT tickets_sold, max_attendees FROM events WHERE id = ?",
+        event_id
+    )
+        .fetch_one(pool)
+        .await?;
+
+    Ok(config.warning(event.tickets_sold, event.max_attendees))
+}
+
+
+/// Retrieves an event together with every child collection for export.
+///
+/// Unlike `fetch_event_details`, a collection that fails to load fails the export.
+///
+/// # Arguments
+///
+/// * `event` - The event to export.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the `EventExport`, or an `sqlx::Error` if any collection fails to load.
+///
+/// # Errors
+///
+/// Returns an error if any of the queries fail.
+pub async fn fetch_event_export(
+    event: Event,
+    pool: &SqlitePool
+) -> Result<EventExport, sqlx::Error> {
+    let event_id = event.id;
+
+    Ok(EventExport {
+        agenda: fetch_agenda(GetAgendaData { event_id }, pool).await?,
+        speakers: fetch_speakers(GetSpeakerData { event_id }, pool).await?,
+        faqs: fetch_faqs(GetFaqData { event_id }, pool).await?,
+        attachments: fetch_attachments(GetAttachmentData { event_id }, pool).await?,
+        comments: fetch_comments(GetCommentData { event_id }, pool).await?,
+        attendees: fetch_attendees_by_event(GetAttendeeData { event_id }, pool).await?,
+        event,
+    })
+}
+
+
+/// Inserts a new event into the database.
+///
+/// `created_at` and `updated_at` are set to the current UTC time by the database.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing all the event data.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` containing the newly created `Event`, or an `sqlx::Error` if the insert fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails or any constraint is violated.
+pub async fn create_event(
+    data: EventData, 
+    conn: &mut SqliteConnection
+) -> Result<Event, sqlx::Error> {
+    debug!(target: "event", "Creating event '{}' on {} for organizer {} (category {}, status '{}')", data.title, data.event_date, data.organizer_id, data.category_id, data.status);
+
+    let rec = sqlx::query_as!(
+        Event,
+        "INSERT INTO events (title, description, event_date, end_date, start_time, end_time, location, category_id, status, 
+                     organizer_id, price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, 
+                     registration_deadline, is_virtual, image, map_embed, accessibility_info, safety_guidelines, is_template,
+                     approval_status, created_at, updated_at) 
+         VALUES (?, ?, ?, COALESCE(?, ?), ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+         RETURNING id, title, description, event_date, end_date, start_time, end_time, location, category_id, status, 
+                   organizer_id, price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, 
+                   registration_deadline, is_virtual, image, map_embed, accessibility_info, safety_guidelines,
+                   is_template, approval_status, created_at, updated_at",
+        data.title, data.description, data.event_date, data.end_date, data.event_date, data.start_time, data.end_time, 
+        data.location, data.category_id, data.status, data.organizer_id, data.price, data.tickets_sold, data.attendees, data.max_attendees,
+        data.contact_email, data.contact_phone, data.registration_deadline, data.is_virtual, data.image, data.map_embed,
+        data.accessibility_info, data.safety_guidelines, data.is_template, data.approval_status
+    )
+        .fetch_one(conn)
+        .await?;
+
+    Ok(rec)
+}
+
+
+/// Recreates an exported event tree under an organizer with fresh ids.
+///
+/// Everything is inserted in a single transaction. Agenda items are relinked to the
+/// newly created speakers, falling back to matching by speaker name.
+///
+/// # Arguments
+///
+/// * `export` - The validated event tree to import.
+/// * `organizer_id` - Identifier of the organizer to import the event under.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the imported tree with its new ids, or an `sqlx::Error` if any insert fails.
+///
+/// # Errors
+///
+/// Returns an error if any query fails or any constraint is violated; nothing is inserted in that case.
+pub async fn import_event(
+    export: EventExport,
+    organizer_id: i64,
+    pool: &SqlitePool
+) -> Result<EventExport, sqlx::Error> {
+    let EventExport { event, mut agenda, speakers, faqs, attachments, comments, attendees } = export;
+
+    debug!(target: "event", "Importing event {} for organizer {}", event.id, organizer_id);
+
+    let mut tx = pool.begin().await?;
+
+    let event = create_event(EventData { organizer_id, ..EventData::from(event) }, &mut tx).await?;
+
+    let speaker_ids: Vec<i64> = speakers.iter().map(|speaker| speaker.id).collect();
+    let speakers = create_speakers(
+        speakers.into_iter().map(|speaker| Speaker { event_id: event.id, ..speaker }).collect(),
+        &mut tx
+    ).await?;
+    let new_speaker_ids: HashMap<i64, i64> = speaker_ids.into_iter()
+        .zip(speakers.iter().map(|speaker| speaker.id))
+        .collect();
+
+    for agenda_item in agenda.iter_mut() {
+        agenda_item.event_id = event.id;
+        agenda_item.speaker_id = agenda_item.speaker_id.and_then(|speaker_id| new_speaker_ids.get(&speaker_id).copied());
+    }
+    link_agenda_speakers(&mut agenda, &speakers);
+
+    let agenda = create_agenda(agenda, &mut tx).await?;
+    let faqs = create_faqs(
+        faqs.into_iter().map(|faq| Faq { event_id: event.id, ..faq }).collect(),
+        &mut tx
+    ).await?;
+    let attachments = create_attachments(
+        attachments.into_iter().map(|attachment| Attachment { event_id: event.id, ..attachment }).collect(),
+        &mut tx
+    ).await?;
+    // Comments are exported newest first; insert them oldest first so they keep that order
+    let mut comments = create_comments(
+        comments.into_iter().rev().map(|comment| Comment { event_id: event.id, ..comment }).collect(),
+        &mut tx
+    ).await?;
+    comments.reverse();
+    let attendees = import_attendees(
+        attendees.into_iter().map(|attendee| Attendee { event_id: event.id, ..attendee }).collect(),
+        &mut tx
+    ).await?;
+
+    tx.commit().await?;
+
+    Ok(EventExport { event, agenda, speakers, faqs, attachments, comments, attendees })
+}
+
+
+/// Creates a new event from an existing event or template, copying its speakers,
+/// agenda, faqs and attachments.
+///
+/// Everything is inserted in a single transaction.
+///
+/// # Arguments
+///
+/// * `source_event_id` - Unique identifier of the event or template to copy details from.
+/// * `data` - The data of the new event.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the newly created `Event`, or an `sqlx::Error` if any insert fails.
+///
+/// # Errors
+///
+/// Returns an error if any query fails or any constraint is violated; nothing is inserted in that case.
+pub async fn create_event_from(
+    source_event_id: i64,
+    data: EventData,
+    pool: &SqlitePool
+) -> Result<Event, sqlx::Error> {
+    debug!(target: "event", "Duplicating event {} onto {}", source_event_id, data.event_date);
+
+    let mut tx = pool.begin().await?;
+
+    let event = create_event(data, &mut tx).await?;
+
+    copy_speakers(source_event_id, event.id, &mut tx).await?;
+    copy_agenda(source_event_id, event.id, &mut tx).await?;
+    copy_faqs(source_event_id, event.id, &mut tx).await?;
+    copy_attachments(source_event_id, event.id, &mut tx).await?;
+
+    tx.commit().await?;
+
+    Ok(event)
+}
+
+
+/// Updates an event in the database.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing all the event data.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the newly updated `Event`, or an `sqlx::Error` if the update fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails or any constraint is violated.
+pub async fn update_event(
+    data: Event, 
+    pool: &SqlitePool
+) -> Result<(), sqlx::Error> {
+    debug!(target: "event", "Updating event {} ('{}' on {}, status '{}')", data.id, data.title, data.event_date, data.status);
+
+    sqlx::query_as!(
+        Event,
+        "UPDATE events 
+         SET title = ?, description = ?, event_date = ?, end_date = ?, start_time = ?, end_time = ?, location = ?, 
+             category_id = ?, status = ?, organizer_id = ?, price = ?, tickets_sold = ?, attendees = ?, max_attendees = ?, 
+             contact_email = ?, contact_phone = ?, registration_deadline = ?, is_virtual = ?, image = ?, map_embed = ?, 
+             accessibility_info = ?, safety_guidelines = ?, is_template = ?, updated_at = CURRENT_TIMESTAMP
+         WHERE id = ?",
+        data.title, data.description, data.event_date, data.end_date, data.start_time, data.end_time, data.location, 
+        data.category_id, data.status, data.organizer_id, data.price, data.tickets_sold, data.attendees, data.max_attendees,
+        data.contact_email, data.contact_phone, data.registration_deadline, data.is_virtual, data.image, data.map_embed,
+        data.accessibility_info, data.safety_guidelines, data.is_template, data.id
+    )
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+
+/// Counts the events, organizer logos and speaker photos, of any organizer, that use the given path.
+///
+/// # Arguments
+///
+/// * `image` - The image path to look for.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the number of references to the image, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn count_image_references(
+    image: &str,
+    pool: &SqlitePool
+) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"SELECT (SELECT COUNT(*) FROM events WHERE image = ?1)
+                + (SELECT COUNT(*) FROM organizers WHERE logo = ?1)
+                + (SELECT COUNT(*) FROM speakers WHERE photo = ?1) AS "count!: i64""#,
+        image
+    )
+        .fetch_one(pool)
+        .await
+}
+
+
+/// Sets the approval status of an event, regardless of its organizer.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id` and the new `approval_status`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the updated `Event`, or an `sqlx::Error` if the update fails.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if the event does not exist or is deleted, or another error if the query fails.
+pub async fn update_event_approval(
+    data: UpdateEventApprovalData,
+    pool: &SqlitePool
+) -> Result<Event, sqlx::Error> {
+    debug!(target: "event", "Setting approval status of event {} to '{}'", data.event_id, data.approval_status);
+
+    sqlx::query_as!(
+        Event,
+        "UPDATE events
+         SET approval_status = ?, updated_at = CURRENT_TIMESTAMP
+         WHERE id = ? AND deleted_at IS NULL
+         RETURNING id, title, description, event_date, end_date, start_time, end_time, location, category_id, status, 
+                   organizer_id, price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, 
+                   registration_deadline, is_virtual, image, map_embed, accessibility_info, safety_guidelines,
+                   is_template, approval_status, created_at, updated_at",
+        data.approval_status, data.event_id
+    )
+        .fetch_one(pool)
+        .await
+}
+
+
+/// Fetches which of the given events belong to an organizer and are not deleted.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `organizer_id` and the event IDs to check.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` containing the IDs of the given events the organizer owns, or an `sqlx::Error`
+/// if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn fetch_owned_event_ids(
+    data: GetOwnedEventIdsData,
+    conn: &mut SqliteConnection
+) -> Result<Vec<i64>, sqlx::Error> {
+    let ids = serde_json::to_string(&data.event_ids).unwrap_or_else(|_| "[]".to_string());
+
+    sqlx::query_scalar!(
+        "SELECT id FROM events WHERE organizer_id = ? AND deleted_at IS NULL AND id IN (SELECT value FROM json_each(?))",
+        data.organizer_id, ids
+    )
+        .fetch_all(&mut *conn)
+        .await
+}
+
+
+/// Sets the status of several events of an organizer.
+///
+/// Only events belonging to the organizer are updated; other IDs are ignored.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `organizer_id`, the event IDs and the new status.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` containing the number of updated events, or an `sqlx::Error` if the update fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn update_event_statuses(
+    data: UpdateEventStatusesData,
+    conn: &mut SqliteConnection
+) -> Result<u64, sqlx::Error> {
+    debug!(target: "event", "Setting status of events {:?} of organizer {} to '{}'", data.event_ids, data.organizer_id, data.status);
+
+    let ids = serde_json::to_string(&data.event_ids).unwrap_or_else(|_| "[]".to_string());
+
+    let result = sqlx::query!(
+        "UPDATE events
+         SET status = ?, updated_at = CURRENT_TIMESTAMP
+         WHERE organizer_id = ? AND id IN (SELECT value FROM json_each(?))",
+        data.status, data.organizer_id, ids
+    )
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(result.rows_affected())
+}
+
+
+/// Soft-deletes an event of an organizer, hiding it from every listing and analytics query
+/// while keeping its row and related records.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id` and `organizer_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing `()` if the event was deleted, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if the organizer owns no such event or it is already
+/// deleted, or another error if the query fails.
+pub async fn soft_delete_event(
+    data: DeleteEventData,
+    pool: &SqlitePool
+) -> Result<(), sqlx::Error> {
+    debug!(target: "event", "Deleting event {} of organizer {}", data.event_id, data.organizer_id);
+
+    let result = sqlx::query!(
+        "UPDATE events
+         SET deleted_at = CURRENT_TIMESTAMP, updated_at = CURRENT_TIMESTAMP
+         WHERE id = ? AND organizer_id = ? AND deleted_at IS NULL",
+        data.event_id, data.organizer_id
+    )
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(sqlx::Error::RowNotFound);
+    }
+
+    Ok(())
+}
+
+
+/// Restores a soft-deleted event of an organizer.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id` and `organizer_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the restored `Event`, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if the organizer has no such deleted event, or another
+/// error if the query fails.
+pub async fn restore_event(
+    data: DeleteEventData,
+    pool: &SqlitePool
+) -> Result<Event, sqlx::Error> {
+    debug!(target: "event", "Restoring event {} of organizer {}", data.event_id, data.organizer_id);
+
+    sqlx::query_as!(
+        Event,
+        "UPDATE events
+         SET deleted_at = NULL, updated_at = CURRENT_TIMESTAMP
+         WHERE id = ? AND organizer_id = ? AND deleted_at IS NOT NULL
+         RETURNING id, title, description, event_date, end_date, start_time, end_time, location, category_id, status, 
+                   organizer_id, price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, 
+                   registration_deadline, is_virtual, image, map_embed, accessibility_info, safety_guidelines,
+                   is_template, approval_status, created_at, updated_at",
+        data.event_id, data.organizer_id
+    )
+        .fetch_one(pool)
+        .await
+}
+
+
+/// Fetches an organizer's soft-deleted events, most recently deleted first.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `organizer_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the `DeletedEvent`s, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn fetch_deleted_events(
+    data: GetDeletedEventsData,
+    pool: &SqlitePool
+) -> Result<Vec<DeletedEvent>, sqlx::Error> {
+    sqlx::query_as!(
+        DeletedEvent,
+        r#"SELECT id, title, event_date, end_date, status, is_template, deleted_at AS "deleted_at!: _"
+         FROM events
+         WHERE organizer_id = ? AND deleted_at IS NOT NULL
+         ORDER BY deleted_at DESC, id DESC"#,
+        data.organizer_id
+    )
+        .fetch_all(pool)
+        .await
+}
+
+
+/// Marks every upcoming event whose last day has passed as complete.
+///
+/// Canceled events and templates are left untouched.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the number of events updated, or an `sqlx::Error` if the update fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn complete_past_events(
+    pool: &SqlitePool
+) -> Result<u64, sqlx::Error> {
+    debug!(target: "event", "Completing past upcoming events");
+
+    let result = sqlx::query!(
+        "UPDATE events
+         SET status = 'complete', updated_at = CURRENT_TIMESTAMP
+         WHERE status = 'upcoming' AND end_date < CURRENT_DATE AND is_template = 0 AND deleted_at IS NULL"
+    )
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected())
+}
+
+
+
+/// Counts an event's confirmed and checked-in attendees from the attendees table.
+///
+/// # Arguments
+///
+/// * `event_id` - Unique identifier of the event.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the `LiveAttendeeCounts`, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn fetch_live_attendee_counts(
+    event_id: i64,
+    pool: &SqlitePool
+) -> Result<LiveAttendeeCounts, sqlx::Error> {
+    sqlx::query_as!(
+        LiveAttendeeCounts,
+        r#"SELECT COUNT(*) AS "tickets_sold!: i64",
+                  COALESCE(SUM(checked_in), 0) AS "attendees!: i64"
+           FROM attendees
+           WHERE event_id = ? AND confirmed = 1"#,
+        event_id
+    )
+        .fetch_one(pool)
+        .await
+}
+
+
+/// Overwrites an event's `tickets_sold` and `attendees` with the counts from its attendee rows.
+///
+/// `tickets_sold` becomes the number of confirmed attendees and `attendees` the number of
+/// those checked in, so figures recorded without attendee rows are discarded.
+///
+/// # Arguments
+///
+/// * `event_id` - Unique identifier of the event.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the `LiveAttendeeCounts` written to the event.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn reconcile_attendee_counts(
+    event_id: i64,
+    pool: &SqlitePool
+) -> Result<LiveAttendeeCounts, sqlx::Error> {
+    debug!(target: "event", "Reconciling attendee counts of event {}", event_id);
+
+    sqlx::query_as!(
+        LiveAttendeeCounts,
+        r#"UPDATE events
+           SET tickets_sold = (SELECT COUNT(*) FROM attendees WHERE event_id = events.id AND confirmed = 1),
+               attendees = (SELECT COUNT(*) FROM attendees WHERE event_id = events.id AND confirmed = 1 AND checked_in = 1),
+               updated_at = CURRENT_TIMESTAMP
+           WHERE id = ?
+           RETURNING tickets_sold AS "tickets_sold!: i64", attendees AS "attendees!: i64""#,
+        event_id
+    )
+        .fetch_one(pool)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_utils::{create_test_event, create_test_organizer, test_pool};
+
+    async fn set_dates(event_id: i64, event_offset: &str, end_offset: &str, pool: &SqlitePool) {
+        sqlx::query("UPDATE events SET event_date = date('now', ?), end_date = date('now', ?) WHERE id = ?")
+            .bind(event_offset)
+            .bind(end_offset)
+            .bind(event_id)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    async fn status(event_id: i64, pool: &SqlitePool) -> String {
+        sqlx::query_scalar("SELECT status FROM events WHERE id = ?")
+            .bind(event_id)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[actix_web::test]
+    async fn complete_past_events_waits_for_last_day() {
+        let pool = test_pool().await;
+        let (organizer_id, _) = create_test_organizer("organizer", &pool).await;
+        let running = create_test_event(organizer_id, &pool).await;
+        let ended = create_test_event(organizer_id, &pool).await;
+        set_dates(running, "-2 days", "+1 day", &pool).await;
+        set_dates(ended, "-3 days", "-1 day", &pool).await;
+
+        assert_eq!(complete_past_events(&pool).await.unwrap(), 1);
+        assert_eq!(status(running, &pool).await, "upcoming");
+        assert_eq!(status(ended, &pool).await, "complete");
+    }
+}
@@ -0,0 +1,233 @@
+// External Libraries
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use log::{debug, warn};
+use rand::RngCore;
+use sqlx::SqlitePool;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+
+// Internal Mappers
+use crate::event::mapper::count_image_references;
+
+
+/// Directory served under the `/static` mount.
+pub const STATIC_DIR: &str = "static";
+
+/// Subdirectory of `STATIC_DIR` that uploaded images are written to.
+pub const UPLOAD_DIR: &str = "uploads";
+
+/// Returns the file extension of a PNG, JPEG or WebP image, judged by its leading magic bytes.
+fn image_extension(
+    bytes: &[u8]
+) -> Option<&'static str> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("png")
+    } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("jpg")
+    } else if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+        Some("webp")
+    } else {
+        None
+    }
+}
+
+
+/// Returns whether an image value is an inline `data:` URI rather than a stored path or link.
+pub fn is_data_uri(
+    image: &str
+) -> bool {
+    image.starts_with("data:")
+}
+
+
+/// Returns whether an image value is a file stored by `save_image`, as opposed to a seeded
+/// static asset or an external link.
+pub fn is_uploaded_image(
+    image: &str
+) -> bool {
+    image.strip_prefix(UPLOAD_DIR)
+        .and_then(|path| path.strip_prefix('/'))
+        .is_some_and(is_static_file)
+}
+
+
+/// Returns whether an image value names a file directly inside `STATIC_DIR`, such as a seeded
+/// organizer logo, rather than a path into another directory or an external link.
+fn is_static_file(
+    image: &str
+) -> bool {
+    !image.is_empty() && !image.contains(['/', '\\']) && !image.starts_with('.')
+}
+
+
+/// Decodes a base64 image data URI and writes it to the upload directory.
+///
+/// The image type is taken from the decoded file's magic bytes, not from the MIME type the
+/// URI declares, so only actual PNG, JPEG and WebP files are stored.
+///
+/// # Arguments
+///
+/// * `data_uri` - The image as a `data:<mime>;base64,<payload>` URI.
+/// * `max_bytes` - Largest decoded image size accepted.
+///
+/// # Returns
+///
+/// The stored image's path relative to the `/static` mount, e.g. `uploads/3f9c….png`.
+///
+/// # Errors
+///
+/// Returns an `ErrorKind::InvalidInput` error if the URI is malformed, not base64, not a PNG,
+/// JPEG or WebP image or larger than `max_bytes`, or any other error if the file cannot be written.
+pub fn save_image(
+    data_uri: &str,
+    max_bytes: usize
+) -> Result<String, Error> {
+    let (header, payload) = data_uri.strip_prefix("data:")
+        .and_then(|uri| uri.split_once(','))
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "must be a data URI"))?;
+
+    if !header.ends_with(";base64") {
+        return Err(Error::new(ErrorKind::InvalidInput, "must be base64 encoded"));
+    }
+
+    // Reject oversized payloads before decoding them
+    if payload.len() / 4 * 3 > max_bytes + 3 {
+        return Err(Error::new(ErrorKind::InvalidInput, format!("must not be larger than {} bytes", max_bytes)));
+    }
+
+    let bytes = STANDARD.decode(payload)
+        .map_err(|e| Error::new(ErrorKind::InvalidInput, format!("invalid base64: {}", e)))?;
+
+    if bytes.len() > max_bytes {
+        return Err(Error::new(ErrorKind::InvalidInput, format!("must not be larger than {} bytes", max_bytes)));
+    }
+
+    let extension = image_extension(&bytes)
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "unsupported image type (expected png, jpeg or webp)"))?;
+
+    let mut name = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut name);
+    let name: String = name.iter().map(|byte| format!("{:02x}", byte)).collect();
+
+    let relative_path = format!("{}/{}.{}", UPLOAD_DIR, name, extension);
+
+    fs::create_dir_all(Path::new(STATIC_DIR).join(UPLOAD_DIR))?;
+    fs::write(Path::new(STATIC_DIR).join(&relative_path), bytes)?;
+
+    Ok(relative_path)
+}
+
+
+/// Deletes an uploaded image file.
+///
+/// # Arguments
+///
+/// * `image` - The image's path relative to the `/static` mount.
+///
+/// # Errors
+///
+/// Returns an `ErrorKind::InvalidInput` error if the path is not an uploaded image, or any
+/// error raised while removing the file.
+pub fn delete_image(
+    image: &str
+) -> Result<(), Error> {
+    if !is_uploaded_image(image) {
+        return Err(Error::new(ErrorKind::InvalidInput, format!("'{}' is not an uploaded image", image)));
+    }
+
+    fs::remove_file(Path::new(STATIC_DIR).join(image))
+}
+
+
+/// Deletes an uploaded image once no event, organizer or speaker references it any more.
+///
+/// Seeded assets and external links are left alone. Failures, including a file that is
+/// already missing, are logged rather than returned so they never fail the calling request.
+///
+/// # Arguments
+///
+/// * `image` - The image's path relative to the `/static` mount.
+/// * `pool` - A reference to the SQLite connection pool.
+pub async fn release_image(
+    image: &str,
+    pool: &SqlitePool
+) {
+    if is_uploaded_image(image) {
+        delete_unused_file(image, pool).await;
+    }
+}
+
+
+/// Deletes an organizer logo once no event, organizer or speaker references it any more.
+///
+/// Unlike `release_image`, this also removes logos stored directly in `STATIC_DIR`. External
+/// links and paths leaving `STATIC_DIR` are left alone, and failures are only logged.
+///
+/// # Arguments
+///
+/// * `logo` - The logo's path relative to the `/static` mount.
+/// * `pool` - A reference to the SQLite connection pool.
+pub async fn release_logo(
+    logo: &str,
+    pool: &SqlitePool
+) {
+    if is_uploaded_image(logo) || is_static_file(logo) {
+        delete_unused_file(logo, pool).await;
+    }
+}
+
+
+/// Deletes a file below `STATIC_DIR` unless it is still referenced, logging the outcome.
+async fn delete_unused_file(
+    image: &str,
+    pool: &SqlitePool
+) {
+    match count_image_references(image, pool).await {
+        Ok(0) => {},
+        Ok(count) => {
+            debug!(target: "event", "Keeping image {} still referenced {} time(s)", image, count);
+            return;
+        },
+        Err(e) => {
+            warn!(target: "event", "Failed to check whether image {} is still used: {}", image, e);
+            return;
+        },
+    }
+
+    match fs::remove_file(Path::new(STATIC_DIR).join(image)) {
+        Ok(()) => debug!(target: "event", "Deleted unused image {}", image),
+        Err(e) => warn!(target: "event", "Failed to delete unused image {}: {}", image, e),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn image_extension_recognizes_supported_types() {
+        assert_eq!(image_extension(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"), Some("png"));
+        assert_eq!(image_extension(&[0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10]), Some("jpg"));
+        assert_eq!(image_extension(b"RIFF\x24\0\0\0WEBPVP8 "), Some("webp"));
+    }
+
+    #[test]
+    fn is_static_file_rejects_other_directories() {
+        assert!(is_static_file("future-tech-logo.png"));
+        assert!(!is_static_file("../database.db"));
+        assert!(!is_static_file("uploads/logo.png"));
+        assert!(!is_static_file("https://example.com/logo.png"));
+        assert!(!is_static_file("..\\database.db"));
+        assert!(!is_static_file(".env"));
+        assert!(!is_static_file(""));
+    }
+
+    #[test]
+    fn image_extension_rejects_other_content() {
+        assert_eq!(image_extension(b"<svg xmlns=\"http://www.w3.org/2000/svg\"/>"), None);
+        assert_eq!(image_extension(b"RIFF\x24\0\0\0WAVEfmt "), None);
+        assert_eq!(image_extension(b""), None);
+    }
+}
@@ -1,4 +1,6 @@
 // Internal Modules
 pub mod mapper;
+pub mod media;
 pub mod models;
 pub mod routes;
+pub mod validation;
\ No newline at end of file
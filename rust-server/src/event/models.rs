@@ -1,14 +1,17 @@
 // External Libraries
 use serde::{Serialize, Deserialize};
 use chrono::{NaiveDate, NaiveDateTime};
+use std::collections::BTreeMap;
 
 // Internal Models
-use crate::organizer::models::{Organizer};
+use crate::organizer::models::{Organizer, PublicOrganizer};
 use crate::agenda::models::{Agenda};
 use crate::speaker::models::{Speaker};
 use crate::faq::models::{Faq};
 use crate::attachment::models::{Attachment};
+use crate::ticket_tier::models::TierSales;
 use crate::comment::models::{Comment};
+use crate::attendee::models::{Attendee};
 use crate::overview::models::CountByDate;
 
 
@@ -27,6 +30,10 @@ pub struct Event {
     /// The date of the event (required for yearly/monthly queries).
     pub event_date: NaiveDate,
 
+    /// The last day of the event; equal to `event_date` for single-day events.
+    /// Monthly and daily analytics count multi-day events once, under `event_date`.
+    pub end_date: NaiveDate,
+
     /// Start time of the event in string format.
     pub start_time: String,
 
@@ -69,7 +76,8 @@ pub struct Event {
     /// Flag indicating whether the event is virtual.
     pub is_virtual: i64,
 
-    /// Optional encoded image or image link url
+    /// Optional image: a path under `/static`, an image link url, or on update a base64
+    /// `data:` URI that is stored as a file and replaced by its path
     pub image: Option<String>,
 
     /// Optional embedded map link url
@@ -81,10 +89,21 @@ pub struct Event {
     /// Optional safety guidelines
     pub safety_guidelines: Option<String>,
 
-    /// Timestamp for when the event was created.
+    /// Flag indicating whether the event is a reusable template rather than a real event.
+    #[serde(default)]
+    pub is_template: i64,
+
+    /// Moderation state of the event ("pending", "approved" or "rejected"); only approved
+    /// events are shown publicly.
+    #[serde(default)]
+    pub approval_status: String,
+
+    /// Timestamp for when the event was created; set by the server, never by the client.
+    #[serde(skip_deserializing)]
     pub created_at: NaiveDateTime,
 
-    /// Timestamp for the last update to the event.
+    /// Timestamp for the last update to the event; set by the server, never by the client.
+    #[serde(skip_deserializing)]
     pub updated_at: NaiveDateTime,
 }
 
@@ -101,6 +120,10 @@ pub struct EventData {
     /// The date of the event (required for yearly/monthly queries).
     pub event_date: NaiveDate,
 
+    /// The last day of a multi-day event; defaults to `event_date` when omitted.
+    #[serde(default)]
+    pub end_date: Option<NaiveDate>,
+
     /// Start time of the event in string format.
     pub start_time: String,
 
@@ -113,7 +136,8 @@ pub struct EventData {
     /// Identifier for the associated category.
     pub category_id: i64,
 
-    /// Status of the event ("upcoming", "canceled", etc.).
+    /// Status of the event; one of `EVENT_STATUSES`, defaulting to the configured status when empty.
+    #[serde(default)]
     pub status: String,
 
     /// Identifier for the event organizer.
@@ -137,13 +161,14 @@ pub struct EventData {
     /// Phone contact for the event.
     pub contact_phone: String,
 
-    /// Deadline for event registration.
+    /// Deadline for event registration in `YYYY-MM-DD` format.
     pub registration_deadline: String,
 
     /// Flag indicating whether the event is virtual.
     pub is_virtual: i64,
 
-    /// Optional encoded image or image link url
+    /// Optional image: a path under `/static`, an image link url, or on creation a base64
+    /// `data:` URI that is stored as a file and replaced by its path
     pub image: Option<String>,
 
     /// Optional embedded map link url
@@ -155,11 +180,13 @@ pub struct EventData {
     /// Optional safety guidelines
     pub safety_guidelines: Option<String>,
 
-    /// Timestamp for when the event was created.
-    pub created_at: String,
+    /// Flag indicating whether the event is a reusable template rather than a real event.
+    #[serde(default)]
+    pub is_template: i64,
 
-    /// Timestamp for the last update to the event.
-    pub updated_at: String,
+    /// Moderation state of the event; set by the server, never by the client.
+    #[serde(skip_deserializing)]
+    pub approval_status: String,
 }
 
 
@@ -168,6 +195,22 @@ pub struct EventData {
 pub struct GetUserEventsQuery {
     /// The year to retrieve totals for (e.g., 2025).
     pub year: i64,
+
+    /// Whether to also include multi-day events that end, but do not start, in the year.
+    #[serde(default)]
+    pub include_spanning: bool,
+
+    /// Optional status the events must have.
+    pub status: Option<String>,
+
+    /// Optional category the events must belong to.
+    pub category_id: Option<i64>,
+
+    /// Optional column to sort by; one of `EVENT_SORT_COLUMNS`, defaulting to `event_date`.
+    pub sort_by: Option<String>,
+
+    /// Optional sort direction; one of `SORT_ORDERS`, defaulting to `asc`.
+    pub order: Option<String>,
 }
 
 
@@ -179,6 +222,365 @@ pub struct GetUserEventsData {
     
     /// The year to retrieve totals for (e.g., 2025).
     pub year: i64,
+
+    /// Whether to also include multi-day events that end, but do not start, in the year.
+    pub include_spanning: bool,
+
+    /// Status the events must have, or `None` for any status.
+    pub status: Option<String>,
+
+    /// Category the events must belong to, or `None` for any category.
+    pub category_id: Option<i64>,
+
+    /// Column to sort by; one of `EVENT_SORT_COLUMNS`.
+    pub sort_by: String,
+
+    /// Whether to sort in descending rather than ascending order.
+    pub descending: bool,
+}
+
+
+/// Result of a dry-run validation of event data.
+#[derive(Serialize)]
+pub struct EventValidation {
+    /// Whether the event data passed every create-time validation.
+    pub valid: bool,
+}
+
+
+/// Query parameters for creating or updating event details.
+#[derive(Deserialize)]
+pub struct EventDetailsQuery {
+    /// Whether agenda speakers must match a speaker of the event.
+    #[serde(default)]
+    pub strict_speakers: bool,
+}
+
+
+/// Query parameters for paginating a list of events or other items.
+#[derive(Deserialize)]
+pub struct PaginationQuery {
+    /// Maximum number of items to return.
+    pub limit: Option<i64>,
+
+    /// Number of items to skip.
+    pub offset: Option<i64>,
+}
+
+
+/// Query parameters for paginating a user's events across all years.
+#[derive(Deserialize)]
+pub struct GetAllUserEventsQuery {
+    /// Maximum number of events to return.
+    pub limit: Option<i64>,
+
+    /// Number of events to skip.
+    pub offset: Option<i64>,
+
+    /// Whether to also include events in the trash.
+    #[serde(default)]
+    pub include_deleted: bool,
+}
+
+
+/// Data required to retrieve a page of a user's events across all years.
+#[derive(Deserialize)]
+pub struct GetAllUserEventsData {
+    /// Identifier for the event organizer.
+    pub organizer_id: i64,
+
+    /// Maximum number of events to return.
+    pub limit: i64,
+
+    /// Number of events to skip.
+    pub offset: i64,
+
+    /// Whether to also include events in the trash.
+    pub include_deleted: bool,
+}
+
+
+/// Query parameters for searching events by keyword.
+#[derive(Deserialize)]
+pub struct SearchEventsQuery {
+    /// Keyword matched against the title, description and location.
+    pub q: String,
+}
+
+
+/// Data required to search a user's events by keyword.
+pub struct SearchEventsData {
+    /// Identifier for the event organizer.
+    pub organizer_id: i64,
+
+    /// Keyword matched literally against the title, description and location.
+    pub query: String,
+}
+
+
+/// Data required to change the approval status of an event.
+pub struct UpdateEventApprovalData {
+    /// Unique identifier of the event to moderate.
+    pub event_id: i64,
+
+    /// New approval status of the event.
+    pub approval_status: String,
+}
+
+
+/// Request body for changing the status of several events at once.
+#[derive(Deserialize)]
+pub struct BulkStatusData {
+    /// Unique identifiers of the events to update.
+    pub event_ids: Vec<i64>,
+
+    /// New status of the events.
+    pub status: String,
+}
+
+
+/// Data required to find which of the given events an organizer owns.
+pub struct GetOwnedEventIdsData {
+    /// Identifier for the event organizer.
+    pub organizer_id: i64,
+
+    /// Unique identifiers of the events to check.
+    pub event_ids: Vec<i64>,
+}
+
+
+/// Data required to change the status of several events of an organizer.
+pub struct UpdateEventStatusesData {
+    /// Identifier for the event organizer.
+    pub organizer_id: i64,
+
+    /// Unique identifiers of the events to update.
+    pub event_ids: Vec<i64>,
+
+    /// New status of the events.
+    pub status: String,
+}
+
+
+/// Represents the number of events whose status was changed.
+#[derive(Serialize)]
+pub struct BulkStatusResult {
+    /// Number of events updated.
+    pub updated: u64,
+}
+
+
+/// Data required to retrieve an organizer's event templates.
+pub struct GetEventTemplatesData {
+    /// Identifier for the event organizer.
+    pub organizer_id: i64,
+}
+
+
+/// Request body for creating a new event from an existing event or template.
+#[derive(Default, Deserialize)]
+pub struct DuplicateEventData {
+    /// The date of the new event; defaults to the source event's dates.
+    #[serde(default)]
+    pub event_date: Option<NaiveDate>,
+
+    /// The last day of the new event; defaults to keeping the source event's length.
+    #[serde(default)]
+    pub end_date: Option<NaiveDate>,
+
+    /// Registration deadline of the new event; defaults to `event_date`, or to the source
+    /// event's deadline when `event_date` is omitted.
+    #[serde(default)]
+    pub registration_deadline: Option<NaiveDate>,
+}
+
+
+/// Data required to soft-delete or restore an event of an organizer.
+pub struct DeleteEventData {
+    /// Unique identifier of the event.
+    pub event_id: i64,
+
+    /// Identifier for the event organizer.
+    pub organizer_id: i64,
+}
+
+
+/// Data required to retrieve an organizer's deleted events.
+pub struct GetDeletedEventsData {
+    /// Identifier for the event organizer.
+    pub organizer_id: i64,
+}
+
+
+/// Represents a soft-deleted event in the organizer's trash.
+#[derive(Serialize)]
+pub struct DeletedEvent {
+    /// Unique identifier for the event.
+    pub id: i64,
+
+    /// Title of the event.
+    pub title: String,
+
+    /// The date of the event.
+    pub event_date: NaiveDate,
+
+    /// The last day of the event.
+    pub end_date: NaiveDate,
+
+    /// Status of the event ("upcoming", "canceled", etc.).
+    pub status: String,
+
+    /// Whether the event is a reusable template (1) or a regular event (0).
+    pub is_template: i64,
+
+    /// Timestamp for when the event was deleted.
+    pub deleted_at: NaiveDateTime,
+}
+
+
+/// Represents a location used by an organizer's events.
+#[derive(Serialize)]
+pub struct EventLocation {
+    /// The location as entered on the events.
+    pub location: String,
+
+    /// Number of events held at the location.
+    pub event_count: i64,
+}
+
+
+/// Represents a lightweight projection of an event for list views.
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct EventSummary {
+    /// Unique identifier for the event.
+    pub id: i64,
+
+    /// Title of the event.
+    pub title: String,
+
+    /// The date of the event.
+    pub event_date: NaiveDate,
+
+    /// The last day of the event.
+    pub end_date: NaiveDate,
+
+    /// Start time of the event in string format.
+    pub start_time: String,
+
+    /// End time of the event in string format.
+    pub end_time: String,
+
+    /// Location where the event is held.
+    pub location: String,
+
+    /// Identifier for the associated category.
+    pub category_id: i64,
+
+    /// Status of the event ("upcoming", "canceled", etc.).
+    pub status: String,
+
+    /// Number of tickets sold for the event.
+    pub tickets_sold: i64,
+
+    /// Maximum number of attendees allowed.
+    pub max_attendees: i64,
+
+    /// When the event was moved to the trash, or `None` if it was not.
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+
+/// Represents a page of event summaries.
+#[derive(Serialize)]
+pub struct EventSummaryPage {
+    /// Event summaries in the requested page.
+    pub events: Vec<EventSummary>,
+
+    /// Total number of events available across all pages.
+    pub total: i64,
+
+    /// Maximum number of events returned in this page.
+    pub limit: i64,
+
+    /// Number of events skipped before this page.
+    pub offset: i64,
+}
+
+
+/// Data required to retrieve a publicly visible event.
+pub struct GetPublicEventData {
+    /// Unique identifier of the event to retrieve.
+    pub event_id: i64,
+}
+
+
+/// Represents an event as shown on its public page.
+///
+/// Leaves out contact details, ticket and attendee counts, and moderation fields.
+#[derive(Serialize)]
+pub struct PublicEvent {
+    /// Unique identifier for the event.
+    pub id: i64,
+
+    /// Title of the event.
+    pub title: String,
+
+    /// Detailed description of the event.
+    pub description: String,
+
+    /// The first day of the event.
+    pub event_date: NaiveDate,
+
+    /// The last day of the event.
+    pub end_date: NaiveDate,
+
+    /// Start time of the event in string format.
+    pub start_time: String,
+
+    /// End time of the event in string format.
+    pub end_time: String,
+
+    /// Location where the event is held.
+    pub location: String,
+
+    /// Identifier for the associated category.
+    pub category_id: i64,
+
+    /// Status of the event ("upcoming" or "complete").
+    pub status: String,
+
+    /// Ticket price for the event.
+    pub price: f64,
+
+    /// Last date to register for the event.
+    pub registration_deadline: NaiveDate,
+
+    /// Flag indicating whether the event is virtual.
+    pub is_virtual: i64,
+
+    /// Optional image URL for the event.
+    pub image: Option<String>,
+
+    /// Optional embedded map for the event location.
+    pub map_embed: Option<String>,
+
+    /// Optional accessibility information.
+    pub accessibility_info: Option<String>,
+
+    /// Optional safety guidelines.
+    pub safety_guidelines: Option<String>,
+
+    /// Organizer of the event.
+    pub organizer: PublicOrganizer,
+
+    /// List of agenda items of the event.
+    pub agenda: Vec<Agenda>,
+
+    /// List of speakers of the event.
+    pub speakers: Vec<Speaker>,
+
+    /// List of faqs of the event.
+    pub faqs: Vec<Faq>,
 }
 
 
@@ -219,6 +621,17 @@ pub struct EventDetails {
 }
 
 
+/// Represents an event together with its related detail information.
+#[derive(Serialize)]
+pub struct EventBundle {
+    /// The event itself.
+    pub event: Event,
+
+    /// Related detail information of the event.
+    pub details: EventDetails,
+}
+
+
 /// Represents registerable related detail information of the event.
 #[derive(Deserialize, Serialize)]
 pub struct CreateEventDetails {
@@ -236,6 +649,99 @@ pub struct CreateEventDetails {
 }
 
 
+/// Represents a single event with every child collection, for export and re-import.
+#[derive(Deserialize, Serialize)]
+pub struct EventExport {
+    /// The event itself.
+    pub event: Event,
+
+    /// List of agenda items of the event.
+    pub agenda: Vec<Agenda>,
+
+    /// List of speakers of the event.
+    pub speakers: Vec<Speaker>,
+
+    /// List of faqs of the event.
+    pub faqs: Vec<Faq>,
+
+    /// List of attachments of the event.
+    pub attachments: Vec<Attachment>,
+
+    /// List of comments on the event.
+    pub comments: Vec<Comment>,
+
+    /// List of attendees of the event.
+    pub attendees: Vec<Attendee>,
+}
+
+
+/// Selects which detail collections to copy from a source event.
+#[derive(Deserialize)]
+pub struct CopyEventDetailsData {
+    /// Whether to copy agenda items.
+    #[serde(default)]
+    pub agenda: bool,
+
+    /// Whether to copy speakers.
+    #[serde(default = "default_true")]
+    pub speakers: bool,
+
+    /// Whether to copy faqs.
+    #[serde(default = "default_true")]
+    pub faqs: bool,
+
+    /// Whether to copy attachments.
+    #[serde(default)]
+    pub attachments: bool,
+}
+
+
+/// Copies faqs and speakers when no selection is provided.
+impl Default for CopyEventDetailsData {
+    fn default() -> Self {
+        CopyEventDetailsData {
+            agenda: false,
+            speakers: true,
+            faqs: true,
+            attachments: false,
+        }
+    }
+}
+
+
+/// Represents the number of detail items copied onto an event.
+#[derive(Serialize)]
+pub struct CopiedEventDetails {
+    /// Number of agenda items copied.
+    pub agenda: u64,
+
+    /// Number of speakers copied.
+    pub speakers: u64,
+
+    /// Number of faqs copied.
+    pub faqs: u64,
+
+    /// Number of attachments copied.
+    pub attachments: u64,
+}
+
+
+/// Identifies the detail items of an event to delete.
+#[derive(Deserialize)]
+pub struct BulkDeleteData {
+    /// Unique identifiers of the items to delete.
+    pub ids: Vec<i64>,
+}
+
+
+/// Represents the number of detail items deleted from an event.
+#[derive(Serialize)]
+pub struct BulkDeleteResult {
+    /// Number of items deleted.
+    pub deleted: u64,
+}
+
+
 /// Represents aggregated totals for ticket metrics for a given year.
 #[derive(Serialize)]
 pub struct TicketTotals {
@@ -247,9 +753,305 @@ pub struct TicketTotals {
 }
 
 
+/// Ticket and attendance counts computed from an event's attendee rows.
+#[derive(Debug, sqlx::FromRow)]
+pub struct LiveAttendeeCounts {
+    /// Number of confirmed attendees.
+    pub tickets_sold: i64,
+
+    /// Number of confirmed attendees that have checked in.
+    pub attendees: i64,
+}
+
+
+/// Compares an event's stored ticket and attendance counts with its attendee rows.
+#[derive(Serialize)]
+pub struct AttendeeCountComparison {
+    /// Unique identifier for the event.
+    pub event_id: i64,
+
+    /// `tickets_sold` as stored on the event.
+    pub stored_tickets_sold: i64,
+
+    /// Number of confirmed attendees.
+    pub live_tickets_sold: i64,
+
+    /// `attendees` as stored on the event.
+    pub stored_attendees: i64,
+
+    /// Number of confirmed attendees that have checked in.
+    pub live_attendees: i64,
+}
+
+
+impl AttendeeCountComparison {
+    /// Builds a comparison from an event and its live counts.
+    pub fn new(event: &Event, live: &LiveAttendeeCounts) -> Self {
+        AttendeeCountComparison {
+            event_id: event.id,
+            stored_tickets_sold: event.tickets_sold,
+            live_tickets_sold: live.tickets_sold,
+            stored_attendees: event.attendees,
+            live_attendees: live.attendees,
+        }
+    }
+}
+
+
+/// Represents the realized and potential ticket revenue of a single event.
+#[derive(Serialize)]
+pub struct EventRevenue {
+    /// Unique identifier for the event.
+    pub event_id: i64,
+
+    /// Revenue from tickets sold so far (`tickets_sold * price`).
+    pub gross: f64,
+
+    /// Amount refunded to attendees. Refunds are not tracked yet, so this is always zero.
+    pub refunds: f64,
+
+    /// Revenue kept after refunds (`gross - refunds`).
+    pub net: f64,
+
+    /// Revenue still obtainable from unsold seats.
+    pub remaining_revenue: f64,
+
+    /// Tickets sold in each of the event's tiers; empty for an event without tiers.
+    pub tiers: Vec<TierSales>,
+}
+
+
+impl EventRevenue {
+    /// Computes revenue figures from an event's price, tickets sold, capacity and ticket tiers.
+    ///
+    /// Unsold tier tickets are valued at the tier's price and any remaining seats at the event's `price`.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The event whose revenue is computed.
+    /// * `tiers` - The ticket sales of the event's tiers.
+    ///
+    /// # Returns
+    ///
+    /// The event's `EventRevenue`.
+    pub fn new(
+        event: &Event,
+        tiers: Vec<TierSales>
+    ) -> Self {
+        let remaining_seats = (event.max_attendees - event.tickets_sold).max(0);
+        let tier_remaining: Vec<i64> = tiers.iter().map(|tier| (tier.quantity - tier.sold).max(0)).collect();
+        let untiered_remaining = (remaining_seats - tier_remaining.iter().sum::<i64>()).max(0);
+
+        let gross = event.ticket_revenue(&tiers);
+        let refunds = 0.0;
+
+        EventRevenue {
+            event_id: event.id,
+            gross,
+            refunds,
+            net: gross - refunds,
+            remaining_revenue: tiers.iter().zip(&tier_remaining).map(|(tier, remaining)| *remaining as f64 * tier.price).sum::<f64>()
+                + untiered_remaining as f64 * event.price,
+            tiers,
+        }
+    }
+}
+
+
+/// Builds the creation data for a copy of an existing event.
+impl From<Event> for EventData {
+    fn from(event: Event) -> Self {
+        EventData {
+            title: event.title,
+            description: event.description,
+            event_date: event.event_date,
+            end_date: Some(event.end_date),
+            start_time: event.start_time,
+            end_time: event.end_time,
+            location: event.location,
+            category_id: event.category_id,
+            status: event.status,
+            organizer_id: event.organizer_id,
+            price: event.price,
+            tickets_sold: event.tickets_sold,
+            attendees: event.attendees,
+            max_attendees: event.max_attendees,
+            contact_email: event.contact_email,
+            contact_phone: event.contact_phone,
+            registration_deadline: event.registration_deadline.to_string(),
+            is_virtual: event.is_virtual,
+            image: event.image,
+            map_embed: event.map_embed,
+            accessibility_info: event.accessibility_info,
+            safety_guidelines: event.safety_guidelines,
+            is_template: event.is_template,
+            approval_status: event.approval_status,
+        }
+    }
+}
+
+
+/// Statuses an event may have.
+pub const EVENT_STATUSES: [&str; 3] = ["upcoming", "complete", "canceled"];
+
+
+/// Columns a list of events may be sorted by.
+pub const EVENT_SORT_COLUMNS: [&str; 4] = ["event_date", "tickets_sold", "attendees", "price"];
+
+
+/// Directions a list may be sorted in.
+pub const SORT_ORDERS: [&str; 2] = ["asc", "desc"];
+
+
+/// Approval status of an event awaiting moderation.
+pub const APPROVAL_PENDING: &str = "pending";
+
+/// Approval status of an event that may be shown publicly.
+pub const APPROVAL_APPROVED: &str = "approved";
+
+/// Approval status of an event an admin has turned down.
+pub const APPROVAL_REJECTED: &str = "rejected";
+
+
+/// Settings controlling the status given to new events.
+#[derive(Clone)]
+pub struct EventStatusConfig {
+    /// Status assigned to new events that are submitted without one.
+    pub default_status: String,
+
+    /// Whether new events must be approved by an admin before they are shown publicly.
+    pub require_approval: bool,
+}
+
+
+impl EventStatusConfig {
+    /// Returns the approval status given to new events.
+    pub fn initial_approval_status(&self) -> &'static str {
+        if self.require_approval {
+            APPROVAL_PENDING
+        } else {
+            APPROVAL_APPROVED
+        }
+    }
+}
+
+
+/// Settings controlling uploaded event images.
+#[derive(Clone, Copy)]
+pub struct ImageUploadConfig {
+    /// Largest decoded image size accepted, in bytes.
+    pub max_bytes: usize,
+}
+
+
+/// Settings controlling when an event is flagged as nearing capacity.
+#[derive(Clone, Copy)]
+pub struct CapacityWarningConfig {
+    /// Percentage of `max_attendees` sold at which an event is considered nearly full.
+    pub threshold_percent: i64,
+}
+
+
+impl CapacityWarningConfig {
+    /// Returns the capacity warning for an event with the given sales, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `tickets_sold` - Number of tickets sold for the event.
+    /// * `max_attendees` - Capacity of the event.
+    ///
+    /// # Returns
+    ///
+    /// `Some(CapacityWarning)` if the event is full or within the threshold of capacity,
+    /// or `None` otherwise, including for events without a capacity.
+    pub fn warning(&self, tickets_sold: i64, max_attendees: i64) -> Option<CapacityWarning> {
+        if max_attendees <= 0 {
+            return None;
+        }
+
+        if tickets_sold >= max_attendees {
+            Some(CapacityWarning::Full)
+        } else if tickets_sold * 100 >= max_attendees * self.threshold_percent {
+            Some(CapacityWarning::Near)
+        } else {
+            None
+        }
+    }
+}
+
+
+/// Capacity state signalled to clients through the `X-Capacity-Warning` header.
+#[derive(Debug, PartialEq)]
+pub enum CapacityWarning {
+    /// Tickets sold have reached the configured threshold.
+    Near,
+
+    /// Tickets sold have reached or exceeded `max_attendees`.
+    Full,
+}
+
+
+impl CapacityWarning {
+    /// Returns the header value for the warning.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CapacityWarning::Near => "near",
+            CapacityWarning::Full => "full",
+        }
+    }
+}
+
+
+impl Event {
+    /// Returns the revenue from the tickets sold so far.
+    ///
+    /// Tickets sold in a tier earn the tier's price; the rest of `tickets_sold` earn the event's
+    /// `price`. Without tiers this is `tickets_sold * price`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tiers` - The ticket sales of the event's tiers.
+    pub fn ticket_revenue(&self, tiers: &[TierSales]) -> f64 {
+        let tier_sold: i64 = tiers.iter().map(|tier| tier.sold).sum();
+        let untiered_sold = (self.tickets_sold - tier_sold).max(0);
+
+        tiers.iter().map(|tier| tier.sold as f64 * tier.price).sum::<f64>() + untiered_sold as f64 * self.price
+    }
+
+
+    /// Returns the capacity warning for the event, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The capacity warning settings.
+    ///
+    /// # Returns
+    ///
+    /// `Some(CapacityWarning)` if the event is full or within the threshold of capacity,
+    /// or `None` otherwise, including for events without a capacity.
+    pub fn capacity_warning(&self, config: &CapacityWarningConfig) -> Option<CapacityWarning> {
+        config.warning(self.tickets_sold, self.max_attendees)
+    }
+}
+
+
+/// Default value for opt-out boolean flags.
+fn default_true() -> bool {
+    true
+}
+
+
 /// Represents aggregated daily event counts for a given year.
 #[derive(Serialize)]
 pub struct EventCounts {
     /// Daily totals of event counts.
     pub event_counts: Vec<CountByDate>,
-}
\ No newline at end of file
+}
+
+
+/// Represents the number of events per status for a given year.
+#[derive(Serialize)]
+pub struct EventStatusCounts {
+    /// Count of events per status; every known status is present, with 0 if unused.
+    pub status_counts: BTreeMap<String, usize>,
+}
@@ -1,22 +1,43 @@
 // External Libraries
-use actix_web::{web, Responder, HttpResponse, HttpRequest};
-use sqlx::{SqlitePool};
+use actix_web::{web, HttpResponse, HttpRequest};
+use actix_web::http::header::{EntityTag, Header, IfNoneMatch, ETag};
+use log::{debug, info, warn};
+use sqlx::{SqliteConnection, SqlitePool};
+use std::io::ErrorKind;
 
 // Internal Mappers
 use crate::event::mapper::{
     fetch_events,
+    fetch_all_events,
+    search_events,
     fetch_event,
+    fetch_event_details,
+    fetch_event_details_revision,
+    fetch_public_event,
+    fetch_event_export,
+    fetch_event_templates,
+    fetch_event_locations,
     create_event,
+    create_event_from,
+    import_event,
     update_event,
     fetch_monthly_ticket_sales,
-    fetch_daily_event_counts
+    fetch_daily_event_counts,
+    fetch_event_status_counts,
+    fetch_live_attendee_counts,
+    fetch_owned_event_ids,
+    update_event_statuses,
+    soft_delete_event,
+    restore_event,
+    fetch_deleted_events,
+    reconcile_attendee_counts
 };
-use crate::organizer::mapper::fetch_organizer;
-use crate::agenda::mapper::{fetch_agenda, create_agenda, update_agenda};
-use crate::speaker::mapper::{fetch_speakers, create_speakers, update_speakers};
-use crate::faq::mapper::{fetch_faqs, create_faqs, update_faqs};
-use crate::attachment::mapper::{fetch_attachments, create_attachments, update_attachments};
-use crate::comment::mapper::fetch_comments;
+use crate::agenda::mapper::{fetch_agenda, fetch_agenda_grouped, create_agenda, update_agenda, copy_agenda, delete_agenda};
+use crate::speaker::mapper::{fetch_speakers, create_speakers, update_speakers, copy_speakers, delete_speaker, delete_speakers};
+use crate::faq::mapper::{fetch_faqs, create_faqs, update_faqs, copy_faqs, delete_faqs};
+use crate::attachment::mapper::{fetch_attachments, create_attachments, update_attachments, copy_attachments, delete_attachments};
+use crate::ticket_tier::mapper::fetch_tier_sales;
+use crate::category::mapper::fetch_category_ids;
 
 // Internal Models
 use crate::event::models::{
@@ -24,22 +45,75 @@ use crate::event::models::{
     EventData,
     GetUserEventsQuery,
     GetUserEventsData,
+    GetAllUserEventsData,
+    GetAllUserEventsQuery,
+    SearchEventsQuery,
+    SearchEventsData,
+    EventDetailsQuery,
     GetEventData,
+    GetPublicEventData,
+    GetEventTemplatesData,
+    DuplicateEventData,
     EventDetails,
+    EventBundle,
+    EventExport,
     CreateEventDetails,
+    CopyEventDetailsData,
+    CopiedEventDetails,
+    BulkDeleteData,
+    BulkDeleteResult,
+    BulkStatusData,
+    BulkStatusResult,
+    GetOwnedEventIdsData,
+    UpdateEventStatusesData,
+    DeleteEventData,
+    GetDeletedEventsData,
+    EventRevenue,
+    AttendeeCountComparison,
+    EventValidation,
+    CapacityWarningConfig,
+    EventStatusConfig,
+    ImageUploadConfig,
     TicketTotals,
-    EventCounts
+    EventCounts,
+    EventStatusCounts,
+    EVENT_STATUSES,
+    EVENT_SORT_COLUMNS,
+    SORT_ORDERS
 };
-use crate::organizer::models::{Organizer, GetOrganizerData};
 use crate::overview::models::{GetOverview, YearQuery};
-use crate::agenda::models::GetAgendaData;
-use crate::speaker::models::GetSpeakerData;
-use crate::faq::models::GetFaqData;
-use crate::attachment::models::GetAttachmentData;
-use crate::comment::models::GetCommentData;
+use crate::agenda::models::{GetAgendaData, DeleteAgendaData};
+use crate::speaker::models::{GetSpeakerData, DeleteSpeakerData, DeleteSpeakersData};
+use crate::faq::models::{GetFaqData, DeleteFaqsData};
+use crate::attachment::models::{GetAttachmentData, DeleteAttachmentsData};
+use crate::ticket_tier::models::GetTierSalesData;
+use crate::category::models::GetCategoriesData;
+use crate::organizer::models::GetOrganizerData;
+use crate::webhook::models::EventWebhookData;
 
 // Internal Services
+use crate::audit::services::record_session_audit;
+use crate::event::media::{is_data_uri, save_image, delete_image, release_image};
 use crate::auth::services::validate_session;
+use crate::webhook::services::WebhookNotifier;
+
+// Internal Validation
+use crate::event::validation::{apply_event_defaults, validate_event_data, validate_event, validate_event_export, validate_event_item_ids, validate_bulk_status, removed_item_ids};
+use crate::agenda::validation::{validate_agenda_speakers, link_agenda_speakers};
+use crate::validation::ValidationErrors;
+
+// Internal Errors
+use crate::error::AppError;
+
+
+/// Default number of items returned per page when no limit is given.
+pub const DEFAULT_PAGE_LIMIT: i64 = 25;
+
+/// Maximum number of items returned per page.
+pub const MAX_PAGE_LIMIT: i64 = 100;
+
+/// Response header signalling that an event is nearly full (`near`) or full (`full`).
+pub const CAPACITY_WARNING_HEADER: &str = "X-Capacity-Warning";
 
 
 /// Retrieves aggregated ticket sales data including monthly ticket counts and revenue
@@ -58,19 +132,15 @@ pub async fn get_monthly_ticket_sales(
     req: HttpRequest,
     query: web::Query<YearQuery>,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
-    let session = match validate_session(&req, &pool).await {
-        Ok(session) => session,
-        Err(response) => return response,
-    };
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
 
     let year = query.year;
     let organizer_id = session.user_id;
 
-    match fetch_monthly_ticket_sales(GetOverview {organizer_id, year}, &pool).await {
-        Ok(totals) => HttpResponse::Ok().json(TicketTotals {..totals}),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Failed to fetch ticket sales data: {}", e)),
-    }
+    let totals = fetch_monthly_ticket_sales(GetOverview {organizer_id, year}, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(TicketTotals {..totals}))
 }
 
 
@@ -89,19 +159,43 @@ pub async fn get_daily_event_counts(
     req: HttpRequest,
     query: web::Query<YearQuery>,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
-    let session = match validate_session(&req, &pool).await {
-        Ok(session) => session,
-        Err(response) => return response,
-    };
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
 
     let year = query.year;
     let organizer_id = session.user_id;
 
-    match fetch_daily_event_counts(GetOverview {organizer_id, year}, &pool).await {
-        Ok(totals) => HttpResponse::Ok().json(EventCounts {..totals}),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Failed to fetch daily event counts: {}", e)),
-    }
+    let totals = fetch_daily_event_counts(GetOverview {organizer_id, year}, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(EventCounts {..totals}))
+}
+
+
+/// Retrieves the number of events per status for a specific organizer and year.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `query` - A query parameter containing the year to retrieve data for.
+/// * `pool` - A reference to the SQLite database connection pool.
+///
+/// # Returns
+///
+/// A JSON response containing a map of status to event count, with every known status present,
+/// or an error message if the operation fails.
+pub async fn get_event_status_counts(
+    req: HttpRequest,
+    query: web::Query<YearQuery>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let year = query.year;
+    let organizer_id = session.user_id;
+
+    let counts = fetch_event_status_counts(GetOverview {organizer_id, year}, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(EventStatusCounts {..counts}))
 }
 
 
@@ -110,25 +204,171 @@ pub async fn get_daily_event_counts(
 /// # Arguments
 ///
 /// * `req` - The incoming HTTP request containing session data.
+/// * `query` - Query parameters containing the `year`, the optional `include_spanning` flag,
+///   optional `status` and `category_id` filters, and optional `sort_by` and `order`.
 /// * `pool` - The SQLite database connection pool.
 ///
 /// # Returns
 ///
-/// An HTTP response with event data if successful, or an error message.
+/// An HTTP response with event data sorted by `event_date` ascending unless requested otherwise,
+/// `400 Bad Request` for an unknown status, sort column or order, or an error message.
 pub async fn get_events(
     req: HttpRequest,
     query: web::Query<GetUserEventsQuery>,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
-    let session = match validate_session(&req, &pool).await {
-        Ok(session) => session,
-        Err(response) => return response,
-    };
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
     
-    match fetch_events(GetUserEventsData {organizer_id: session.user_id, year: query.year}, &pool).await {
-        Ok(events) => HttpResponse::Ok().json(events),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Events not found: {}", e)),
+    let GetUserEventsQuery { year, include_spanning, status, category_id, sort_by, order } = query.into_inner();
+
+    let mut errors = ValidationErrors::new();
+    if let Some(status) = &status
+        && !EVENT_STATUSES.contains(&status.as_str()) {
+        errors.add("status", format!("'{}' is not a valid status (expected one of: {})", status, EVENT_STATUSES.join(", ")));
+    }
+
+    let sort_by = sort_by.unwrap_or_else(|| EVENT_SORT_COLUMNS[0].to_string());
+    if !EVENT_SORT_COLUMNS.contains(&sort_by.as_str()) {
+        errors.add("sort_by", format!("'{}' is not a sortable column (expected one of: {})", sort_by, EVENT_SORT_COLUMNS.join(", ")));
+    }
+
+    let order = order.unwrap_or_else(|| SORT_ORDERS[0].to_string()).to_lowercase();
+    if !SORT_ORDERS.contains(&order.as_str()) {
+        errors.add("order", format!("'{}' is not a valid order (expected one of: {})", order, SORT_ORDERS.join(", ")));
+    }
+    errors.into_result()?;
+
+    let descending = order == "desc";
+
+    let events = fetch_events(GetUserEventsData {organizer_id: session.user_id, year, include_spanning, status, category_id, sort_by, descending}, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(events))
+}
+
+
+/// Handles retrieving a page of all events associated with the authenticated organizer,
+/// regardless of year.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `query` - Query parameters containing the optional `limit`, `offset` and `include_deleted`.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with a page of event summaries if successful, or an error message.
+pub async fn get_all_events(
+    req: HttpRequest,
+    query: web::Query<GetAllUserEventsQuery>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let limit = query.limit.unwrap_or(DEFAULT_PAGE_LIMIT).clamp(1, MAX_PAGE_LIMIT);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    let page = fetch_all_events(GetAllUserEventsData {organizer_id: session.user_id, limit, offset, include_deleted: query.include_deleted}, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(page))
+}
+
+
+/// Handles searching the authenticated organizer's events for a keyword.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `query` - Query parameters containing the keyword `q`.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the events whose title, description or location contains the keyword,
+/// ordered by date, `400 Bad Request` for an empty keyword, or an error message.
+pub async fn get_event_search(
+    req: HttpRequest,
+    query: web::Query<SearchEventsQuery>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let keyword = query.into_inner().q.trim().to_string();
+
+    if keyword.is_empty() {
+        return Err(ValidationErrors::single("q", "Search keyword must not be empty").into());
     }
+
+    let events = search_events(SearchEventsData {organizer_id: session.user_id, query: keyword}, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(events))
+}
+
+
+/// Handles retrieving the event templates of the authenticated organizer.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with a summary of each template, or an error message.
+pub async fn get_event_templates(
+    req: HttpRequest,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let templates = fetch_event_templates(GetEventTemplatesData {organizer_id: session.user_id}, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(templates))
+}
+
+
+/// Handles retrieving the authenticated organizer's deleted events, most recently deleted first.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with a summary of each deleted event, or an error message.
+pub async fn get_deleted_events(
+    req: HttpRequest,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let events = fetch_deleted_events(GetDeletedEventsData {organizer_id: session.user_id}, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(events))
+}
+
+
+/// Handles retrieving the locations the authenticated organizer has used, for autocomplete.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the distinct non-empty locations and their event counts,
+/// most used first, or an error message.
+pub async fn get_event_locations(
+    req: HttpRequest,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let locations = fetch_event_locations(GetOrganizerData {organizer_id: session.user_id}, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(locations))
 }
 
 
@@ -139,29 +379,37 @@ pub async fn get_events(
 /// * `req` - The incoming HTTP request containing session data.
 /// * `event_id` - The path parameter representing the event's ID.
 /// * `pool` - The SQLite database connection pool.
+/// * `capacity_config` - Settings for the capacity warning header.
 ///
 /// # Returns
 ///
 /// An HTTP response with the event information if found, or an error message.
+/// Sets `X-Capacity-Warning` to `near` or `full` when the event is close to or at capacity.
 pub async fn get_event(
     req: HttpRequest,
     event_id: web::Path<i64>,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
-    let session = match validate_session(&req, &pool).await {
-        Ok(session) => session,
-        Err(response) => return response,
-    };
+    capacity_config: web::Data<CapacityWarningConfig>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let event = fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
 
-    match fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await {
-        Ok(event) => HttpResponse::Ok().json(Event {..event}),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Event not found: {}", e)),
+    let mut response = HttpResponse::Ok();
+    if let Some(warning) = event.capacity_warning(&capacity_config) {
+        response.insert_header((CAPACITY_WARNING_HEADER, warning.as_str()));
     }
+
+    Ok(response.json(Event {..event}))
 }
 
 
 /// Handles retrieving a specific event's details by ID, ensuring the organizer owns it.
 ///
+/// The response carries a weak `ETag` built from the revision of the event's details; a request
+/// whose `If-None-Match` matches it is answered with `304 Not Modified` without loading the details.
+///
 /// # Arguments
 ///
 /// * `req` - The incoming HTTP request containing session data.
@@ -170,234 +418,1038 @@ pub async fn get_event(
 ///
 /// # Returns
 ///
-/// An HTTP response with the event detail information if found, or an error message.
+/// An HTTP response with the event detail information if found, `304 Not Modified` if the
+/// client's copy is current, or an error message.
 pub async fn get_event_details(
     req: HttpRequest,
     event_id: web::Path<i64>,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
-    let session = match validate_session(&req, &pool).await {
-        Ok(session) => session,
-        Err(response) => return response,
-    };
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
 
-    let event = match fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await {
-        Ok(event) => event,
-        Err(e) => return HttpResponse::InternalServerError().body(format!("Event not found: {}", e)),
+    let event = fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    let revision = fetch_event_details_revision(&event, &pool).await?;
+    let etag = EntityTag::new_weak(format!("{}-{}", event.id, revision));
+
+    let client_is_current = match IfNoneMatch::parse(&req) {
+        Ok(IfNoneMatch::Any) => true,
+        Ok(IfNoneMatch::Items(tags)) => tags.iter().any(|tag| tag.weak_eq(&etag)),
+        Err(_) => false,
     };
+    if client_is_current {
+        return Ok(HttpResponse::NotModified().insert_header(ETag(etag)).finish());
+    }
 
-    let organizer_info = fetch_organizer(GetOrganizerData { organizer_id: event.organizer_id }, &pool)
-        .await.unwrap_or_else(|_| Organizer::default());
-    let agenda_items = fetch_agenda(GetAgendaData { event_id: event.id }, &pool)
-        .await.unwrap_or_else(|_| vec![]);
-    let speaker_items = fetch_speakers(GetSpeakerData { event_id: event.id }, &pool)
-        .await.unwrap_or_else(|_| vec![]);
-    let faq_items = fetch_faqs(GetFaqData { event_id: event.id }, &pool)
-        .await.unwrap_or_else(|_| vec![]);
-    let attachment_items = fetch_attachments(GetAttachmentData { event_id: event.id }, &pool)
-        .await.unwrap_or_else(|_| vec![]);
-    let comment_items = fetch_comments(GetCommentData { event_id: event.id }, &pool)
-        .await.unwrap_or_else(|_| vec![]);
-    
-    // TODO Fetch related events based on similar data: category_id, speakers, etc
+    Ok(HttpResponse::Ok().insert_header(ETag(etag)).json(fetch_event_details(&event, &pool).await))
+}
 
-    HttpResponse::Ok().json(EventDetails {
-        organizer: organizer_info,
-        agenda: agenda_items,
-        speakers: speaker_items,
-        faqs: faq_items,
-        attachments: attachment_items,
-        comments: comment_items,
-        related_events: vec![],
-    })
+
+/// Handles retrieving the public view of an event; no session is required.
+///
+/// Contact details, ticket and attendee counts are left out, and only approved events that are
+/// not canceled or templates can be viewed.
+///
+/// # Arguments
+///
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the public event, its organizer's name and logo, agenda, speakers and
+/// faqs, or `404 Not Found` if the event does not exist or is not public.
+pub async fn get_public_event(
+    event_id: web::Path<i64>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let event = fetch_public_event(GetPublicEventData {event_id: *event_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    Ok(HttpResponse::Ok().json(event))
 }
 
 
-/// Handles registering a new event under the authenticated organizer.
+/// Handles retrieving a specific event together with its details by ID, ensuring the organizer owns it.
 ///
 /// # Arguments
 ///
 /// * `req` - The incoming HTTP request containing session data.
-/// * `data` - The JSON body containing new event data.
+/// * `event_id` - The path parameter representing the event's ID.
 /// * `pool` - The SQLite database connection pool.
 ///
 /// # Returns
 ///
-/// An HTTP response indicating success or failure of event creation.
-pub async fn register_event(
+/// An HTTP response with the event and its detail information if found, or an error message.
+pub async fn get_event_full(
     req: HttpRequest,
-    data: web::Json<EventData>,
+    event_id: web::Path<i64>,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
-    let session = match validate_session(&req, &pool).await {
-        Ok(session) => session,
-        Err(response) => return response,
-    };
-    
-    // TODO Save image file and update image to be location reference
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
 
-    match create_event(EventData {organizer_id: session.user_id, ..data.into_inner()}, &pool).await {
-        Ok(event) => HttpResponse::Ok().body(format!("Event '{}' registered", event.title)),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Failed to register event: {}", e)),
-    }
+    let event = fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    let details = fetch_event_details(&event, &pool).await;
+
+    Ok(HttpResponse::Ok().json(EventBundle { event, details }))
 }
 
 
-/// Handles registering a new events details under the authenticated organizer.
+/// Exports a single event with all of its children as one JSON document, ensuring the organizer owns it.
+///
+/// The document can be recreated with `POST /events/import-one/`.
 ///
 /// # Arguments
 ///
 /// * `req` - The incoming HTTP request containing session data.
 /// * `event_id` - The path parameter representing the event's ID.
-/// * `data` - The JSON body containing new event detail data.
 /// * `pool` - The SQLite database connection pool.
 ///
 /// # Returns
 ///
-/// An HTTP response indicating success or failure of event detail creation.
-pub async fn register_event_details(
+/// An HTTP response with the event, agenda, speakers, faqs, attachments, comments and attendees,
+/// or an error message.
+pub async fn export_event(
     req: HttpRequest,
     event_id: web::Path<i64>,
-    data: web::Json<CreateEventDetails>,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
-    let session = match validate_session(&req, &pool).await {
-        Ok(session) => session,
-        Err(response) => return response,
-    };
-    
-    match fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await {
-        Ok(event) => event,
-        Err(e) => return HttpResponse::InternalServerError().body(format!("Event not found: {}", e)),
-    };
-    
-    let CreateEventDetails { 
-        agenda, 
-        speakers, 
-        faqs, 
-        attachments, 
-    } = data.into_inner();
-    
-    let agenda_items = match create_agenda(agenda, &pool).await {
-        Ok(agenda_items) => agenda_items,
-        Err(e) => return HttpResponse::InternalServerError().body(format!("Failed to create agenda: {}", e)),
-    };
-    let speaker_items = match create_speakers(speakers, &pool).await {
-        Ok(speaker_items) => speaker_items,
-        Err(e) => return HttpResponse::InternalServerError().body(format!("Failed to create speakers: {}", e)),
-    };
-    let faq_items = match create_faqs(faqs, &pool).await {
-        Ok(faq_items) => faq_items,
-        Err(e) => return HttpResponse::InternalServerError().body(format!("Failed to create faqs: {}", e)),
-    };
-    let attachment_items = match create_attachments(attachments, &pool).await {
-        Ok(attachment_items) => attachment_items,
-        Err(e) => return HttpResponse::InternalServerError().body(format!("Failed to create attachments: {}", e)),
-    };
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
 
-    HttpResponse::Ok().json(CreateEventDetails {
-        agenda: agenda_items, 
-        speakers: speaker_items,
-        faqs: faq_items,
-        attachments: attachment_items,
-    })
+    let event = fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    let export = fetch_event_export(event, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(export))
 }
 
 
-/// Handles updating an event under the authenticated organizer.
+/// Handles retrieving a specific event's agenda grouped by day, ensuring the organizer owns it.
 ///
 /// # Arguments
 ///
 /// * `req` - The incoming HTTP request containing session data.
 /// * `event_id` - The path parameter representing the event's ID.
-/// * `data` - The JSON body containing new event data.
 /// * `pool` - The SQLite database connection pool.
 ///
 /// # Returns
 ///
-/// An HTTP response indicating success or failure of updating event.
-pub async fn put_event(
+/// An HTTP response with the agenda items grouped by date if found, or an error message.
+pub async fn get_event_agenda_grouped(
     req: HttpRequest,
     event_id: web::Path<i64>,
-    data: web::Json<Event>,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
-    let session = match validate_session(&req, &pool).await {
-        Ok(session) => session,
-        Err(response) => return response,
-    };
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
 
-    let event = match fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await {
-        Ok(event) => event,
-        Err(e) => return HttpResponse::InternalServerError().body(format!("Event not found: {}", e)),
-    };
-    
-    // TODO Remove old and save new image file and update image location reference
-    if data.image != event.image {
-        
-    }
+    let event = fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
 
-    match update_event(Event {id: *event_id, ..data.into_inner()}, &pool).await {
-        Ok(()) => HttpResponse::Ok().body(format!("Event '{}' updated", event_id)),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Failed to update event: {}", e)),
-    }
+    let agenda_days = fetch_agenda_grouped(GetAgendaData { event_id: event.id }, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(agenda_days))
 }
 
 
-/// Handles updating the detailed information of a specific event.
+/// Handles retrieving a specific event's gross, refunded, net and remaining revenue, with its sales
+/// per ticket tier, ensuring the organizer owns it.
 ///
 /// # Arguments
 ///
 /// * `req` - The incoming HTTP request containing session data.
-/// * `event_id` - The path parameter representing the event's ID to update.
-/// * `data` - The JSON body containing new event detail data.
+/// * `event_id` - The path parameter representing the event's ID.
 /// * `pool` - The SQLite database connection pool.
 ///
 /// # Returns
 ///
-/// An HTTP response indicating success or failure of the update operation.
-pub async fn put_event_details(
+/// An HTTP response with the event revenue if found, or an error message.
+pub async fn get_event_revenue(
     req: HttpRequest,
     event_id: web::Path<i64>,
-    data: web::Json<EventDetails>,
     pool: web::Data<SqlitePool>,
-) -> impl Responder {
-    let session = match validate_session(&req, &pool).await {
-        Ok(session) => session,
-        Err(response) => return response,
-    };
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
 
-    match fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await {
-        Ok(event) => event,
-        Err(e) => return HttpResponse::InternalServerError().body(format!("Event not found: {}", e)),
-    };
+    let event = fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
 
-    let EventDetails { 
-        agenda, 
-        speakers, 
-        faqs, 
-        attachments, 
-        .. 
-    } = data.into_inner();
+    let tiers = fetch_tier_sales(GetTierSalesData {event_ids: vec![event.id]}, &pool).await?
+        .remove(&event.id)
+        .unwrap_or_default();
 
-    match update_agenda(agenda, &pool).await {
-        Err(e) => return HttpResponse::InternalServerError().body(format!("Failed to update agenda: {}", e)),
-        _ => {},
-    };
-    match update_speakers(speakers, &pool).await {
-        Err(e) => return HttpResponse::InternalServerError().body(format!("Failed to update speakers: {}", e)),
-        _ => {},
-    };
-    match update_faqs(faqs, &pool).await {
-        Err(e) => return HttpResponse::InternalServerError().body(format!("Failed to update faqs: {}", e)),
-        _ => {},
-    };
-    match update_attachments(attachments, &pool).await {
-        Err(e) => return HttpResponse::InternalServerError().body(format!("Failed to update attachments: {}", e)),
-        _ => {},
-    };
-    
-    HttpResponse::Ok().body("Event details updated")
+    Ok(HttpResponse::Ok().json(EventRevenue::new(&event, tiers)))
+}
+
+
+/// Handles comparing a specific event's stored ticket and attendance counts with its attendee rows.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the stored and live counts if found, or an error message.
+pub async fn get_event_attendee_counts(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let event = fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    let live = fetch_live_attendee_counts(event.id, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(AttendeeCountComparison::new(&event, &live)))
+}
+
+
+/// Handles overwriting a specific event's `tickets_sold` and `attendees` with the counts
+/// computed from its attendee rows.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the previously stored and the reconciled counts, or an error message.
+pub async fn reconcile_event_counts(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let event = fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    let live = reconcile_attendee_counts(event.id, &pool).await?;
+
+    info!(target: "event", "Organizer {} reconciled attendee counts of event {}", session.user_id, event.id);
+    record_session_audit(&session, "event", event.id, "reconcile_counts", None, &pool).await;
+
+    Ok(HttpResponse::Ok().json(AttendeeCountComparison::new(&event, &live)))
+}
+
+
+/// Handles registering a new event under the authenticated organizer.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `data` - The JSON body containing new event data.
+/// * `pool` - The SQLite database connection pool.
+/// * `status_config` - Settings providing the default status for events submitted without one.
+/// * `upload_config` - Settings limiting the size of an uploaded image.
+/// * `webhook` - Notifier posting the change to the configured webhook.
+///
+/// # Returns
+///
+/// A `201 Created` response with the new event, `400 Bad Request` listing each invalid field,
+/// or an error message.
+pub async fn register_event(
+    req: HttpRequest,
+    data: web::Json<EventData>,
+    pool: web::Data<SqlitePool>,
+    status_config: web::Data<EventStatusConfig>,
+    upload_config: web::Data<ImageUploadConfig>,
+    webhook: web::Data<WebhookNotifier>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let mut data = data.into_inner();
+    apply_event_defaults(&mut data, &status_config);
+
+    let category_ids = fetch_category_ids(GetCategoriesData {organizer_id: session.user_id}, &pool).await?;
+    if let Err(errors) = validate_event_data(&data, &category_ids) {
+        debug!(target: "event", "Rejected event from organizer {}: {}", session.user_id, errors);
+        return Err(errors.into());
+    }
+
+    let mut saved_image = None;
+
+    if let Some(image) = data.image.as_deref()
+        && is_data_uri(image) {
+        let path = match save_image(image, upload_config.max_bytes) {
+            Ok(path) => path,
+            Err(e) if e.kind() == ErrorKind::InvalidInput => return Err(ValidationErrors::single("image", e.to_string()).into()),
+            Err(e) => return Err(AppError::Internal(format!("Failed to save image: {}", e))),
+        };
+        data.image = Some(path.clone());
+        saved_image = Some(path);
+    }
+
+    let mut conn = pool.acquire().await?;
+
+    let event = match create_event(EventData {organizer_id: session.user_id, ..data}, &mut conn).await {
+        Ok(event) => event,
+        Err(e) => {
+            // Do not leave behind an image no event references
+            if let Some(path) = saved_image
+                && let Err(delete_error) = delete_image(&path) {
+                warn!(target: "event", "Failed to delete image {} of failed registration: {}", path, delete_error);
+            }
+            return Err(AppError::Db(e));
+        },
+    };
+
+    info!(target: "event", "Organizer {} registered event {}", session.user_id, event.id);
+    record_session_audit(&session, "event", event.id, "create", None, &pool).await;
+    webhook.notify_event_change(EventWebhookData {event_id: event.id, action: "create", organizer_id: session.user_id});
+
+    Ok(HttpResponse::Created().json(event))
+}
+
+
+/// Creates a new event from an existing event or template owned by the authenticated organizer.
+///
+/// The new event gets the given dates (the source's when `event_date` is omitted or no body is
+/// sent), status `upcoming` and zeroed ticket and attendee counts, is never a template, and
+/// receives copies of the source's speakers, agenda, faqs and attachments.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the source event's or template's ID.
+/// * `data` - The optional JSON body containing the new event's dates.
+/// * `pool` - The SQLite database connection pool.
+/// * `status_config` - Settings providing the approval status of the new event.
+/// * `webhook` - Notifier posting the change to the configured webhook.
+///
+/// # Returns
+///
+/// An HTTP response with the newly created event, `400 Bad Request` listing each invalid field,
+/// or an error message.
+pub async fn duplicate_event(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    data: Option<web::Json<DuplicateEventData>>,
+    pool: web::Data<SqlitePool>,
+    status_config: web::Data<EventStatusConfig>,
+    webhook: web::Data<WebhookNotifier>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let source = fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+    let source_id = source.id;
+
+    let DuplicateEventData { event_date, end_date, registration_deadline } = data.map(|data| data.into_inner()).unwrap_or_default();
+    let registration_deadline = match (registration_deadline, event_date) {
+        (Some(deadline), _) => deadline,
+        (None, Some(event_date)) => event_date,
+        (None, None) => source.registration_deadline,
+    };
+    let event_date = event_date.unwrap_or(source.event_date);
+    let end_date = end_date.unwrap_or_else(|| {
+        event_date.checked_add_signed(source.end_date - source.event_date).unwrap_or(event_date)
+    });
+
+    let new_event = EventData {
+        event_date,
+        end_date: Some(end_date),
+        registration_deadline: registration_deadline.to_string(),
+        status: "upcoming".to_string(),
+        approval_status: status_config.initial_approval_status().to_string(),
+        organizer_id: session.user_id,
+        tickets_sold: 0,
+        attendees: 0,
+        is_template: 0,
+        ..EventData::from(source)
+    };
+
+    let category_ids = fetch_category_ids(GetCategoriesData {organizer_id: session.user_id}, &pool).await?;
+    if let Err(errors) = validate_event_data(&new_event, &category_ids) {
+        debug!(target: "event", "Rejected duplicate of event {} from organizer {}: {}", source_id, session.user_id, errors);
+        return Err(errors.into());
+    }
+
+    let event = create_event_from(source_id, new_event, &pool).await?;
+
+    info!(target: "event", "Organizer {} created event {} from event {}", session.user_id, event.id, source_id);
+    record_session_audit(&session, "event", event.id, "duplicate", Some(format!("Created from event {}", source_id)), &pool).await;
+    webhook.notify_event_change(EventWebhookData {event_id: event.id, action: "create", organizer_id: session.user_id});
+
+    Ok(HttpResponse::Created().json(event))
+}
+
+
+/// Imports an event exported with `GET /events/{id}/export/` under the authenticated organizer.
+///
+/// The event and all of its children are recreated with fresh ids in a single transaction.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `data` - The JSON body containing the exported event tree.
+/// * `pool` - The SQLite database connection pool.
+/// * `status_config` - Settings providing the approval status of the imported event.
+/// * `webhook` - Notifier posting the change to the configured webhook.
+///
+/// # Returns
+///
+/// An HTTP response with the imported event tree, `400 Bad Request` listing each invalid field,
+/// or an error message.
+pub async fn import_one_event(
+    req: HttpRequest,
+    data: web::Json<EventExport>,
+    pool: web::Data<SqlitePool>,
+    status_config: web::Data<EventStatusConfig>,
+    webhook: web::Data<WebhookNotifier>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let category_ids = fetch_category_ids(GetCategoriesData {organizer_id: session.user_id}, &pool).await?;
+    if let Err(errors) = validate_event_export(&data, &category_ids) {
+        debug!(target: "event", "Rejected event import from organizer {}: {}", session.user_id, errors);
+        return Err(errors.into());
+    }
+
+    let mut export = data.into_inner();
+    export.event.approval_status = status_config.initial_approval_status().to_string();
+
+    let export = import_event(export, session.user_id, &pool).await?;
+
+    info!(target: "event", "Organizer {} imported event {}", session.user_id, export.event.id);
+    record_session_audit(&session, "event", export.event.id, "import", None, &pool).await;
+    webhook.notify_event_change(EventWebhookData {event_id: export.event.id, action: "create", organizer_id: session.user_id});
+
+    Ok(HttpResponse::Ok().json(export))
+}
+
+
+/// Runs the create-time validations against event data without persisting it.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `data` - The JSON body containing the event data to validate.
+/// * `pool` - The SQLite database connection pool.
+/// * `status_config` - Settings providing the default status for events submitted without one.
+///
+/// # Returns
+///
+/// `200 OK` with `{"valid": true}` if the data would be accepted by event creation,
+/// or `422 Unprocessable Entity` listing each invalid field.
+pub async fn validate_event_draft(
+    req: HttpRequest,
+    data: web::Json<EventData>,
+    pool: web::Data<SqlitePool>,
+    status_config: web::Data<EventStatusConfig>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let mut data = data.into_inner();
+    apply_event_defaults(&mut data, &status_config);
+
+    let category_ids = fetch_category_ids(GetCategoriesData {organizer_id: session.user_id}, &pool).await?;
+    match validate_event_data(&data, &category_ids) {
+        Ok(()) => Ok(HttpResponse::Ok().json(EventValidation { valid: true })),
+        Err(errors) => Ok(HttpResponse::UnprocessableEntity().json(errors)),
+    }
+}
+
+
+/// Handles registering a new events details under the authenticated organizer.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `query` - Query parameters; `strict_speakers=true` rejects agenda items whose speaker
+///   is not one of the event's speakers.
+/// * `data` - The JSON body containing new event detail data.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response indicating success or failure of event detail creation.
+pub async fn register_event_details(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    query: web::Query<EventDetailsQuery>,
+    data: web::Json<CreateEventDetails>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+    
+    fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+    
+    let CreateEventDetails { 
+        mut agenda, 
+        mut speakers, 
+        mut faqs, 
+        mut attachments, 
+    } = data.into_inner();
+
+    // Items always belong to the event in the path, whatever `event_id` the body carries
+    agenda.iter_mut().for_each(|item| item.event_id = *event_id);
+    speakers.iter_mut().for_each(|item| item.event_id = *event_id);
+    faqs.iter_mut().for_each(|item| item.event_id = *event_id);
+    attachments.iter_mut().for_each(|item| item.event_id = *event_id);
+
+    let stored_speakers = fetch_speakers(GetSpeakerData { event_id: *event_id }, &pool).await?;
+
+    if query.strict_speakers {
+        let speaker_names: Vec<&str> = stored_speakers.iter()
+            .chain(speakers.iter())
+            .map(|speaker| speaker.name.as_str())
+            .collect();
+
+        validate_agenda_speakers(&agenda, &speaker_names)?;
+    }
+    
+    let mut tx = pool.begin().await?;
+
+    let speaker_items = create_speakers(speakers, &mut tx).await?;
+
+    link_agenda_speakers(&mut agenda, &stored_speakers);
+    link_agenda_speakers(&mut agenda, &speaker_items);
+
+    let agenda_items = create_agenda(agenda, &mut tx).await?;
+    let faq_items = create_faqs(faqs, &mut tx).await?;
+    let attachment_items = create_attachments(attachments, &mut tx).await?;
+
+    tx.commit().await?;
+
+    record_session_audit(&session, "event", *event_id, "create_details", None, &pool).await;
+
+    Ok(HttpResponse::Ok().json(CreateEventDetails {
+        agenda: agenda_items, 
+        speakers: speaker_items,
+        faqs: faq_items,
+        attachments: attachment_items,
+    }))
+}
+
+
+/// Handles copying detail items (faqs, speakers, and optionally agenda and attachments)
+/// from a source event onto a target event, both owned by the authenticated organizer.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `path` - The path parameters representing the target and source event IDs.
+/// * `data` - The optional JSON body selecting which collections to copy.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the number of copied items per collection, or an error message.
+pub async fn copy_event_details(
+    req: HttpRequest,
+    path: web::Path<(i64, i64)>,
+    data: Option<web::Json<CopyEventDetailsData>>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let (event_id, source_id) = path.into_inner();
+
+    if event_id == source_id {
+        return Err(AppError::BadRequest("Cannot copy event details onto the same event".to_string()));
+    }
+
+    let event = fetch_event(GetEventData {event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+    let source = fetch_event(GetEventData {event_id: source_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Source event", e))?;
+
+    let selection = data.map(|data| data.into_inner()).unwrap_or_default();
+
+    let mut tx = pool.begin().await?;
+
+    let mut copied = CopiedEventDetails { agenda: 0, speakers: 0, faqs: 0, attachments: 0 };
+
+    if selection.speakers {
+        copied.speakers = copy_speakers(source.id, event.id, &mut tx).await?;
+    }
+    if selection.agenda {
+        copied.agenda = copy_agenda(source.id, event.id, &mut tx).await?;
+    }
+    if selection.faqs {
+        copied.faqs = copy_faqs(source.id, event.id, &mut tx).await?;
+    }
+    if selection.attachments {
+        copied.attachments = copy_attachments(source.id, event.id, &mut tx).await?;
+    }
+
+    tx.commit().await?;
+
+    record_session_audit(&session, "event", event.id, "copy_details", Some(format!("Copied from event {}", source.id)), &pool).await;
+
+    Ok(HttpResponse::Ok().json(copied))
+}
+
+
+/// Handles updating an event under the authenticated organizer.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `data` - The JSON body containing new event data.
+/// * `pool` - The SQLite database connection pool.
+/// * `upload_config` - Settings limiting the size of an uploaded image.
+/// * `webhook` - Notifier posting the change to the configured webhook.
+///
+/// # Returns
+///
+/// An HTTP response indicating success or failure of updating event.
+pub async fn put_event(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    data: web::Json<Event>,
+    pool: web::Data<SqlitePool>,
+    upload_config: web::Data<ImageUploadConfig>,
+    webhook: web::Data<WebhookNotifier>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let event = fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    let category_ids = fetch_category_ids(GetCategoriesData {organizer_id: session.user_id}, &pool).await?;
+    if let Err(errors) = validate_event(&data, &category_ids) {
+        debug!(target: "event", "Rejected update of event {} from organizer {}: {}", event_id, session.user_id, errors);
+        return Err(errors.into());
+    }
+
+    let mut data = data.into_inner();
+    let mut saved_image = None;
+
+    if let Some(image) = data.image.as_deref()
+        && is_data_uri(image) {
+        let path = match save_image(image, upload_config.max_bytes) {
+            Ok(path) => path,
+            Err(e) if e.kind() == ErrorKind::InvalidInput => return Err(ValidationErrors::single("image", e.to_string()).into()),
+            Err(e) => return Err(AppError::Internal(format!("Failed to save image: {}", e))),
+        };
+        data.image = Some(path.clone());
+        saved_image = Some(path);
+    }
+
+    let image_changed = data.image != event.image;
+
+    match update_event(Event {id: *event_id, ..data}, &pool).await {
+        Ok(()) => {
+            if image_changed
+                && let Some(old_image) = event.image.as_deref() {
+                release_image(old_image, &pool).await;
+            }
+            info!(target: "event", "Organizer {} updated event {}", session.user_id, event_id);
+            record_session_audit(&session, "event", *event_id, "update", None, &pool).await;
+            webhook.notify_event_change(EventWebhookData {event_id: *event_id, action: "update", organizer_id: session.user_id});
+            Ok(HttpResponse::Ok().body(format!("Event '{}' updated", event_id)))
+        },
+        Err(e) => {
+            // Do not leave behind an image no event references
+            if let Some(path) = saved_image
+                && let Err(delete_error) = delete_image(&path) {
+                warn!(target: "event", "Failed to delete image {} of failed update: {}", path, delete_error);
+            }
+            Err(AppError::Db(e))
+        },
+    }
+}
+
+
+/// Handles updating the detailed information of a specific event.
+///
+/// Each list replaces the event's items: submitted items are updated by ID and stored items
+/// missing from the list are deleted, all in one transaction.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID to update.
+/// * `query` - Query parameters; `strict_speakers=true` rejects agenda items whose speaker
+///   is not one of the event's speakers.
+/// * `data` - The JSON body containing new event detail data.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response indicating success or failure of the update operation.
+pub async fn put_event_details(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    query: web::Query<EventDetailsQuery>,
+    data: web::Json<EventDetails>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    let EventDetails { 
+        mut agenda, 
+        speakers, 
+        faqs, 
+        attachments, 
+        .. 
+    } = data.into_inner();
+
+    let stored_speakers = fetch_speakers(GetSpeakerData { event_id: *event_id }, &pool).await?;
+
+    if query.strict_speakers {
+        // Stored speakers missing from the payload are deleted below, so only submitted ones count
+        let speaker_names: Vec<&str> = speakers.iter()
+            .map(|speaker| speaker.name.as_str())
+            .collect();
+
+        validate_agenda_speakers(&agenda, &speaker_names)?;
+    }
+
+    link_agenda_speakers(&mut agenda, &speakers);
+
+    let stored_agenda = fetch_agenda(GetAgendaData { event_id: *event_id }, &pool).await?;
+    let stored_faqs = fetch_faqs(GetFaqData { event_id: *event_id }, &pool).await?;
+    let stored_attachments = fetch_attachments(GetAttachmentData { event_id: *event_id }, &pool).await?;
+
+    let removed_agenda = removed_item_ids(&stored_agenda, &agenda, |item| item.id);
+    let removed_speakers = removed_item_ids(&stored_speakers, &speakers, |item| item.id);
+    let removed_faqs = removed_item_ids(&stored_faqs, &faqs, |item| item.id);
+    let removed_attachments = removed_item_ids(&stored_attachments, &attachments, |item| item.id);
+
+    let mut tx = pool.begin().await?;
+
+    update_speakers(*event_id, speakers, &mut tx).await?;
+    update_agenda(*event_id, agenda, &mut tx).await?;
+    update_faqs(*event_id, faqs, &mut tx).await?;
+    update_attachments(*event_id, attachments, &mut tx).await?;
+
+    // Deleted after the updates so agenda items are unlinked from removed speakers
+    delete_agenda(DeleteAgendaData { event_id: *event_id, ids: removed_agenda }, &mut tx).await?;
+    delete_speakers(DeleteSpeakersData { event_id: *event_id, ids: removed_speakers }, &mut tx).await?;
+    delete_faqs(DeleteFaqsData { event_id: *event_id, ids: removed_faqs }, &mut tx).await?;
+    delete_attachments(DeleteAttachmentsData { event_id: *event_id, ids: removed_attachments }, &mut tx).await?;
+
+    tx.commit().await?;
+
+    record_session_audit(&session, "event", *event_id, "update_details", None, &pool).await;
+    
+    Ok(HttpResponse::Ok().body("Event details updated"))
+}
+
+
+/// Handles changing the status of several events of the authenticated organizer in one transaction.
+///
+/// Every event must belong to the organizer; otherwise no event is updated.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `data` - The JSON body containing the event IDs and the new status.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the number of updated events, `400 Bad Request` listing each invalid
+/// field, or an error message.
+pub async fn bulk_update_event_status(
+    req: HttpRequest,
+    data: web::Json<BulkStatusData>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let BulkStatusData { event_ids, status } = data.into_inner();
+
+    let mut tx = pool.begin().await?;
+
+    let owned = fetch_owned_event_ids(GetOwnedEventIdsData { organizer_id: session.user_id, event_ids: event_ids.clone() }, &mut tx).await?;
+
+    if let Err(errors) = validate_bulk_status(&event_ids, &status, &owned) {
+        debug!(target: "event", "Rejected status change of events {:?} by organizer {}: {}", event_ids, session.user_id, errors);
+        return Err(errors.into());
+    }
+
+    let detail = format!("Set status of events {:?} to '{}'", event_ids, status);
+
+    let updated = update_event_statuses(UpdateEventStatusesData { organizer_id: session.user_id, event_ids, status }, &mut tx).await?;
+
+    tx.commit().await?;
+
+    info!(target: "event", "Organizer {} changed the status of {} events", session.user_id, updated);
+    record_session_audit(&session, "organizer", session.user_id, "bulk_status", Some(detail), &pool).await;
+
+    Ok(HttpResponse::Ok().json(BulkStatusResult { updated }))
+}
+
+
+/// Deletes several child items of an event in one transaction, ensuring the organizer owns the event.
+///
+/// Every ID must belong to the event; otherwise nothing is deleted.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - Unique identifier of the event.
+/// * `ids` - The IDs of the items to delete.
+/// * `action` - The action recorded in the audit log, `delete_` followed by the plural name of the items.
+/// * `fetch_ids` - Retrieves the IDs of the event's items.
+/// * `delete` - Deletes the given items of the event, returning the number deleted.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the number of deleted items, `400 Bad Request` if an ID does not belong
+/// to the event, `404 Not Found` if the organizer does not own the event, or an error message.
+async fn delete_event_items(
+    req: &HttpRequest,
+    event_id: i64,
+    ids: Vec<i64>,
+    action: &'static str,
+    fetch_ids: impl AsyncFn(i64, &SqlitePool) -> Result<Vec<i64>, sqlx::Error>,
+    delete: impl AsyncFn(i64, Vec<i64>, &mut SqliteConnection) -> Result<u64, sqlx::Error>,
+    pool: &SqlitePool,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(req, pool).await?;
+    let kind = action.trim_start_matches("delete_");
+
+    fetch_event(GetEventData {event_id, organizer_id: session.user_id}, pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    let existing = fetch_ids(event_id, pool).await?;
+
+    if let Err(errors) = validate_event_item_ids(&ids, &existing) {
+        debug!(target: "event", "Rejected deletion of {} {:?} from event {}: {}", kind, ids, event_id, errors);
+        return Err(errors.into());
+    }
+
+    let detail = format!("Deleted {} {:?}", kind, ids);
+
+    let mut tx = pool.begin().await?;
+
+    let deleted = delete(event_id, ids, &mut tx).await?;
+
+    tx.commit().await?;
+
+    info!(target: "event", "Organizer {} deleted {} {} from event {}", session.user_id, deleted, kind, event_id);
+    record_session_audit(&session, "event", event_id, action, Some(detail), pool).await;
+
+    Ok(HttpResponse::Ok().json(BulkDeleteResult { deleted }))
+}
+
+
+/// Handles deleting several faqs of an event in one transaction.
+///
+/// Every ID must belong to the event; otherwise nothing is deleted.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `data` - The JSON body containing the IDs of the faqs to delete.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the number of deleted faqs, or an error message.
+pub async fn delete_event_faqs(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    data: web::Json<BulkDeleteData>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    delete_event_items(
+        &req,
+        *event_id,
+        data.into_inner().ids,
+        "delete_faqs",
+        async |event_id, pool| Ok(fetch_faqs(GetFaqData { event_id }, pool).await?.iter().map(|item| item.id).collect()),
+        async |event_id, ids, conn| delete_faqs(DeleteFaqsData { event_id, ids }, conn).await,
+        &pool,
+    ).await
+}
+
+
+/// Handles deleting several speakers of an event in one transaction.
+///
+/// Every ID must belong to the event; otherwise nothing is deleted.
+///
+/// Agenda items presented by a deleted speaker are kept but unlinked from it.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `data` - The JSON body containing the IDs of the speakers to delete.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the number of deleted speakers, or an error message.
+pub async fn delete_event_speakers(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    data: web::Json<BulkDeleteData>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    delete_event_items(
+        &req,
+        *event_id,
+        data.into_inner().ids,
+        "delete_speakers",
+        async |event_id, pool| Ok(fetch_speakers(GetSpeakerData { event_id }, pool).await?.iter().map(|item| item.id).collect()),
+        async |event_id, ids, conn| delete_speakers(DeleteSpeakersData { event_id, ids }, conn).await,
+        &pool,
+    ).await
+}
+
+
+/// Handles deleting a single speaker of an event owned by the authenticated organizer.
+///
+/// Agenda items presented by the speaker are kept but unlinked from it.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `path` - The path parameters representing the event and speaker IDs.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response indicating success, `404 Not Found` if the organizer does not own the event
+/// or the speaker does not belong to it, or an error message.
+pub async fn delete_event_speaker(
+    req: HttpRequest,
+    path: web::Path<(i64, i64)>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let (event_id, speaker_id) = path.into_inner();
+
+    fetch_event(GetEventData {event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    let mut tx = pool.begin().await?;
+
+    delete_speaker(DeleteSpeakerData { event_id, speaker_id }, &mut tx).await
+        .map_err(|e| AppError::lookup("Speaker", e))?;
+
+    tx.commit().await?;
+
+    info!(target: "event", "Organizer {} deleted speaker {} from event {}", session.user_id, speaker_id, event_id);
+    record_session_audit(&session, "event", event_id, "delete_speakers", Some(format!("Deleted speakers [{}]", speaker_id)), &pool).await;
+
+    Ok(HttpResponse::Ok().body("Speaker deleted"))
+}
+
+
+/// Handles deleting several attachments of an event in one transaction.
+///
+/// Every ID must belong to the event; otherwise nothing is deleted.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `data` - The JSON body containing the IDs of the attachments to delete.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the number of deleted attachments, or an error message.
+pub async fn delete_event_attachments(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    data: web::Json<BulkDeleteData>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    delete_event_items(
+        &req,
+        *event_id,
+        data.into_inner().ids,
+        "delete_attachments",
+        async |event_id, pool| Ok(fetch_attachments(GetAttachmentData { event_id }, pool).await?.iter().map(|item| item.id).collect()),
+        async |event_id, ids, conn| delete_attachments(DeleteAttachmentsData { event_id, ids }, conn).await,
+        &pool,
+    ).await
+}
+
+
+/// Handles soft-deleting an event owned by the authenticated organizer.
+///
+/// The event disappears from listings and analytics but keeps its attendees, details and
+/// image, and can be brought back with `restore_deleted_event`.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `pool` - The SQLite database connection pool.
+/// * `webhook` - Notifier posting the change to the configured webhook.
+///
+/// # Returns
+///
+/// An HTTP response indicating success, `404 Not Found` if the organizer owns no such event
+/// or it is already deleted, or an error message.
+pub async fn delete_event(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    pool: web::Data<SqlitePool>,
+    webhook: web::Data<WebhookNotifier>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let event_id = event_id.into_inner();
+
+    soft_delete_event(DeleteEventData {event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    info!(target: "event", "Organizer {} deleted event {}", session.user_id, event_id);
+    record_session_audit(&session, "event", event_id, "delete", None, &pool).await;
+    webhook.notify_event_change(EventWebhookData {event_id, action: "delete", organizer_id: session.user_id});
+
+    Ok(HttpResponse::Ok().body("Event deleted"))
+}
+
+
+/// Handles restoring a deleted event owned by the authenticated organizer.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `pool` - The SQLite database connection pool.
+/// * `webhook` - Notifier posting the change to the configured webhook.
+///
+/// # Returns
+///
+/// An HTTP response with the restored event, `404 Not Found` if the organizer has no such
+/// deleted event, or an error message.
+pub async fn restore_deleted_event(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    pool: web::Data<SqlitePool>,
+    webhook: web::Data<WebhookNotifier>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let event = restore_event(DeleteEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Deleted event", e))?;
+
+    info!(target: "event", "Organizer {} restored event {}", session.user_id, event.id);
+    record_session_audit(&session, "event", event.id, "restore", None, &pool).await;
+    webhook.notify_event_change(EventWebhookData {event_id: event.id, action: "restore", organizer_id: session.user_id});
+
+    Ok(HttpResponse::Ok().json(event))
 }
 
 
@@ -414,11 +1466,36 @@ pub fn configure_event_routes(cfg: &mut web::ServiceConfig) {
     cfg
         .route("/events/sales/", web::get().to(get_monthly_ticket_sales))
         .route("/events/counts/daily/", web::get().to(get_daily_event_counts))
+        .route("/events/counts/status/", web::get().to(get_event_status_counts))
         .route("/events/", web::get().to(get_events))
+        .route("/events/all/", web::get().to(get_all_events))
+        .route("/events/search/", web::get().to(get_event_search))
+        .route("/events/templates/", web::get().to(get_event_templates))
+        .route("/events/trash/", web::get().to(get_deleted_events))
+        .route("/events/locations/", web::get().to(get_event_locations))
         .route("/events/{id}/", web::get().to(get_event))
         .route("/events/{id}/details/", web::get().to(get_event_details))
+        .route("/events/{id}/full/", web::get().to(get_event_full))
+        .route("/events/{id}/export/", web::get().to(export_event))
+        .route("/events/{id}/agenda/grouped/", web::get().to(get_event_agenda_grouped))
+        .route("/events/{id}/revenue/", web::get().to(get_event_revenue))
+        .route("/events/{id}/attendee-counts/", web::get().to(get_event_attendee_counts))
+        .route("/public/events/{id}/", web::get().to(get_public_event))
         .route("/events/", web::post().to(register_event))
+        .route("/events/validate/", web::post().to(validate_event_draft))
+        .route("/events/status/bulk/", web::post().to(bulk_update_event_status))
+        .route("/events/import-one/", web::post().to(import_one_event))
+        .route("/events/{id}/duplicate/", web::post().to(duplicate_event))
+        .route("/events/{id}/clone/", web::post().to(duplicate_event))
         .route("/events/{id}/details/", web::post().to(register_event_details))
+        .route("/events/{id}/details/copy-from/{source_id}/", web::post().to(copy_event_details))
+        .route("/events/{id}/reconcile-counts/", web::post().to(reconcile_event_counts))
+        .route("/events/{id}/restore/", web::post().to(restore_deleted_event))
         .route("/events/{id}/", web::put().to(put_event))
-        .route("/events/{id}/details/", web::put().to(put_event_details));
+        .route("/events/{id}/details/", web::put().to(put_event_details))
+        .route("/events/{id}/faqs/", web::delete().to(delete_event_faqs))
+        .route("/events/{id}/speakers/", web::delete().to(delete_event_speakers))
+        .route("/events/{id}/speakers/{speaker_id}/", web::delete().to(delete_event_speaker))
+        .route("/events/{id}/attachments/", web::delete().to(delete_event_attachments))
+        .route("/events/{id}/", web::delete().to(delete_event));
 }
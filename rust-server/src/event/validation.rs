@@ -0,0 +1,528 @@
+// External Libraries
+use chrono::{NaiveDate, NaiveTime};
+
+// Internal Models
+use crate::event::models::{Event, EventData, EventExport, EventStatusConfig, EVENT_STATUSES};
+
+// Internal Validation
+use crate::validation::{parse_date, validate_email, validate_flag, ValidationErrors};
+
+
+/// Fills in defaults for fields a new event was submitted without, and its approval status.
+///
+/// # Arguments
+///
+/// * `data` - The event data to complete.
+/// * `status_config` - Settings providing the default status and the initial approval status.
+pub fn apply_event_defaults(
+    data: &mut EventData,
+    status_config: &EventStatusConfig
+) {
+    if data.status.trim().is_empty() {
+        data.status = status_config.default_status.clone();
+    }
+    data.approval_status = status_config.initial_approval_status().to_string();
+}
+
+
+/// Validates the client-supplied fields of a new event.
+///
+/// Besides the per-field checks, the registration deadline must not fall after `event_date`,
+/// a single-day event must not end before it starts, the counters must satisfy
+/// `attendees <= tickets_sold <= max_attendees` and the category must be visible to the organizer.
+///
+/// # Arguments
+///
+/// * `data` - The event data to validate.
+/// * `category_ids` - The IDs of the categories visible to the organizer.
+///
+/// # Returns
+///
+/// `Ok(())` if every field is valid, or `Err(ValidationErrors)` listing each invalid field.
+pub fn validate_event_data(
+    data: &EventData,
+    category_ids: &[i64]
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+
+    if let Some(registration_deadline) = parse_date(&data.registration_deadline, "registration_deadline", &mut errors)
+        && registration_deadline > data.event_date {
+        errors.add("registration_deadline", format!("must be on or before event_date ({})", data.event_date));
+    }
+    if data.end_date.is_none_or(|end_date| end_date == data.event_date) {
+        validate_time_order(&data.start_time, &data.end_time, &mut errors);
+    }
+    if !EVENT_STATUSES.contains(&data.status.as_str()) {
+        errors.add("status", format!("'{}' is not a valid status (expected one of: {})", data.status, EVENT_STATUSES.join(", ")));
+    }
+    if let Some(end_date) = data.end_date {
+        validate_end_date(data.event_date, end_date, &mut errors);
+    }
+    validate_non_negative(data.price, data.tickets_sold, data.attendees, data.max_attendees, &mut errors);
+    validate_capacity(data.tickets_sold, data.attendees, data.max_attendees, &mut errors);
+    validate_category(data.category_id, category_ids, &mut errors);
+    validate_email(&data.contact_email, "contact_email", &mut errors);
+    validate_flag(data.is_virtual, "is_virtual", &mut errors);
+
+    errors.into_result()
+}
+
+
+/// Validates the client-supplied fields of an updated event.
+///
+/// The counters must satisfy `attendees <= tickets_sold <= max_attendees` and the category must
+/// be visible to the organizer.
+///
+/// # Arguments
+///
+/// * `event` - The updated event to validate.
+/// * `category_ids` - The IDs of the categories visible to the organizer.
+///
+/// # Returns
+///
+/// `Ok(())` if every field is valid, or `Err(ValidationErrors)` listing each invalid field.
+pub fn validate_event(
+    event: &Event,
+    category_ids: &[i64]
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+
+    validate_end_date(event.event_date, event.end_date, &mut errors);
+    validate_non_negative(event.price, event.tickets_sold, event.attendees, event.max_attendees, &mut errors);
+    validate_capacity(event.tickets_sold, event.attendees, event.max_attendees, &mut errors);
+    validate_category(event.category_id, category_ids, &mut errors);
+    validate_email(&event.contact_email, "contact_email", &mut errors);
+    validate_flag(event.is_virtual, "is_virtual", &mut errors);
+
+    errors.into_result()
+}
+
+
+/// Validates an exported event tree before it is imported.
+///
+/// Checks the event's own fields with the same rules as an update, and that each child item is
+/// internally consistent: required text is present and agenda items only link to speakers
+/// within the export.
+///
+/// # Arguments
+///
+/// * `export` - The event tree to validate.
+/// * `category_ids` - The IDs of the categories visible to the importing organizer.
+///
+/// # Returns
+///
+/// `Ok(())` if the tree can be imported, or `Err(ValidationErrors)` listing each invalid field.
+pub fn validate_event_export(
+    export: &EventExport,
+    category_ids: &[i64]
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+
+    if export.event.title.trim().is_empty() {
+        errors.add("event.title", "must not be empty");
+    }
+    if !EVENT_STATUSES.contains(&export.event.status.as_str()) {
+        errors.add("event.status", format!("'{}' is not a valid status (expected one of: {})", export.event.status, EVENT_STATUSES.join(", ")));
+    }
+    if let Err(event_errors) = validate_event(&export.event, category_ids) {
+        errors.merge("event", event_errors);
+    }
+
+    for (index, speaker) in export.speakers.iter().enumerate() {
+        if speaker.name.trim().is_empty() {
+            errors.add(&format!("speakers[{}].name", index), "must not be empty");
+        }
+    }
+    for (index, agenda_item) in export.agenda.iter().enumerate() {
+        if agenda_item.title.trim().is_empty() {
+            errors.add(&format!("agenda[{}].title", index), "must not be empty");
+        }
+        if let Some(speaker_id) = agenda_item.speaker_id
+            && !export.speakers.iter().any(|speaker| speaker.id == speaker_id) {
+            errors.add(&format!("agenda[{}].speaker_id", index), format!("speaker {} is not part of the export", speaker_id));
+        }
+    }
+    for (index, faq) in export.faqs.iter().enumerate() {
+        if faq.question.trim().is_empty() {
+            errors.add(&format!("faqs[{}].question", index), "must not be empty");
+        }
+    }
+    for (index, attachment) in export.attachments.iter().enumerate() {
+        if attachment.url.trim().is_empty() {
+            errors.add(&format!("attachments[{}].url", index), "must not be empty");
+        }
+    }
+    for (index, comment) in export.comments.iter().enumerate() {
+        if comment.message.trim().is_empty() {
+            errors.add(&format!("comments[{}].message", index), "must not be empty");
+        }
+    }
+    for (index, attendee) in export.attendees.iter().enumerate() {
+        if attendee.email.trim().is_empty() {
+            errors.add(&format!("attendees[{}].email", index), "must not be empty");
+        }
+        if attendee.checked_in != 0 && attendee.confirmed == 0 {
+            errors.add(&format!("attendees[{}].checked_in", index), "an unconfirmed attendee cannot be checked in");
+        }
+    }
+
+    errors.into_result()
+}
+
+
+/// Validates that every requested ID belongs to the event's existing items.
+///
+/// # Arguments
+///
+/// * `requested` - The IDs submitted for deletion.
+/// * `existing` - The IDs of the items currently attached to the event.
+///
+/// # Returns
+///
+/// `Ok(())` if the list is non-empty and every ID belongs to the event, or
+/// `Err(ValidationErrors)` listing each foreign ID.
+pub fn validate_event_item_ids(
+    requested: &[i64],
+    existing: &[i64]
+) -> Result<(), ValidationErrors> {
+    if requested.is_empty() {
+        return Err(ValidationErrors::single("ids", "must not be empty"));
+    }
+
+    let mut errors = ValidationErrors::new();
+
+    for (index, id) in requested.iter().enumerate() {
+        if !existing.contains(id) {
+            errors.add(&format!("ids[{}]", index), format!("{} does not belong to this event", id));
+        }
+    }
+
+    errors.into_result()
+}
+
+
+/// Validates a bulk status change of an organizer's events.
+///
+/// # Arguments
+///
+/// * `event_ids` - The IDs of the events to update.
+/// * `status` - The requested status.
+/// * `owned` - The IDs among `event_ids` that belong to the organizer.
+///
+/// # Returns
+///
+/// `Ok(())` if the list is non-empty, the status is known and every event belongs to the
+/// organizer, or `Err(ValidationErrors)` listing each invalid field.
+pub fn validate_bulk_status(
+    event_ids: &[i64],
+    status: &str,
+    owned: &[i64]
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+
+    if event_ids.is_empty() {
+        errors.add("event_ids", "must not be empty");
+    }
+    for (index, id) in event_ids.iter().enumerate() {
+        if !owned.contains(id) {
+            errors.add(&format!("event_ids[{}]", index), format!("event {} does not belong to this organizer", id));
+        }
+    }
+    if !EVENT_STATUSES.contains(&status) {
+        errors.add("status", format!("'{}' is not a valid status (expected one of: {})", status, EVENT_STATUSES.join(", ")));
+    }
+
+    errors.into_result()
+}
+
+
+/// Finds the existing items of an event that are missing from a submitted list.
+///
+/// # Arguments
+///
+/// * `existing` - The items currently attached to the event.
+/// * `submitted` - The items the client sent back.
+/// * `id` - Returns the ID of an item.
+///
+/// # Returns
+///
+/// The IDs of the existing items whose ID is not among the submitted items.
+pub fn removed_item_ids<T>(
+    existing: &[T],
+    submitted: &[T],
+    id: impl Fn(&T) -> i64
+) -> Vec<i64> {
+    existing.iter()
+        .map(&id)
+        .filter(|existing_id| !submitted.iter().any(|item| id(item) == *existing_id))
+        .collect()
+}
+
+
+/// Records an error if a multi-day event ends before it starts.
+fn validate_end_date(
+    event_date: NaiveDate,
+    end_date: NaiveDate,
+    errors: &mut ValidationErrors
+) {
+    if end_date < event_date {
+        errors.add("end_date", format!("must be on or after event_date ({})", event_date));
+    }
+}
+
+
+/// Records an error if `end_time` is earlier than `start_time`.
+///
+/// Times are compared only if both parse as `HH:MM` or `HH:MM:SS`.
+fn validate_time_order(
+    start_time: &str,
+    end_time: &str,
+    errors: &mut ValidationErrors
+) {
+    let parse = |value: &str| NaiveTime::parse_from_str(value, "%H:%M")
+        .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M:%S"))
+        .ok();
+
+    if let (Some(start), Some(end)) = (parse(start_time), parse(end_time))
+        && end < start {
+        errors.add("end_time", format!("must not be earlier than start_time ({}) on a single-day event", start_time));
+    }
+}
+
+
+/// Records an error for each numeric field that is negative.
+///
+/// Zero is allowed for every field.
+fn validate_non_negative(
+    price: f64,
+    tickets_sold: i64,
+    attendees: i64,
+    max_attendees: i64,
+    errors: &mut ValidationErrors
+) {
+    if price < 0.0 {
+        errors.add("price", "must not be negative");
+    }
+    if tickets_sold < 0 {
+        errors.add("tickets_sold", "must not be negative");
+    }
+    if attendees < 0 {
+        errors.add("attendees", "must not be negative");
+    }
+    if max_attendees < 0 {
+        errors.add("max_attendees", "must not be negative");
+    }
+}
+
+
+/// Records an error unless the category is one of those visible to the organizer.
+///
+/// Organizers may file events under the shared categories and their own, but not under another
+/// organizer's private categories.
+fn validate_category(
+    category_id: i64,
+    category_ids: &[i64],
+    errors: &mut ValidationErrors
+) {
+    if !category_ids.contains(&category_id) {
+        errors.add("category_id", format!("category {} does not exist or is not available to this organizer", category_id));
+    }
+}
+
+
+/// Records an error for each counter that exceeds the capacity it counts against.
+///
+/// Checked-in attendees must hold a sold ticket and no more tickets may be sold than there are
+/// seats, so `attendees <= tickets_sold <= max_attendees`. Equal values are allowed.
+fn validate_capacity(
+    tickets_sold: i64,
+    attendees: i64,
+    max_attendees: i64,
+    errors: &mut ValidationErrors
+) {
+    if attendees > tickets_sold {
+        errors.add("attendees", format!("must not exceed tickets_sold ({})", tickets_sold));
+    }
+    if tickets_sold > max_attendees {
+        errors.add("tickets_sold", format!("must not exceed max_attendees ({})", max_attendees));
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CATEGORY_IDS: [i64; 2] = [1, 2];
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    fn event_data() -> EventData {
+        EventData {
+            title: "Conference".to_string(),
+            description: "Yearly conference".to_string(),
+            event_date: date("2026-06-15"),
+            end_date: None,
+            start_time: "09:00".to_string(),
+            end_time: "17:00".to_string(),
+            location: "Hall A".to_string(),
+            category_id: 1,
+            status: "upcoming".to_string(),
+            organizer_id: 1,
+            price: 10.0,
+            tickets_sold: 50,
+            attendees: 40,
+            max_attendees: 100,
+            contact_email: "organizer@example.com".to_string(),
+            contact_phone: "555-0100".to_string(),
+            registration_deadline: "2026-06-10".to_string(),
+            is_virtual: 0,
+            image: None,
+            map_embed: None,
+            accessibility_info: None,
+            safety_guidelines: None,
+            is_template: 0,
+            approval_status: String::new(),
+        }
+    }
+
+    fn fields(errors: ValidationErrors) -> Vec<String> {
+        errors.errors.into_iter().map(|e| e.field).collect()
+    }
+
+    fn event_data_fields(data: &EventData) -> Vec<String> {
+        validate_event_data(data, &CATEGORY_IDS).err().map(fields).unwrap_or_default()
+    }
+
+    fn capacity_fields(tickets_sold: i64, attendees: i64, max_attendees: i64) -> Vec<String> {
+        let mut errors = ValidationErrors::new();
+        validate_capacity(tickets_sold, attendees, max_attendees, &mut errors);
+        fields(errors)
+    }
+
+    #[test]
+    fn event_data_accepts_valid_event() {
+        assert!(event_data_fields(&event_data()).is_empty());
+    }
+
+    #[test]
+    fn event_data_rejects_deadline_after_event() {
+        let mut data = event_data();
+        data.registration_deadline = "2026-06-16".to_string();
+        assert_eq!(event_data_fields(&data), vec!["registration_deadline"]);
+    }
+
+    #[test]
+    fn event_data_accepts_deadline_on_event_date() {
+        let mut data = event_data();
+        data.registration_deadline = "2026-06-15".to_string();
+        assert!(event_data_fields(&data).is_empty());
+    }
+
+    #[test]
+    fn event_data_rejects_end_time_before_start_time() {
+        let mut data = event_data();
+        data.end_time = "08:30".to_string();
+        assert_eq!(event_data_fields(&data), vec!["end_time"]);
+    }
+
+    #[test]
+    fn event_data_rejects_negative_values() {
+        let mut data = event_data();
+        data.price = -1.0;
+        data.tickets_sold = -1;
+        data.attendees = -1;
+        data.max_attendees = -1;
+        let errors = event_data_fields(&data);
+        for field in ["price", "tickets_sold", "attendees", "max_attendees"] {
+            assert!(errors.iter().any(|e| e == field), "missing error for {}", field);
+        }
+    }
+
+    #[test]
+    fn defaults_fill_in_empty_status() {
+        let mut data = event_data();
+        data.status = " ".to_string();
+        apply_event_defaults(&mut data, &EventStatusConfig { default_status: "upcoming".to_string(), require_approval: false });
+        assert_eq!(data.status, "upcoming");
+        assert!(event_data_fields(&data).is_empty());
+    }
+
+    #[test]
+    fn defaults_keep_given_status() {
+        let mut data = event_data();
+        data.status = "canceled".to_string();
+        apply_event_defaults(&mut data, &EventStatusConfig { default_status: "upcoming".to_string(), require_approval: true });
+        assert_eq!(data.status, "canceled");
+        assert_eq!(data.approval_status, "pending");
+        assert!(event_data_fields(&data).is_empty());
+    }
+
+    #[test]
+    fn event_data_rejects_unknown_status() {
+        let mut data = event_data();
+        data.status = "postponed".to_string();
+        apply_event_defaults(&mut data, &EventStatusConfig { default_status: "upcoming".to_string(), require_approval: false });
+        assert_eq!(event_data_fields(&data), vec!["status"]);
+    }
+
+    #[test]
+    fn end_date_accepts_single_day_event() {
+        let mut errors = ValidationErrors::new();
+        validate_end_date(date("2026-06-15"), date("2026-06-15"), &mut errors);
+        assert!(fields(errors).is_empty());
+    }
+
+    #[test]
+    fn end_date_accepts_multi_day_event() {
+        let mut errors = ValidationErrors::new();
+        validate_end_date(date("2026-06-15"), date("2026-06-17"), &mut errors);
+        assert!(fields(errors).is_empty());
+    }
+
+    #[test]
+    fn end_date_rejects_end_before_start() {
+        let mut errors = ValidationErrors::new();
+        validate_end_date(date("2026-06-15"), date("2026-06-14"), &mut errors);
+        assert_eq!(fields(errors), vec!["end_date"]);
+    }
+
+    #[test]
+    fn event_data_skips_time_order_on_multi_day_event() {
+        let mut data = event_data();
+        data.end_date = Some(date("2026-06-16"));
+        data.end_time = "08:30".to_string();
+        assert!(event_data_fields(&data).is_empty());
+    }
+
+    #[test]
+    fn non_negative_accepts_zero() {
+        let mut errors = ValidationErrors::new();
+        validate_non_negative(0.0, 0, 0, 0, &mut errors);
+        assert!(fields(errors).is_empty());
+    }
+
+    #[test]
+    fn non_negative_rejects_each_negative_field() {
+        let mut errors = ValidationErrors::new();
+        validate_non_negative(-0.01, -1, -1, -1, &mut errors);
+        assert_eq!(fields(errors), vec!["price", "tickets_sold", "attendees", "max_attendees"]);
+    }
+
+    #[test]
+    fn capacity_accepts_counters_at_capacity() {
+        assert!(capacity_fields(100, 100, 100).is_empty());
+    }
+
+    #[test]
+    fn capacity_rejects_tickets_sold_one_over() {
+        assert_eq!(capacity_fields(101, 100, 100), vec!["tickets_sold"]);
+    }
+
+    #[test]
+    fn capacity_rejects_attendees_one_over() {
+        assert_eq!(capacity_fields(100, 101, 100), vec!["attendees"]);
+    }
+}
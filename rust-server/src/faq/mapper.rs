@@ -1,8 +1,9 @@
 // External Libraries
-use sqlx::SqlitePool;
+use log::debug;
+use sqlx::{SqliteConnection, SqlitePool};
 
 // Internal Models
-use crate::faq::models::{Faq, GetFaqData};
+use crate::faq::models::{Faq, GetFaqData, DeleteFaqsData};
 
 
 /// Retrieves faq items by their event ID.
@@ -27,7 +28,7 @@ pub async fn fetch_faqs(
 
     sqlx::query_as!(
         Faq,
-        "SELECT id, event_id, question, answer
+        "SELECT id, event_id, question, answer, created_at, updated_at
          FROM faqs
          WHERE event_id = ?",
         event_id
@@ -37,12 +38,42 @@ pub async fn fetch_faqs(
 }
 
 
+/// Retrieves a single faq by its ID.
+///
+/// # Arguments
+///
+/// * `id` - Unique identifier of the faq.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the `Faq` if found, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if no faq has the given ID.
+pub async fn fetch_faq(
+    id: i64,
+    pool: &SqlitePool
+) -> Result<Faq, sqlx::Error> {
+    sqlx::query_as!(
+        Faq,
+        "SELECT id, event_id, question, answer, created_at, updated_at
+         FROM faqs
+         WHERE id = ?",
+        id
+    )
+        .fetch_one(pool)
+        .await
+}
+
+
 /// Creates multiple faq items in the database.
 ///
 /// # Arguments
 ///
 /// * `data` - A vector of `Faq` structs containing the created faq items.
-/// * `pool` - A reference to the SQLite connection pool.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
 ///
 /// # Returns
 ///
@@ -53,8 +84,10 @@ pub async fn fetch_faqs(
 /// Returns an error if any of the creation queries fail during execution.
 pub async fn create_faqs(
     data: Vec<Faq>, 
-    pool: &SqlitePool
+    conn: &mut SqliteConnection
 ) -> Result<Vec<Faq>, sqlx::Error> {
+    debug!(target: "faq", "Creating {} faq(s)", data.len());
+
     let mut faqs = Vec::new();
     
     for faq_item in data {
@@ -62,10 +95,10 @@ pub async fn create_faqs(
             Faq,
             "INSERT INTO faqs (event_id, question, answer) 
              VALUES (?, ?, ?)
-             RETURNING id, event_id, question, answer",
+             RETURNING id, event_id, question, answer, created_at, updated_at",
             faq_item.event_id, faq_item.question, faq_item.answer
         )
-            .fetch_one(pool)
+            .fetch_one(&mut *conn)
             .await?;
         
         faqs.push(rec);
@@ -79,8 +112,9 @@ pub async fn create_faqs(
 ///
 /// # Arguments
 ///
+/// * `event_id` - Unique identifier of the event the items belong to.
 /// * `data` - A vector of `Faq` structs containing the updated faq items.
-/// * `pool` - A reference to the SQLite connection pool.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
 ///
 /// # Returns
 ///
@@ -88,22 +122,97 @@ pub async fn create_faqs(
 ///
 /// # Errors
 ///
-/// Returns an error if any of the update queries fail during execution.
+/// Returns an error if any of the update queries fail during execution. Items that do not
+/// belong to the event are left untouched.
 pub async fn update_faqs(
+    event_id: i64,
     data: Vec<Faq>, 
-    pool: &SqlitePool
+    conn: &mut SqliteConnection
 ) -> Result<(), sqlx::Error> {
+    debug!(target: "faq", "Updating faqs {:?}", data.iter().map(|faq_item| faq_item.id).collect::<Vec<_>>());
+
     for faq_item in data {
         sqlx::query_as!(
             Faq,
             "UPDATE faqs 
-             SET question = ?, answer = ?
-             WHERE id = ?",
-            faq_item.question, faq_item.answer, faq_item.id
+             SET question = ?, answer = ?, updated_at = CURRENT_TIMESTAMP
+             WHERE id = ? AND event_id = ?",
+            faq_item.question, faq_item.answer, faq_item.id, event_id
         )
-            .execute(pool)
+            .execute(&mut *conn)
             .await?;
     };
 
     Ok(())
-}
\ No newline at end of file
+}
+
+
+/// Copies every faq of one event onto another event.
+///
+/// # Arguments
+///
+/// * `source_event_id` - Unique identifier of the event to copy from.
+/// * `target_event_id` - Unique identifier of the event to copy onto.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` containing the number of copied rows, or an `sqlx::Error` if the insert fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails or any constraint is violated.
+pub async fn copy_faqs(
+    source_event_id: i64,
+    target_event_id: i64,
+    conn: &mut SqliteConnection
+) -> Result<u64, sqlx::Error> {
+    debug!(target: "faq", "Copying faqs from event {} to event {}", source_event_id, target_event_id);
+
+    let result = sqlx::query!(
+        "INSERT INTO faqs (event_id, question, answer)
+         SELECT ?, question, answer
+         FROM faqs
+         WHERE event_id = ?",
+        target_event_id, source_event_id
+    )
+        .execute(conn)
+        .await?;
+
+    Ok(result.rows_affected())
+}
+
+
+/// Deletes several faqs of an event by their IDs.
+///
+/// Only faqs belonging to the event are deleted; other IDs are ignored.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id` and the IDs to delete.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` containing the number of deleted rows, or an `sqlx::Error` if the delete fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails or any constraint is violated.
+pub async fn delete_faqs(
+    data: DeleteFaqsData,
+    conn: &mut SqliteConnection
+) -> Result<u64, sqlx::Error> {
+    debug!(target: "faq", "Deleting faqs {:?} of event {}", data.ids, data.event_id);
+
+    let ids = serde_json::to_string(&data.ids).unwrap_or_else(|_| "[]".to_string());
+
+    let result = sqlx::query!(
+        "DELETE FROM faqs WHERE event_id = ? AND id IN (SELECT value FROM json_each(?))",
+        data.event_id, ids
+    )
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(result.rows_affected())
+}
@@ -1,3 +1,4 @@
 // Internal Modules
 pub mod mapper;
-pub mod models;
\ No newline at end of file
+pub mod models;
+pub mod routes;
\ No newline at end of file
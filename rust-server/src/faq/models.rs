@@ -1,5 +1,6 @@
 // External Libraries
 use serde::{Serialize, Deserialize};
+use chrono::NaiveDateTime;
 
 
 /// Represents a faq in the system.
@@ -16,6 +17,14 @@ pub struct Faq {
 
     /// Answer of the faq
     pub answer: Option<String>,
+
+    /// Timestamp for when the faq was created.
+    #[serde(skip_deserializing)]
+    pub created_at: NaiveDateTime,
+
+    /// Timestamp for the last update to the faq.
+    #[serde(skip_deserializing)]
+    pub updated_at: NaiveDateTime,
 }
 
 
@@ -24,4 +33,14 @@ pub struct Faq {
 pub struct GetFaqData {
     /// Unique identifier for the event of the faq.
     pub event_id: i64,
-}
\ No newline at end of file
+}
+
+
+/// Data required to delete several faqs of an event.
+pub struct DeleteFaqsData {
+    /// Unique identifier for the event of the faqs.
+    pub event_id: i64,
+
+    /// Unique identifiers of the faqs to delete.
+    pub ids: Vec<i64>,
+}
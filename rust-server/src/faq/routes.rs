@@ -0,0 +1,60 @@
+// External Libraries
+use actix_web::{web, HttpRequest, HttpResponse};
+use sqlx::SqlitePool;
+
+// Internal Mappers
+use crate::faq::mapper::fetch_faq;
+use crate::event::mapper::fetch_event;
+
+// Internal Models
+use crate::event::models::GetEventData;
+
+// Internal Services
+use crate::auth::services::validate_session;
+
+// Internal Errors
+use crate::error::AppError;
+
+
+/// Handles retrieving a single faq of an event owned by the session's organizer.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `id` - The path parameter representing the faq's ID.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the faq, `404 Not Found` if it does not exist or the organizer
+/// does not own its event, or an error message.
+pub async fn get_faq(
+    req: HttpRequest,
+    id: web::Path<i64>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let faq = fetch_faq(*id, &pool).await
+        .map_err(|e| AppError::lookup("Faq", e))?;
+
+    fetch_event(GetEventData {event_id: faq.event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Faq", e))?;
+
+    Ok(HttpResponse::Ok().json(faq))
+}
+
+
+/// Configures all routes related to faqs.
+///
+/// # Arguments
+///
+/// * `cfg` - A mutable reference to the Actix service configuration.
+///
+/// # Returns
+///
+/// Adds all faq routes to the Actix web application.
+pub fn configure_faq_routes(cfg: &mut web::ServiceConfig) {
+    cfg
+        .route("/faqs/{id}/", web::get().to(get_faq));
+}
@@ -0,0 +1,23 @@
+// External Libraries
+use sqlx::SqlitePool;
+
+
+/// Runs a trivial query to check that the database can be reached.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// `Ok(())` if the query succeeds, or an `sqlx::Error` if no connection could be acquired
+/// or the database could not be queried.
+pub async fn ping_database(
+    pool: &SqlitePool
+) -> Result<(), sqlx::Error> {
+    sqlx::query!("SELECT 1 AS ok")
+        .fetch_one(pool)
+        .await?;
+
+    Ok(())
+}
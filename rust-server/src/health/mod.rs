@@ -0,0 +1,4 @@
+// Internal Modules
+pub mod mapper;
+pub mod models;
+pub mod routes;
\ No newline at end of file
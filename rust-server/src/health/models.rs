@@ -0,0 +1,13 @@
+// External Libraries
+use serde::Serialize;
+
+
+/// Health of the server and its database, as reported to load balancers and orchestrators.
+#[derive(Serialize)]
+pub struct HealthStatus {
+    /// Overall status, `ok` when every dependency is reachable and `unavailable` otherwise.
+    pub status: &'static str,
+
+    /// State of the database connection, `up` or `down`.
+    pub db: &'static str,
+}
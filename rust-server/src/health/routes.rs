@@ -0,0 +1,50 @@
+// External Libraries
+use actix_web::{web, HttpResponse, Responder};
+use log::warn;
+use sqlx::SqlitePool;
+
+// Internal Mappers
+use crate::health::mapper::ping_database;
+
+// Internal Models
+use crate::health::models::HealthStatus;
+
+
+/// Handles liveness and readiness probes by pinging the database.
+///
+/// No session is required.
+///
+/// # Arguments
+///
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// `200 OK` with `{"status":"ok","db":"up"}` if the database answers, or
+/// `503 Service Unavailable` with `"db":"down"` if it does not.
+pub async fn get_health(
+    pool: web::Data<SqlitePool>,
+) -> impl Responder {
+    match ping_database(&pool).await {
+        Ok(()) => HttpResponse::Ok().json(HealthStatus { status: "ok", db: "up" }),
+        Err(e) => {
+            warn!(target: "health", "Health check failed to reach the database: {}", e);
+            HttpResponse::ServiceUnavailable().json(HealthStatus { status: "unavailable", db: "down" })
+        },
+    }
+}
+
+
+/// Configures the health-check route.
+///
+/// # Arguments
+///
+/// * `cfg` - A mutable reference to the Actix service configuration.
+///
+/// # Returns
+///
+/// Adds the health-check route to the Actix web application.
+pub fn configure_health_routes(cfg: &mut web::ServiceConfig) {
+    cfg
+        .route("/health/", web::get().to(get_health));
+}
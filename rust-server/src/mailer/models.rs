@@ -0,0 +1,11 @@
+/// An email to send to a single recipient.
+pub struct Email {
+    /// Address the email is sent to.
+    pub to: String,
+
+    /// Subject line of the email.
+    pub subject: String,
+
+    /// Plain-text body of the email.
+    pub body: String,
+}
@@ -0,0 +1,39 @@
+// External Libraries
+use log::info;
+
+// Internal Models
+use crate::mailer::models::Email;
+
+
+/// Delivers emails to their recipients.
+///
+/// Shared across workers as `web::Data<dyn Mailer>`, so the delivery mechanism can be swapped
+/// without touching the handlers that send mail.
+pub trait Mailer: Send + Sync {
+    /// Sends an email without waiting for the delivery.
+    ///
+    /// # Arguments
+    ///
+    /// * `email` - The recipient, subject and body of the email.
+    fn send(
+        &self,
+        email: Email
+    );
+}
+
+
+/// Development mailer that writes every email to the log instead of delivering it.
+///
+/// The log then holds whatever the emails carry, such as confirmation links, so it is not
+/// meant for production.
+pub struct LogMailer;
+
+
+impl Mailer for LogMailer {
+    fn send(
+        &self,
+        email: Email
+    ) {
+        info!(target: "mailer", "Email to {} with subject '{}':\n{}", email.to, email.subject, email.body);
+    }
+}
@@ -1,32 +1,79 @@
 // External Libraries
 use actix_cors::Cors;
 use actix_files::Files;
-use actix_web::{App, HttpServer, web, http::header, middleware::Logger};
+use actix_web::{App, HttpServer, web, rt, http::header, middleware::{from_fn, Compress, Logger, NormalizePath, TrailingSlash}};
 use sqlx::sqlite::SqlitePoolOptions;
 use dotenv::dotenv;
 use std::env;
+use std::sync::Arc;
+use std::time::Duration;
 use env_logger::Env;
 
+// Internal Mappers
+use attendee::mapper::purge_unconfirmed_attendees;
+use auth::mapper::{fetch_user_by_username, purge_expired_sessions, update_user_role};
+use event::mapper::complete_past_events;
+
+// Internal Models
+use attendee::models::{LiveCountsConfig, RegistrationConfig};
+use auth::models::{GetUserData, PasswordPolicy, SessionConfig, UpdateUserRoleData, ADMIN_ROLE};
+use category::models::CategoryCacheConfig;
+use event::models::{CapacityWarningConfig, EventStatusConfig, ImageUploadConfig, EVENT_STATUSES};
+use organizer::models::FeedConfig;
+use webhook::models::WebhookConfig;
+
+// Internal Services
+use attendee::services::AttendeeCountBroadcaster;
+use auth::services::{init_password_pepper, LoginRateLimiter, RateLimiter};
+use mailer::services::{LogMailer, Mailer};
+use metrics::services::{record_metrics, Metrics};
+use webhook::services::WebhookNotifier;
+
+// Internal Errors
+use error::{json_error_handler, path_error_handler, query_error_handler};
+
 // Internal Routes
+use admin::routes::configure_admin_routes;
+use agenda::routes::configure_agenda_routes;
+use analytics::routes::configure_analytics_routes;
+use audit::routes::configure_audit_routes;
 use attendee::routes::configure_attendee_routes;
 use auth::routes::configure_auth_routes;
 use category::routes::configure_category_routes;
-use event::routes::configure_event_routes;
+use comment::routes::configure_comment_routes;
+use event::routes::{configure_event_routes, CAPACITY_WARNING_HEADER};
+use faq::routes::configure_faq_routes;
+use health::routes::configure_health_routes;
+use metrics::routes::configure_metrics_routes;
 use organizer::routes::configure_organizer_routes;
 use overview::routes::configure_overview_routes;
+use speaker::routes::configure_speaker_routes;
+use ticket_tier::routes::configure_ticket_tier_routes;
 
 // Internal Modules
+mod admin;
 mod agenda;
+mod analytics;
 mod attachment;
+mod audit;
 mod attendee;
 mod auth;
 mod category;
 mod comment;
+mod error;
 mod event;
 mod faq;
+mod health;
+mod mailer;
+mod metrics;
 mod organizer;
 mod overview;
 mod speaker;
+#[cfg(test)]
+mod test_utils;
+mod ticket_tier;
+mod validation;
+mod webhook;
 
 
 /// Initializes the application, sets up the database connection pool,
@@ -39,6 +86,9 @@ async fn main() -> std::io::Result<()> {
     // Initialize logging
     env_logger::init_from_env(Env::default().default_filter_or("info"));
 
+    // Fail fast if the password pepper is missing in a release build
+    init_password_pepper();
+
     // Retrieve the database URL from the environment
     let database_url = env::var("DATABASE_URL")
         .expect("DATABASE_URL must be set in the .env file");
@@ -49,29 +99,242 @@ async fn main() -> std::io::Result<()> {
         .await
         .expect("Failed to connect to database");
 
+    // Promote an existing user to admin so the admin endpoints have someone able to call them
+    if let Ok(admin_username) = env::var("ADMIN_USERNAME") {
+        let admin = fetch_user_by_username(GetUserData { username: admin_username.clone() }, &pool)
+            .await
+            .unwrap_or_else(|_| panic!("ADMIN_USERNAME '{}' must name an existing user", admin_username));
+        update_user_role(UpdateUserRoleData { user_id: admin.id, role: ADMIN_ROLE.to_string() }, &pool)
+            .await
+            .expect("Failed to promote ADMIN_USERNAME to admin");
+        log::info!(target: "auth", "Promoted user '{}' to admin", admin_username);
+    }
+
+    // Percentage of capacity sold at which events are flagged as nearly full
+    let capacity_config = CapacityWarningConfig {
+        threshold_percent: env::var("CAPACITY_WARNING_PERCENT")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(90),
+    };
+    if !(1..=100).contains(&capacity_config.threshold_percent) {
+        panic!("CAPACITY_WARNING_PERCENT must be between 1 and 100");
+    }
+
+    // Status given to new events submitted without one
+    let status_config = EventStatusConfig {
+        default_status: env::var("DEFAULT_EVENT_STATUS").unwrap_or_else(|_| "upcoming".to_string()),
+        require_approval: env::var("REQUIRE_EVENT_APPROVAL")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(false),
+    };
+    if !EVENT_STATUSES.contains(&status_config.default_status.as_str()) {
+        panic!("DEFAULT_EVENT_STATUS must be one of: {}", EVENT_STATUSES.join(", "));
+    }
+
+    // Largest image accepted as a base64 upload
+    let upload_config = ImageUploadConfig {
+        max_bytes: env::var("MAX_IMAGE_UPLOAD_BYTES")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(5 * 1024 * 1024),
+    };
+
+    // Largest JSON request body accepted; by default room for a base64 image plus the other fields
+    let json_body_limit: usize = env::var("MAX_JSON_BODY_BYTES")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(upload_config.max_bytes / 3 * 4 + 256 * 1024);
+    if json_body_limit < 1 {
+        panic!("MAX_JSON_BODY_BYTES must be at least 1");
+    }
+
+    // Public site that organizer feed items link to
+    let frontend_url = env::var("FRONTEND_URL").expect("FRONTEND_URL must be set");
+    let feed_config = FeedConfig {
+        site_url: frontend_url.clone(),
+    };
+
+    // Seconds clients may cache the category list
+    let category_cache_config = CategoryCacheConfig {
+        max_age: env::var("CATEGORIES_CACHE_MAX_AGE")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(0),
+    };
+
+    // Accounts a single IP may register within the registration window
+    let registration_limiter = web::Data::new(RateLimiter::new(
+        env::var("REGISTRATION_RATE_LIMIT")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(3),
+        Duration::from_secs(
+            env::var("REGISTRATION_RATE_WINDOW_SECS")
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(60 * 60),
+        ),
+    ));
+
+    // Failed logins a single IP may make within the login window
+    let login_limiter = web::Data::new(LoginRateLimiter(RateLimiter::new(
+        env::var("LOGIN_RATE_LIMIT")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(5),
+        Duration::from_secs(
+            env::var("LOGIN_RATE_WINDOW_SECS")
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(60),
+        ),
+    )));
+
+    // How long sessions stay valid, and whether use extends them
+    let session_config = SessionConfig {
+        lifetime_days: env::var("SESSION_LIFETIME_DAYS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(7),
+        sliding_expiration: env::var("SESSION_SLIDING_EXPIRATION")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(false),
+    };
+    if session_config.lifetime_days < 1 {
+        panic!("SESSION_LIFETIME_DAYS must be at least 1");
+    }
+
+    // Minimum requirements for new passwords
+    let password_policy = PasswordPolicy {
+        min_length: env::var("PASSWORD_MIN_LENGTH")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(8),
+        require_letter_and_digit: env::var("PASSWORD_REQUIRE_LETTER_AND_DIGIT")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(true),
+    };
+    if password_policy.min_length < 1 {
+        panic!("PASSWORD_MIN_LENGTH must be at least 1");
+    }
+
+    // Where event changes are posted for external integrations, and the secret signing them
+    let webhook_config = WebhookConfig {
+        url: env::var("WEBHOOK_URL").ok().filter(|url| !url.is_empty()),
+        secret: env::var("WEBHOOK_SECRET").ok().filter(|secret| !secret.is_empty()),
+    };
+    if webhook_config.url.is_some() && webhook_config.secret.is_none() {
+        log::warn!(target: "webhook", "WEBHOOK_URL is set without WEBHOOK_SECRET; notifications will be unsigned");
+    }
+    let webhook_notifier = web::Data::new(WebhookNotifier::new(webhook_config));
+
+    // Only the frontend may open the live attendee count WebSocket
+    let live_counts_config = LiveCountsConfig {
+        allowed_origin: frontend_url.clone(),
+    };
+
+    // Confirmation emails of attendee registrations
+    let registration_config = RegistrationConfig {
+        confirm_url: env::var("REGISTRATION_CONFIRM_URL")
+            .unwrap_or_else(|_| "http://localhost:8080/api/attendees/confirm/".to_string()),
+    };
+    let mailer: web::Data<dyn Mailer> = web::Data::from(Arc::new(LogMailer) as Arc<dyn Mailer>);
+
+    // Request metrics shared by all workers
+    let metrics = web::Data::new(Metrics::new());
+
+    // Live attendee count updates shared by all workers
+    let attendee_count_broadcaster = web::Data::new(AttendeeCountBroadcaster::new());
+
+    // Days an unconfirmed attendee registration is kept before being purged
+    let unconfirmed_registration_days: i64 = env::var("UNCONFIRMED_REGISTRATION_DAYS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(2);
+
+    // Periodically mark upcoming events whose date has passed as complete
+    // and purge abandoned attendee registrations and expired sessions
+    let task_pool = pool.clone();
+    rt::spawn(async move {
+        let mut interval = rt::time::interval(Duration::from_secs(60 * 60));
+        loop {
+            interval.tick().await;
+            match complete_past_events(&task_pool).await {
+                Ok(updated) => log::info!(target: "event", "Marked {} past event(s) as complete", updated),
+                Err(e) => log::error!(target: "event", "Failed to complete past events: {}", e),
+            }
+            match purge_unconfirmed_attendees(unconfirmed_registration_days, &task_pool).await {
+                Ok(purged) => log::info!(target: "attendee", "Purged {} unconfirmed registration(s)", purged),
+                Err(e) => log::error!(target: "attendee", "Failed to purge unconfirmed registrations: {}", e),
+            }
+            match purge_expired_sessions(&task_pool).await {
+                Ok(purged) => log::info!(target: "auth", "Purged {} expired session(s)", purged),
+                Err(e) => log::error!(target: "auth", "Failed to purge expired sessions: {}", e),
+            }
+        }
+    });
+
     // Start the Actix-web HTTP server
     HttpServer::new(move || {
         // Configure CORS middleware
         let cors = Cors::default()
-            .allowed_origin(&env::var("FRONTEND_URL").expect("FRONTEND_URL must be set"))
-            .allowed_methods(vec!["GET", "POST", "PUT", "DELETE", "OPTIONS"])
+            .allowed_origin(&frontend_url)
+            .allowed_methods(vec!["GET", "POST", "PUT", "PATCH", "DELETE", "OPTIONS"])
             .allowed_headers(vec![header::CONTENT_TYPE, header::ACCEPT])
+            .expose_headers(vec![CAPACITY_WARNING_HEADER, header::RETRY_AFTER.as_str()])
             .supports_credentials()
             .max_age(3600);
 
         // Construct the application with middleware, data, and routes
         App::new()
             .wrap(Logger::new(r#"%a "%r" %s"#)) // Log client IP, request line, and status
+            .wrap(from_fn(record_metrics)) // Count requests and time them per route
             .wrap(cors)
+            .wrap(Compress::default()) // Compress responses per the client's `Accept-Encoding`
+            .wrap(NormalizePath::new(TrailingSlash::Always)) // Append a trailing slash so `/events` matches `/events/`
             .app_data(web::Data::new(pool.clone())) // Inject DB pool as app data
+            .app_data(web::Data::new(capacity_config)) // Capacity warning threshold
+            .app_data(web::Data::new(category_cache_config)) // Category list caching
+            .app_data(web::Data::new(status_config.clone())) // Default status of new events
+            .app_data(web::Data::new(feed_config.clone())) // Links of the public organizer feeds
+            .app_data(web::Data::new(session_config)) // Session lifetime
+            .app_data(web::Data::new(password_policy)) // Password strength requirements
+            .app_data(registration_limiter.clone()) // Registration rate limit shared by all workers
+            .app_data(login_limiter.clone()) // Failed login limit shared by all workers
+            .app_data(attendee_count_broadcaster.clone()) // Live attendee count updates
+            .app_data(web::Data::new(live_counts_config.clone())) // Origin allowed to open the live count WebSocket
+            .app_data(metrics.clone()) // Request counts and latencies for Prometheus
+            .app_data(mailer.clone()) // Outgoing email, e.g. registration confirmations
+            .app_data(web::Data::new(registration_config.clone())) // Link in confirmation emails
+            .app_data(webhook_notifier.clone()) // Event change notifications for integrations
+            .app_data(web::Data::new(upload_config)) // Image upload size limit
+            .app_data(web::JsonConfig::default()
+                .limit(json_body_limit) // Reject oversized bodies before buffering them
+                .error_handler(json_error_handler)) // Structured JSON body errors, 413 when too large
+            .app_data(web::QueryConfig::default().error_handler(query_error_handler)) // Structured query string errors
+            .app_data(web::PathConfig::default().error_handler(path_error_handler)) // Structured path errors
             .service(
                 web::scope("/api") // API route grouping
+                    .configure(configure_admin_routes)
+                    .configure(configure_agenda_routes)
+                    .configure(configure_audit_routes)
+                    .configure(configure_analytics_routes)
                     .configure(configure_attendee_routes)
                     .configure(configure_auth_routes)
                     .configure(configure_category_routes)
+                    .configure(configure_comment_routes)
                     .configure(configure_event_routes)
+                    .configure(configure_faq_routes)
+                    .configure(configure_health_routes)
+                    .configure(configure_metrics_routes)
                     .configure(configure_organizer_routes)
                     .configure(configure_overview_routes)
+                    .configure(configure_speaker_routes)
+                    .configure(configure_ticket_tier_routes)
             )
             .service(Files::new("/static", "static").show_files_listing()) // Serve static files
     })
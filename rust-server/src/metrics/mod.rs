@@ -0,0 +1,3 @@
+// Internal Modules
+pub mod routes;
+pub mod services;
\ No newline at end of file
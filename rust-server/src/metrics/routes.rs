@@ -0,0 +1,40 @@
+// External Libraries
+use actix_web::{web, HttpResponse, Responder};
+
+// Internal Services
+use crate::metrics::services::Metrics;
+
+
+/// Handles Prometheus scrapes of the request metrics.
+///
+/// No session is required.
+///
+/// # Arguments
+///
+/// * `metrics` - The request metrics shared by all workers.
+///
+/// # Returns
+///
+/// `200 OK` with the metrics in the Prometheus text exposition format.
+pub async fn get_metrics(
+    metrics: web::Data<Metrics>,
+) -> impl Responder {
+    HttpResponse::Ok()
+        .content_type(prometheus::TEXT_FORMAT)
+        .body(metrics.render())
+}
+
+
+/// Configures the metrics route.
+///
+/// # Arguments
+///
+/// * `cfg` - A mutable reference to the Actix service configuration.
+///
+/// # Returns
+///
+/// Adds the metrics route to the Actix web application.
+pub fn configure_metrics_routes(cfg: &mut web::ServiceConfig) {
+    cfg
+        .route("/metrics/", web::get().to(get_metrics));
+}
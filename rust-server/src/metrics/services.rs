@@ -0,0 +1,130 @@
+// External Libraries
+use actix_web::{web, Error};
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::middleware::Next;
+use log::warn;
+use prometheus::{HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry, TextEncoder};
+use std::time::Instant;
+
+
+/// Route label of requests that did not match any route, so unknown paths do not each get a series.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+
+/// Request counts and latencies exposed for Prometheus scraping.
+///
+/// Shared across workers through `web::Data`, so every worker records into the same registry.
+pub struct Metrics {
+    /// Registry the metrics are gathered from.
+    registry: Registry,
+
+    /// Number of handled requests by method, route and status code.
+    requests: IntCounterVec,
+
+    /// Time taken to handle requests by method and route, in seconds.
+    durations: HistogramVec,
+}
+
+
+impl Metrics {
+    /// Creates the HTTP metrics and registers them in a new registry.
+    ///
+    /// # Returns
+    ///
+    /// A new `Metrics` with every series at zero.
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "Number of HTTP requests handled."),
+            &["method", "route", "status"]
+        ).expect("metric options are valid");
+        let durations = HistogramVec::new(
+            HistogramOpts::new("http_request_duration_seconds", "Time taken to handle HTTP requests, in seconds."),
+            &["method", "route"]
+        ).expect("metric options are valid");
+
+        registry.register(Box::new(requests.clone())).expect("metric names are unique");
+        registry.register(Box::new(durations.clone())).expect("metric names are unique");
+
+        Metrics { registry, requests, durations }
+    }
+
+
+    /// Records a handled request.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - HTTP method of the request.
+    /// * `route` - Pattern of the route that handled it, e.g. `/api/events/{id}/`.
+    /// * `status` - Status code of the response.
+    /// * `seconds` - Time taken to handle the request.
+    pub fn observe(
+        &self,
+        method: &str,
+        route: &str,
+        status: u16,
+        seconds: f64
+    ) {
+        self.requests.with_label_values(&[method, route, &status.to_string()]).inc();
+        self.durations.with_label_values(&[method, route]).observe(seconds);
+    }
+
+
+    /// Renders every metric in the Prometheus text exposition format.
+    ///
+    /// # Returns
+    ///
+    /// The metrics as text, or an empty string if they cannot be encoded.
+    pub fn render(&self) -> String {
+        TextEncoder::new()
+            .encode_to_string(&self.registry.gather())
+            .unwrap_or_else(|e| {
+                warn!(target: "metrics", "Failed to encode metrics: {}", e);
+                String::new()
+            })
+    }
+}
+
+
+/// Middleware recording the count, status code and latency of every request.
+///
+/// Requests are labeled with the pattern of the route that matched rather than the raw path,
+/// so `/api/events/1/` and `/api/events/2/` share a series.
+///
+/// # Arguments
+///
+/// * `req` - The incoming request.
+/// * `next` - The rest of the service chain.
+///
+/// # Returns
+///
+/// The response of the wrapped service, unchanged.
+///
+/// # Errors
+///
+/// Returns the error of the wrapped service, after recording it with its status code.
+pub async fn record_metrics(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let metrics = req.app_data::<web::Data<Metrics>>().cloned();
+    let method = req.method().to_string();
+    let started = Instant::now();
+
+    let result = next.call(req).await;
+
+    if let Some(metrics) = metrics {
+        let seconds = started.elapsed().as_secs_f64();
+        match &result {
+            Ok(res) => {
+                let route = res.request().match_pattern().unwrap_or_else(|| UNMATCHED_ROUTE.to_string());
+                metrics.observe(&method, &route, res.status().as_u16(), seconds);
+            },
+            Err(e) => metrics.observe(&method, UNMATCHED_ROUTE, e.as_response_error().status_code().as_u16(), seconds),
+        }
+    }
+
+    result
+}
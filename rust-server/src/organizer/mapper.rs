@@ -1,8 +1,17 @@
 // External Libraries
-use sqlx::SqlitePool;
+use log::debug;
+use sqlx::{SqliteConnection, SqlitePool};
 
 // Internal Models
-use crate::organizer::models::{Organizer, GetOrganizerData, DeleteOrganizerData};
+use crate::organizer::models::{
+    Organizer,
+    GetOrganizerData,
+    GetAllOrganizersData,
+    OrganizerSummary,
+    OrganizerSummaryPage,
+    DeleteOrganizerData,
+    UpdateOrganizerFieldsData
+};
 
 
 /// Retrieves a specific organizer by its ID.
@@ -35,32 +44,83 @@ pub async fn fetch_organizer(
 }
 
 
+/// Retrieves a page of all organizers along with their event counts.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the page `limit` and `offset`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing an `OrganizerSummaryPage`, or an `sqlx::Error` if a query fails.
+///
+/// # Errors
+///
+/// Returns an error if either query fails.
+pub async fn fetch_all_organizers(
+    data: GetAllOrganizersData,
+    pool: &SqlitePool
+) -> Result<OrganizerSummaryPage, sqlx::Error> {
+    let total = sqlx::query_scalar!("SELECT COUNT(*) FROM organizers")
+        .fetch_one(pool)
+        .await?;
+
+    let organizers = sqlx::query_as!(
+        OrganizerSummary,
+        "SELECT organizers.id AS \"id!\", organizers.name, organizers.logo, organizers.website,
+                COUNT(events.id) AS \"event_count!: i64\"
+         FROM organizers
+         LEFT JOIN events ON events.organizer_id = organizers.id AND events.deleted_at IS NULL
+         GROUP BY organizers.id
+         ORDER BY organizers.name, organizers.id
+         LIMIT ? OFFSET ?",
+        data.limit, data.offset
+    )
+        .fetch_all(pool)
+        .await?;
+
+    Ok(OrganizerSummaryPage {
+        organizers,
+        total,
+        limit: data.limit,
+        offset: data.offset,
+    })
+}
+
+
 /// Creates an organizer in the database.
 ///
+/// Creation is idempotent: if an organizer already exists for the given ID,
+/// the existing row is left untouched and returned.
+///
 /// # Arguments
 ///
 /// * `data` - A struct containing all the new organizer data.
-/// * `pool` - A reference to the SQLite connection pool.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
 ///
 /// # Returns
 ///
-/// A `Result` containing the newly created `Organizer`, or an `sqlx::Error` if the update fails.
+/// A `Result` containing the newly created or existing `Organizer`, or an `sqlx::Error` if the insert fails.
 ///
 /// # Errors
 ///
 /// Returns an error if the query fails or any constraint is violated.
 pub async fn create_organizer(
     data: Organizer,
-    pool: &SqlitePool
+    conn: &mut SqliteConnection
 ) -> Result<Organizer, sqlx::Error> {
+    debug!(target: "organizer", "Creating organizer {} ('{}')", data.id, data.name);
+
     let rec = sqlx::query_as!(
         Organizer,
         "INSERT INTO organizers (id, name, logo, website)
          VALUES (?, ?, ?, ?)
+         ON CONFLICT(id) DO UPDATE SET id = excluded.id
          RETURNING id, name, logo, website",
         data.id, data.name, data.logo, data.website
     )
-        .fetch_one(pool)
+        .fetch_one(&mut *conn)
         .await?;
 
     Ok(rec)
@@ -85,6 +145,8 @@ pub async fn update_organizer(
     data: Organizer,
     pool: &SqlitePool
 ) -> Result<(), sqlx::Error> {
+    debug!(target: "organizer", "Updating organizer {} ('{}')", data.id, data.name);
+
     sqlx::query_as!(
         Organizer,
         "UPDATE organizers
@@ -99,12 +161,47 @@ pub async fn update_organizer(
 }
 
 
+/// Updates only the provided fields of an organizer in the database.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `organizer_id` and the fields to update.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the updated `Organizer`, or an `sqlx::Error` if the update fails.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if the organizer does not exist, or an error if the query fails.
+pub async fn update_organizer_fields(
+    data: UpdateOrganizerFieldsData,
+    pool: &SqlitePool
+) -> Result<Organizer, sqlx::Error> {
+    let UpdateOrganizerFieldsData { organizer_id, fields } = data;
+
+    debug!(target: "organizer", "Patching organizer {} (name {:?}, website {:?})", organizer_id, fields.name, fields.website);
+
+    sqlx::query_as!(
+        Organizer,
+        "UPDATE organizers
+         SET name = COALESCE(?, name), logo = COALESCE(?, logo), website = COALESCE(?, website)
+         WHERE id = ?
+         RETURNING id, name, logo, website",
+        fields.name, fields.logo, fields.website, organizer_id
+    )
+        .fetch_one(pool)
+        .await
+}
+
+
 /// Removes a specific organizer by its ID.
 ///
 /// # Arguments
 ///
 /// * `data` - A struct containing the `organizer_id`.
-/// * `pool` - A reference to the SQLite connection pool.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
 ///
 /// # Returns
 ///
@@ -115,14 +212,16 @@ pub async fn update_organizer(
 /// Returns an error if the query fails.
 pub async fn delete_organizer(
     data: DeleteOrganizerData,
-    pool: &SqlitePool
+    conn: &mut SqliteConnection
 ) -> Result<(), sqlx::Error> {
+    debug!(target: "organizer", "Deleting organizer {}", data.organizer_id);
+
     sqlx::query!(
         "DELETE FROM organizers WHERE id = ?",
         data.organizer_id
     )
-        .execute(pool)
+        .execute(&mut *conn)
         .await?;
 
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,4 +1,6 @@
 // Internal Modules
 pub mod mapper;
 pub mod models;
-pub mod routes;
\ No newline at end of file
+pub mod routes;
+pub mod services;
+pub mod validation;
\ No newline at end of file
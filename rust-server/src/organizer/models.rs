@@ -3,7 +3,7 @@ use serde::{Serialize, Deserialize};
 
 
 /// Represents an organizer in the system.
-#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Organizer {
     /// Unique identifier for the organizer.
     pub id: i64,
@@ -19,6 +19,17 @@ pub struct Organizer {
 }
 
 
+/// Represents the publicly visible details of an organizer.
+#[derive(Serialize)]
+pub struct PublicOrganizer {
+    /// Name of the organizer.
+    pub name: String,
+
+    /// Logo of the organizer.
+    pub logo: Option<String>,
+}
+
+
 /// Represents a default organizer.
 impl Default for Organizer {
     fn default() -> Self {
@@ -46,6 +57,30 @@ pub struct OrganizerData {
 }
 
 
+/// Data for a partial organizer update; omitted fields keep their current value.
+#[derive(Deserialize)]
+pub struct PatchOrganizerData {
+    /// New name of the organizer.
+    pub name: Option<String>,
+
+    /// New logo of the organizer.
+    pub logo: Option<String>,
+
+    /// New website of the organizer.
+    pub website: Option<String>,
+}
+
+
+/// Data required to partially update an organizer.
+pub struct UpdateOrganizerFieldsData {
+    /// Unique identifier for the organizer.
+    pub organizer_id: i64,
+
+    /// The fields to update.
+    pub fields: PatchOrganizerData,
+}
+
+
 /// Data required to retrieve organizer info.
 #[derive(Deserialize)]
 pub struct GetOrganizerData {
@@ -59,4 +94,60 @@ pub struct GetOrganizerData {
 pub struct DeleteOrganizerData {
     /// Unique identifier for the organizer.
     pub organizer_id: i64,
-}
\ No newline at end of file
+}
+
+
+/// Data required to retrieve a page of all organizers.
+#[derive(Deserialize)]
+pub struct GetAllOrganizersData {
+    /// Maximum number of organizers to return.
+    pub limit: i64,
+
+    /// Number of organizers to skip.
+    pub offset: i64,
+}
+
+
+/// Represents an organizer along with the number of events they have created.
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct OrganizerSummary {
+    /// Unique identifier for the organizer.
+    pub id: i64,
+
+    /// Name of the organizer.
+    pub name: String,
+
+    /// Logo of the organizer.
+    pub logo: Option<String>,
+
+    /// Website of the organizer
+    pub website: Option<String>,
+
+    /// Number of events created by the organizer.
+    pub event_count: i64,
+}
+
+
+/// A page of organizers along with the total number of organizers.
+#[derive(Serialize)]
+pub struct OrganizerSummaryPage {
+    /// Organizers in the requested page, ordered by name.
+    pub organizers: Vec<OrganizerSummary>,
+
+    /// Total number of organizers.
+    pub total: i64,
+
+    /// Maximum number of organizers returned in this page.
+    pub limit: i64,
+
+    /// Number of organizers skipped before this page.
+    pub offset: i64,
+}
+
+
+/// Settings for the public organizer event feeds.
+#[derive(Clone)]
+pub struct FeedConfig {
+    /// Base URL of the public site that feed items link to.
+    pub site_url: String,
+}
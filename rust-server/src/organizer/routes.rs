@@ -1,15 +1,28 @@
 // External Libraries
-use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder, ResponseError};
+use log::info;
 use sqlx::SqlitePool;
 
 // Internal Mappers
-use crate::organizer::mapper::{fetch_organizer, create_organizer, update_organizer};
+use crate::organizer::mapper::{fetch_organizer, create_organizer, update_organizer, update_organizer_fields};
+use crate::auth::mapper::fetch_user_by_id;
+use crate::event::mapper::fetch_upcoming_events;
 
 // Internal Models
-use crate::organizer::models::{Organizer, OrganizerData, GetOrganizerData};
+use crate::organizer::models::{Organizer, OrganizerData, GetOrganizerData, PatchOrganizerData, UpdateOrganizerFieldsData, FeedConfig};
+use crate::auth::models::GetUserIDData;
 
 // Internal Services
+use crate::audit::services::record_session_audit;
 use crate::auth::services::validate_session;
+use crate::event::media::release_logo;
+use crate::organizer::services::build_event_feed;
+
+// Internal Validation
+use crate::organizer::validation::validate_organizer_website;
+
+// Internal Errors
+use crate::error::{lookup_error_response, AppError};
 
 
 /// Handles retrieving a specific organizer by session token.
@@ -28,12 +41,12 @@ pub async fn get_organizer(
 ) -> impl Responder {
     let session = match validate_session(&req, &pool).await {
         Ok(session) => session,
-        Err(response) => return response,
+        Err(e) => return e.error_response(),
     };
 
     match fetch_organizer(GetOrganizerData { organizer_id: session.user_id }, &pool).await {
         Ok(organizer) => HttpResponse::Ok().json(organizer),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Organizer not found: {}", e)),
+        Err(e) => lookup_error_response("Organizer", e),
     }
 }
 
@@ -56,7 +69,7 @@ pub async fn register_organizer(
 ) -> impl Responder {
     let session = match validate_session(&req, &pool).await {
         Ok(session) => session,
-        Err(response) => return response,
+        Err(e) => return e.error_response(),
     };
     
     let OrganizerData {
@@ -64,12 +77,24 @@ pub async fn register_organizer(
         logo,
         website,
     } = data.into_inner();
+
+    if let Err(errors) = validate_organizer_website(website.as_deref()) {
+        return errors.error_response();
+    }
     
     // TODO Save new image file and update image location reference
 
-    match create_organizer(Organizer {id: session.user_id, name, logo, website}, &pool).await {
-        Ok(organizer) => HttpResponse::Ok().json(organizer),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Failed to create organizer: {}", e)),
+    let mut conn = match pool.acquire().await {
+        Ok(conn) => conn,
+        Err(e) => return AppError::Internal(format!("Failed to create organizer: {}", e)).error_response(),
+    };
+
+    match create_organizer(Organizer {id: session.user_id, name, logo, website}, &mut conn).await {
+        Ok(organizer) => {
+            record_session_audit(&session, "organizer", organizer.id, "create", None, &pool).await;
+            HttpResponse::Ok().json(organizer)
+        },
+        Err(e) => AppError::Internal(format!("Failed to create organizer: {}", e)).error_response(),
     }
 }
 
@@ -92,26 +117,148 @@ pub async fn put_organizer(
 ) -> impl Responder {
     let session = match validate_session(&req, &pool).await {
         Ok(session) => session,
-        Err(response) => return response,
+        Err(e) => return e.error_response(),
     };
 
-    let organizer = match fetch_organizer(GetOrganizerData { organizer_id: session.user_id }, &pool).await {
-        Ok(organizer) => organizer,
-        Err(e) => return HttpResponse::InternalServerError().body(format!("Organizer not found: {}", e)),
-    };
+    if let Err(e) = fetch_organizer(GetOrganizerData { organizer_id: session.user_id }, &pool).await {
+        return lookup_error_response("Organizer", e);
+    }
+
+    if let Err(errors) = validate_organizer_website(data.website.as_deref()) {
+        return errors.error_response();
+    }
     
     // TODO Remove old and save new image file and update image location reference
-    if data.logo != organizer.logo {
-        
-    }
 
     match update_organizer(Organizer {id: session.user_id, ..data.into_inner()}, &pool).await {
-        Ok(()) => HttpResponse::Ok().body(format!("Organizer '{}' updated", session.user_id)),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Failed to update organizer: {}", e)),
+        Ok(()) => {
+            record_session_audit(&session, "organizer", session.user_id, "update", None, &pool).await;
+            HttpResponse::Ok().body(format!("Organizer '{}' updated", session.user_id))
+        },
+        Err(e) => AppError::Internal(format!("Failed to update organizer: {}", e)).error_response(),
     }
 }
 
 
+/// Handles partially updating the authenticated organizer.
+///
+/// Only the fields present in the body are changed; omitted fields keep their current value.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `data` - The JSON body containing the fields to update.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the updated organizer, `404 Not Found` if the user has no organizer,
+/// or an error message.
+pub async fn patch_organizer(
+    req: HttpRequest,
+    data: web::Json<PatchOrganizerData>,
+    pool: web::Data<SqlitePool>,
+) -> impl Responder {
+    let session = match validate_session(&req, &pool).await {
+        Ok(session) => session,
+        Err(e) => return e.error_response(),
+    };
+
+    if let Err(errors) = validate_organizer_website(data.website.as_deref()) {
+        return errors.error_response();
+    }
+
+    // TODO Remove old and save new image file and update image location reference
+
+    match update_organizer_fields(UpdateOrganizerFieldsData {organizer_id: session.user_id, fields: data.into_inner()}, &pool).await {
+        Ok(organizer) => {
+            record_session_audit(&session, "organizer", organizer.id, "update", None, &pool).await;
+            HttpResponse::Ok().json(organizer)
+        },
+        Err(sqlx::Error::RowNotFound) => AppError::NotFound("Organizer not found".to_string()).error_response(),
+        Err(e) => AppError::Internal(format!("Failed to update organizer: {}", e)).error_response(),
+    }
+}
+
+
+/// Handles clearing the authenticated organizer's profile while keeping their user account.
+///
+/// The organizer is reset in place to default data (the username as name, no logo or website),
+/// so the account keeps working. The old logo file is deleted from `static/` once nothing else uses it.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the reset organizer, `404 Not Found` if the user has no organizer,
+/// or an error message.
+pub async fn delete_organizer_profile(
+    req: HttpRequest,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let organizer = fetch_organizer(GetOrganizerData { organizer_id: session.user_id }, &pool)
+        .await
+        .map_err(|e| AppError::lookup("Organizer", e))?;
+
+    let user = fetch_user_by_id(GetUserIDData { id: session.user_id }, &pool)
+        .await
+        .map_err(|e| AppError::lookup("User", e))?;
+
+    let default_organizer = Organizer { id: organizer.id, name: user.username, logo: None, website: None };
+    update_organizer(default_organizer.clone(), &pool).await?;
+
+    if let Some(logo) = organizer.logo {
+        release_logo(&logo, &pool).await;
+    }
+
+    record_session_audit(&session, "organizer", organizer.id, "reset", None, &pool).await;
+
+    info!(target: "organizer", "Organizer {} cleared their profile", organizer.id);
+
+    Ok(HttpResponse::Ok().json(default_organizer))
+}
+
+
+/// Handles retrieving the public RSS feed of an organizer's upcoming events.
+///
+/// This endpoint does not require a session.
+///
+/// # Arguments
+///
+/// * `organizer_id` - The path parameter representing the organizer's ID.
+/// * `feed_config` - Base URL that feed items link to.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the RSS 2.0 document, or an error message.
+pub async fn get_organizer_feed(
+    organizer_id: web::Path<i64>,
+    feed_config: web::Data<FeedConfig>,
+    pool: web::Data<SqlitePool>,
+) -> impl Responder {
+    let organizer = match fetch_organizer(GetOrganizerData { organizer_id: *organizer_id }, &pool).await {
+        Ok(organizer) => organizer,
+        Err(sqlx::Error::RowNotFound) => return AppError::NotFound("Organizer not found".to_string()).error_response(),
+        Err(e) => return AppError::Internal(format!("Failed to fetch organizer: {}", e)).error_response(),
+    };
+
+    let events = match fetch_upcoming_events(GetOrganizerData { organizer_id: *organizer_id }, &pool).await {
+        Ok(events) => events,
+        Err(e) => return AppError::Internal(format!("Failed to fetch events: {}", e)).error_response(),
+    };
+
+    HttpResponse::Ok()
+        .content_type("application/rss+xml; charset=utf-8")
+        .body(build_event_feed(&organizer, &events, &feed_config.site_url))
+}
+
+
 /// Configures all routes related to organizer management.
 ///
 /// # Arguments
@@ -125,5 +272,40 @@ pub fn configure_organizer_routes(cfg: &mut web::ServiceConfig) {
     cfg
         .route("/organizer/", web::get().to(get_organizer))
         .route("/organizer/", web::post().to(register_organizer))
-        .route("/organizer/", web::put().to(put_organizer));
+        .route("/organizer/", web::put().to(put_organizer))
+        .route("/organizer/", web::patch().to(patch_organizer))
+        .route("/organizer/", web::delete().to(delete_organizer_profile))
+        .route("/organizer/{id}/feed.xml/", web::get().to(get_organizer_feed));
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{test, App, cookie::Cookie, http::StatusCode};
+
+    use crate::test_utils::{create_test_organizer, test_pool};
+
+    #[actix_web::test]
+    async fn patch_keeps_omitted_fields() {
+        let pool = test_pool().await;
+        let (_, token) = create_test_organizer("organizer", &pool).await;
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(pool))
+                .configure(configure_organizer_routes)
+        ).await;
+
+        let req = test::TestRequest::patch()
+            .uri("/organizer/")
+            .cookie(Cookie::new("session_token", token))
+            .set_json(serde_json::json!({"website": "https://example.com"}))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let organizer: serde_json::Value = test::read_body_json(res).await;
+        assert_eq!(organizer["name"], "organizer");
+        assert_eq!(organizer["website"], "https://example.com");
+    }
 }
@@ -0,0 +1,54 @@
+// External Libraries
+use rss::{Channel, Guid, Item};
+
+// Internal Models
+use crate::event::models::Event;
+use crate::organizer::models::Organizer;
+
+
+/// Builds an RSS 2.0 feed announcing an organizer's events.
+///
+/// Each item links to the event's public page under `site_url` and uses the
+/// event date as its publication date.
+///
+/// # Arguments
+///
+/// * `organizer` - The organizer the feed belongs to.
+/// * `events` - The events to list, in feed order.
+/// * `site_url` - Base URL of the public site.
+///
+/// # Returns
+///
+/// The serialized RSS document.
+pub fn build_event_feed(
+    organizer: &Organizer,
+    events: &[Event],
+    site_url: &str
+) -> String {
+    let site_url = site_url.trim_end_matches('/');
+
+    let items = events.iter()
+        .map(|event| {
+            let link = format!("{}/events/{}", site_url, event.id);
+
+            Item {
+                title: Some(event.title.clone()),
+                link: Some(link.clone()),
+                description: Some(event.description.clone()),
+                guid: Some(Guid { value: link, permalink: true }),
+                pub_date: event.event_date.and_hms_opt(0, 0, 0).map(|date| date.and_utc().to_rfc2822()),
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    let channel = Channel {
+        title: format!("{} events", organizer.name),
+        link: organizer.website.clone().unwrap_or_else(|| site_url.to_string()),
+        description: format!("Upcoming events from {}", organizer.name),
+        items,
+        ..Default::default()
+    };
+
+    channel.to_string()
+}
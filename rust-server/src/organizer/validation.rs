@@ -0,0 +1,26 @@
+// Internal Validation
+use crate::validation::{validate_url, ValidationErrors};
+
+
+/// Validates the client-supplied website of an organizer.
+///
+/// A missing or blank website is allowed, since organizers need not have one.
+///
+/// # Arguments
+///
+/// * `website` - The submitted website, if any.
+///
+/// # Returns
+///
+/// `Ok(())` if the website is absent or a valid URL, or `Err(ValidationErrors)` describing it.
+pub fn validate_organizer_website(
+    website: Option<&str>
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+
+    if let Some(website) = website.filter(|website| !website.trim().is_empty()) {
+        validate_url(website, "website", &mut errors);
+    }
+
+    errors.into_result()
+}
@@ -2,19 +2,32 @@
 use chrono::Datelike;
 use sqlx::SqlitePool;
 
+// Internal Mappers
+use crate::analytics::mapper::{fetch_year_events, month_of_year, sum_by_month};
+use crate::attendee::mapper::tally_attendees;
+use crate::event::mapper::tally_ticket_sales;
+use crate::ticket_tier::mapper::fetch_tier_sales;
+
 // Internal Models
+use crate::analytics::models::GetYearEventsData;
 use crate::overview::models::{
     MonthlyTotals,
+    RangeTotals,
+    DashboardSummary,
+    GetMonthlyTotalsData,
     GetOverview,
+    GetOverviewRange,
 };
 use crate::event::models::{Event};
+use crate::ticket_tier::models::GetTierSalesData;
 
 
-/// Fetches aggregated event statistics for a specific organizer and year.
+/// Fetches aggregated event statistics for a specific organizer and year,
+/// optionally restricted to a single category.
 ///
 /// # Arguments
 ///
-/// * `data` - A struct containing the `year` and `organizer_id`.
+/// * `data` - A struct containing the `year`, `organizer_id` and optional `category_id`.
 /// * `pool` - A reference to the SQLite connection pool.
 ///
 /// # Returns
@@ -26,50 +39,118 @@ use crate::event::models::{Event};
 ///
 /// Returns an error if the query to fetch events fails.
 pub async fn fetch_monthly_totals(
-    data: GetOverview,
+    data: GetMonthlyTotalsData,
     pool: &SqlitePool
 ) -> Result<MonthlyTotals, sqlx::Error> {
-    let year = data.year.to_string();
-    let organizer_id = data.organizer_id;
+    let events = fetch_year_events(GetYearEventsData {organizer_id: data.organizer_id, year: data.year, category_id: data.category_id}, pool).await?;
+
+    Ok(tally_by_month(&events, 12, month_of_year))
+}
 
+
+/// Fetches every dashboard metric for a specific organizer and year from a single query.
+///
+/// The result matches what the monthly totals, ticket sales and monthly attendees endpoints
+/// return for the same year.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `year` and `organizer_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing a `DashboardSummary` struct, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query to fetch events or their ticket tier sales fails.
+pub async fn fetch_dashboard_summary(
+    data: GetOverview,
+    pool: &SqlitePool
+) -> Result<DashboardSummary, sqlx::Error> {
+    let events = fetch_year_events(GetYearEventsData {organizer_id: data.organizer_id, year: data.year, category_id: None}, pool).await?;
+    let tier_sales = fetch_tier_sales(GetTierSalesData {event_ids: events.iter().map(|event| event.id).collect()}, pool).await?;
+
+    Ok(DashboardSummary {
+        totals: tally_by_month(&events, 12, month_of_year),
+        tickets: tally_ticket_sales(&events, &tier_sales),
+        attendees: tally_attendees(&events),
+    })
+}
+
+
+/// Fetches aggregated event statistics for a specific organizer between two dates, bucketed
+/// by calendar month so that the range may span several years.
+///
+/// Events are counted in the month they start.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the inclusive `start_date` and `end_date` and the `organizer_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing a `RangeTotals` struct with the months of the range and their aggregated
+/// statistics, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query to fetch events fails.
+pub async fn fetch_totals_between(
+    data: GetOverviewRange,
+    pool: &SqlitePool
+) -> Result<RangeTotals, sqlx::Error> {
     let events = sqlx::query_as!(
         Event,
-        "SELECT id, title, description, event_date, start_time, end_time, location, category_id, status, organizer_id, 
+        "SELECT id, title, description, event_date, end_date, start_time, end_time, location, category_id, status, organizer_id, 
                 price, tickets_sold, attendees, max_attendees, contact_email, contact_phone, registration_deadline,
-                is_virtual, image, map_embed, accessibility_info, safety_guidelines, created_at, updated_at
+                is_virtual, image, map_embed, accessibility_info, safety_guidelines, is_template, approval_status, created_at, updated_at
          FROM events 
-         WHERE strftime('%Y', event_date) = ? AND organizer_id = ?",
-        year, organizer_id
+         WHERE event_date BETWEEN ?1 AND ?2 AND organizer_id = ?3 AND is_template = 0 AND deleted_at IS NULL",
+        data.start_date, data.end_date, data.organizer_id
     )
         .fetch_all(pool)
         .await?;
 
-    let mut events_by_month = vec![0i64; 12];
-    let mut upcoming_by_month = vec![0i64; 12];
-    let mut canceled_by_month = vec![0i64; 12];
-    let mut tickets_by_month = vec![0i64; 12];
-    let mut attendees_by_month = vec![0i64; 12];
-
-    for event in events {
-        let month = event.event_date.month() as usize - 1;
-
-        events_by_month[month] += 1;
-        tickets_by_month[month] += event.tickets_sold;
-        attendees_by_month[month] += event.attendees;
-
-        if event.status == "upcoming" {
-            upcoming_by_month[month] += 1;
-        }
-        if event.status == "canceled" {
-            canceled_by_month[month] += 1;
-        }
-    }
+    let month_number = |year: i32, month0: u32| i64::from(year) * 12 + i64::from(month0);
+    let first_month = month_number(data.start_date.year(), data.start_date.month0());
+    let last_month = month_number(data.end_date.year(), data.end_date.month0());
 
-    Ok(MonthlyTotals {
-        events: events_by_month,
-        upcoming: upcoming_by_month,
-        canceled: canceled_by_month,
-        tickets: tickets_by_month,
-        attendees: attendees_by_month,
-    })
-}
\ No newline at end of file
+    let months = (first_month..=last_month)
+        .map(|month| format!("{:04}-{:02}", month.div_euclid(12), month.rem_euclid(12) + 1))
+        .collect::<Vec<_>>();
+
+    let totals = tally_by_month(&events, months.len(), |event| {
+        (month_number(event.event_date.year(), event.event_date.month0()) - first_month) as usize
+    });
+
+    Ok(RangeTotals { months, totals })
+}
+
+
+/// Tallies events into monthly buckets.
+///
+/// # Arguments
+///
+/// * `events` - The events to tally.
+/// * `bucket_count` - Number of monthly buckets.
+/// * `bucket_of` - Returns the index of the bucket an event belongs to.
+///
+/// # Returns
+///
+/// A `MonthlyTotals` struct with `bucket_count` entries per metric.
+fn tally_by_month(
+    events: &[Event],
+    bucket_count: usize,
+    bucket_of: impl Fn(&Event) -> usize
+) -> MonthlyTotals {
+    MonthlyTotals {
+        events: sum_by_month(events, bucket_count, &bucket_of, |_| 1),
+        upcoming: sum_by_month(events, bucket_count, &bucket_of, |event| i64::from(event.status == "upcoming")),
+        canceled: sum_by_month(events, bucket_count, &bucket_of, |event| i64::from(event.status == "canceled")),
+        tickets: sum_by_month(events, bucket_count, &bucket_of, |event| event.tickets_sold),
+        attendees: sum_by_month(events, bucket_count, &bucket_of, |event| event.attendees),
+    }
+}
@@ -1,6 +1,11 @@
 // External Libraries
+use chrono::NaiveDate;
 use serde::{Serialize, Deserialize};
 
+// Internal Models
+use crate::attendee::models::AttendeeTotals;
+use crate::event::models::TicketTotals;
+
 
 /// Represents aggregated totals of various event-related metrics for a given year.
 #[derive(Serialize)]
@@ -22,6 +27,33 @@ pub struct MonthlyTotals {
 }
 
 
+/// Represents aggregated totals of event-related metrics for each month of a date range.
+#[derive(Serialize)]
+pub struct RangeTotals {
+    /// Months covered by the range in "YYYY-MM" format, in order; each totals entry belongs to
+    /// the month at the same position.
+    pub months: Vec<String>,
+
+    /// Totals for each month of the range.
+    #[serde(flatten)]
+    pub totals: MonthlyTotals,
+}
+
+
+/// Represents every metric of the dashboard for a given year.
+#[derive(Serialize)]
+pub struct DashboardSummary {
+    /// Monthly totals of events, upcoming and canceled events, ticket sales and attendees.
+    pub totals: MonthlyTotals,
+
+    /// Monthly ticket revenue and total profit.
+    pub tickets: TicketTotals,
+
+    /// Monthly attendees and total attendees.
+    pub attendees: AttendeeTotals,
+}
+
+
 /// Query parameters for requesting overview totals.
 #[derive(Deserialize)]
 pub struct YearQuery {
@@ -30,6 +62,31 @@ pub struct YearQuery {
 }
 
 
+/// Query parameters for requesting monthly overview totals.
+#[derive(Deserialize)]
+pub struct MonthlyTotalsQuery {
+    /// The year to retrieve totals for (e.g., 2025).
+    pub year: i64,
+
+    /// Optional category to restrict the totals to; all categories when omitted.
+    pub category_id: Option<i64>,
+}
+
+
+/// Data parameters for getting monthly overview totals.
+#[derive(Deserialize)]
+pub struct GetMonthlyTotalsData {
+    /// The year to retrieve totals for (e.g., 2025).
+    pub year: i64,
+
+    /// Identifier for the event organizer.
+    pub organizer_id: i64,
+
+    /// Optional category to restrict the totals to.
+    pub category_id: Option<i64>,
+}
+
+
 /// Data parameters for getting overview totals.
 #[derive(Deserialize)]
 pub struct GetOverview {
@@ -41,6 +98,30 @@ pub struct GetOverview {
 }
 
 
+/// Query parameters for requesting overview totals between two dates.
+#[derive(Deserialize)]
+pub struct DateRangeQuery {
+    /// First date to include, in "YYYY-MM-DD" format.
+    pub start_date: NaiveDate,
+
+    /// Last date to include, in "YYYY-MM-DD" format.
+    pub end_date: NaiveDate,
+}
+
+
+/// Data parameters for getting overview totals between two dates.
+pub struct GetOverviewRange {
+    /// First date to include.
+    pub start_date: NaiveDate,
+
+    /// Last date to include.
+    pub end_date: NaiveDate,
+
+    /// Identifier for the event organizer.
+    pub organizer_id: i64,
+}
+
+
 /// Represents the number of events on a specific date.
 #[derive(Serialize)]
 pub struct CountByDate {
@@ -1,30 +1,42 @@
 // External Libraries
-use actix_web::{web, Responder, HttpResponse, HttpRequest};
+use actix_web::{web, Responder, HttpResponse, HttpRequest, ResponseError};
 use sqlx::SqlitePool;
 
 // Internal Mappers
 use crate::overview::mapper::{
     fetch_monthly_totals,
+    fetch_dashboard_summary,
+    fetch_totals_between,
 };
 
 // Internal Models
 use crate::overview::models::{
     MonthlyTotals,
+    MonthlyTotalsQuery,
+    GetMonthlyTotalsData,
     YearQuery,
     GetOverview,
+    DateRangeQuery,
+    GetOverviewRange,
 };
 
 // Internal Services
 use crate::auth::services::validate_session;
 
+// Internal Validation
+use crate::validation::ValidationErrors;
+
+// Internal Errors
+use crate::error::AppError;
+
 
 /// Retrieves aggregated monthly totals for events, upcoming events, canceled events,
-/// ticket sales, and attendees for a specific year.
+/// ticket sales, and attendees for a specific year, optionally for a single category.
 ///
 /// # Arguments
 ///
 /// * `req` - The incoming HTTP request containing session data.
-/// * `query` - A query parameter containing the year to retrieve data for.
+/// * `query` - Query parameters containing the year and optional `category_id` to retrieve data for.
 /// * `pool` - A reference to the SQLite database connection pool.
 ///
 /// # Returns
@@ -32,21 +44,78 @@ use crate::auth::services::validate_session;
 /// A JSON response containing the aggregated totals for each category or an error message if the operation fails.
 pub async fn get_monthly_totals(
     req: HttpRequest,
-    query: web::Query<YearQuery>,
+    query: web::Query<MonthlyTotalsQuery>,
     pool: web::Data<SqlitePool>,
 ) -> impl Responder {
     let session = match validate_session(&req, &pool).await {
         Ok(session) => session,
-        Err(response) => return response,
+        Err(e) => return e.error_response(),
     };
 
     let year = query.year;
     let organizer_id = session.user_id;
+    let category_id = query.category_id;
 
-    match fetch_monthly_totals(GetOverview {organizer_id, year}, &pool).await {
+    match fetch_monthly_totals(GetMonthlyTotalsData {organizer_id, year, category_id}, &pool).await {
         Ok(totals) => HttpResponse::Ok().json(MonthlyTotals {..totals}),
-        Err(e) => HttpResponse::InternalServerError().body(format!("Failed to fetch monthly totals: {}", e)),
+        Err(e) => AppError::Internal(format!("Failed to fetch monthly totals: {}", e)).error_response(),
+    }
+}
+
+
+/// Retrieves every dashboard metric for a specific year in one response: the monthly totals,
+/// the ticket revenue and the attendee totals.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `query` - A query parameter containing the year to retrieve the summary for.
+/// * `pool` - A reference to the SQLite database connection pool.
+///
+/// # Returns
+///
+/// A JSON response containing the dashboard summary or an error message if the operation fails.
+pub async fn get_dashboard_summary(
+    req: HttpRequest,
+    query: web::Query<YearQuery>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let summary = fetch_dashboard_summary(GetOverview {organizer_id: session.user_id, year: query.year}, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(summary))
+}
+
+
+/// Retrieves aggregated totals for events, upcoming events, canceled events, ticket sales,
+/// and attendees for each month between two dates, e.g. for a rolling 90-day dashboard.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `query` - Query parameters containing the inclusive `start_date` and `end_date`.
+/// * `pool` - A reference to the SQLite database connection pool.
+///
+/// # Returns
+///
+/// A JSON response containing the months of the range as "YYYY-MM" strings and the totals for
+/// each, `400 Bad Request` if `start_date` is after `end_date`, or an error message.
+pub async fn get_totals_between(
+    req: HttpRequest,
+    query: web::Query<DateRangeQuery>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let DateRangeQuery { start_date, end_date } = query.into_inner();
+    if start_date > end_date {
+        return Err(ValidationErrors::single("start_date", format!("must be on or before end_date ({})", end_date)).into());
     }
+
+    let totals = fetch_totals_between(GetOverviewRange {start_date, end_date, organizer_id: session.user_id}, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(totals))
 }
 
 
@@ -61,5 +130,7 @@ pub async fn get_monthly_totals(
 /// Configures the provided service with overview routes.
 pub fn configure_overview_routes(cfg: &mut web::ServiceConfig) {
     cfg
-        .route("/overview/totals/", web::get().to(get_monthly_totals));
+        .route("/overview/totals/", web::get().to(get_monthly_totals))
+        .route("/overview/totals/range/", web::get().to(get_totals_between))
+        .route("/overview/summary/", web::get().to(get_dashboard_summary));
 }
@@ -1,8 +1,9 @@
 // External Libraries
-use sqlx::SqlitePool;
+use log::debug;
+use sqlx::{SqliteConnection, SqlitePool};
 
 // Internal Models
-use crate::speaker::models::{Speaker, GetSpeakerData};
+use crate::speaker::models::{Speaker, GetSpeakerData, DeleteSpeakerData, DeleteSpeakersData};
 
 
 /// Retrieves speaker items by their event ID.
@@ -27,7 +28,7 @@ pub async fn fetch_speakers(
 
     sqlx::query_as!(
         Speaker,
-        "SELECT id, event_id, name, bio, photo
+        "SELECT id, event_id, name, bio, photo, created_at, updated_at
          FROM speakers
          WHERE event_id = ?",
         event_id
@@ -37,12 +38,42 @@ pub async fn fetch_speakers(
 }
 
 
+/// Retrieves a single speaker by its ID.
+///
+/// # Arguments
+///
+/// * `id` - Unique identifier of the speaker.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the `Speaker` if found, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if no speaker has the given ID.
+pub async fn fetch_speaker(
+    id: i64,
+    pool: &SqlitePool
+) -> Result<Speaker, sqlx::Error> {
+    sqlx::query_as!(
+        Speaker,
+        "SELECT id, event_id, name, bio, photo, created_at, updated_at
+         FROM speakers
+         WHERE id = ?",
+        id
+    )
+        .fetch_one(pool)
+        .await
+}
+
+
 /// Creates multiple speaker items in the database.
 ///
 /// # Arguments
 ///
 /// * `data` - A vector of `Speaker` structs containing the created speaker items.
-/// * `pool` - A reference to the SQLite connection pool.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
 ///
 /// # Returns
 ///
@@ -53,8 +84,10 @@ pub async fn fetch_speakers(
 /// Returns an error if any of the creation queries fail during execution.
 pub async fn create_speakers(
     data: Vec<Speaker>, 
-    pool: &SqlitePool
+    conn: &mut SqliteConnection
 ) -> Result<Vec<Speaker>, sqlx::Error> {
+    debug!(target: "speaker", "Creating {} speaker(s)", data.len());
+
     let mut speakers = Vec::new();
     
     for speaker_item in data {
@@ -62,10 +95,10 @@ pub async fn create_speakers(
             Speaker,
             "INSERT INTO speakers (event_id, name, bio, photo)
              VALUES (?, ?, ?, ?)
-             RETURNING id, event_id, name, bio, photo",
+             RETURNING id, event_id, name, bio, photo, created_at, updated_at",
             speaker_item.event_id, speaker_item.name, speaker_item.bio, speaker_item.photo
         )
-            .fetch_one(pool)
+            .fetch_one(&mut *conn)
             .await?;
         
         speakers.push(rec);
@@ -79,8 +112,9 @@ pub async fn create_speakers(
 ///
 /// # Arguments
 ///
+/// * `event_id` - Unique identifier of the event the items belong to.
 /// * `data` - A vector of `Speaker` structs containing the updated speaker items.
-/// * `pool` - A reference to the SQLite connection pool.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
 ///
 /// # Returns
 ///
@@ -88,22 +122,180 @@ pub async fn create_speakers(
 ///
 /// # Errors
 ///
-/// Returns an error if any of the update queries fail during execution.
+/// Returns an error if any of the update queries fail during execution. Items that do not
+/// belong to the event are left untouched.
 pub async fn update_speakers(
+    event_id: i64,
     data: Vec<Speaker>, 
-    pool: &SqlitePool
+    conn: &mut SqliteConnection
 ) -> Result<(), sqlx::Error> {
+    debug!(target: "speaker", "Updating speakers {:?}", data.iter().map(|speaker_item| speaker_item.id).collect::<Vec<_>>());
+
     for speaker_item in data {
         sqlx::query_as!(
             Speaker,
             "UPDATE speakers 
-             SET name = ?, bio = ?, photo = ? 
-             WHERE id = ?",
-            speaker_item.name, speaker_item.bio, speaker_item.photo, speaker_item.id
+             SET name = ?, bio = ?, photo = ?, updated_at = CURRENT_TIMESTAMP
+             WHERE id = ? AND event_id = ?",
+            speaker_item.name, speaker_item.bio, speaker_item.photo, speaker_item.id, event_id
         )
-            .execute(pool)
+            .execute(&mut *conn)
             .await?;
     };
 
     Ok(())
-}
\ No newline at end of file
+}
+
+
+/// Copies every speaker of one event onto another event.
+///
+/// # Arguments
+///
+/// * `source_event_id` - Unique identifier of the event to copy from.
+/// * `target_event_id` - Unique identifier of the event to copy onto.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` containing the number of copied rows, or an `sqlx::Error` if the insert fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails or any constraint is violated.
+pub async fn copy_speakers(
+    source_event_id: i64,
+    target_event_id: i64,
+    conn: &mut SqliteConnection
+) -> Result<u64, sqlx::Error> {
+    debug!(target: "speaker", "Copying speakers from event {} to event {}", source_event_id, target_event_id);
+
+    let result = sqlx::query!(
+        "INSERT INTO speakers (event_id, name, bio, photo)
+         SELECT ?, name, bio, photo
+         FROM speakers
+         WHERE event_id = ?",
+        target_event_id, source_event_id
+    )
+        .execute(conn)
+        .await?;
+
+    Ok(result.rows_affected())
+}
+
+
+/// Deletes a single speaker of an event.
+///
+/// Agenda items presented by the speaker keep their speaker name but are unlinked.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id` and `speaker_id`.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` indicating success, or an `sqlx::Error` if the delete fails.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if the event has no speaker with the ID, or an error if
+/// the query fails.
+pub async fn delete_speaker(
+    data: DeleteSpeakerData,
+    conn: &mut SqliteConnection
+) -> Result<(), sqlx::Error> {
+    match delete_speakers(DeleteSpeakersData { event_id: data.event_id, ids: vec![data.speaker_id] }, conn).await? {
+        0 => Err(sqlx::Error::RowNotFound),
+        _ => Ok(()),
+    }
+}
+
+
+/// Deletes several speakers of an event by their IDs.
+///
+/// Only speakers belonging to the event are deleted; other IDs are ignored.
+///
+/// Agenda items presented by a deleted speaker keep their speaker name but are unlinked.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id` and the IDs to delete.
+/// * `conn` - A mutable reference to an SQLite connection, typically an open transaction.
+///
+/// # Returns
+///
+/// A `Result` containing the number of deleted rows, or an `sqlx::Error` if the delete fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails or any constraint is violated.
+pub async fn delete_speakers(
+    data: DeleteSpeakersData,
+    conn: &mut SqliteConnection
+) -> Result<u64, sqlx::Error> {
+    debug!(target: "speaker", "Deleting speakers {:?} of event {}", data.ids, data.event_id);
+
+    let ids = serde_json::to_string(&data.ids).unwrap_or_else(|_| "[]".to_string());
+
+    sqlx::query!(
+        "UPDATE agendas SET speaker_id = NULL WHERE event_id = ? AND speaker_id IN (SELECT value FROM json_each(?))",
+        data.event_id, ids
+    )
+        .execute(&mut *conn)
+        .await?;
+
+    let result = sqlx::query!(
+        "DELETE FROM speakers WHERE event_id = ? AND id IN (SELECT value FROM json_each(?))",
+        data.event_id, ids
+    )
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(result.rows_affected())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDateTime;
+
+    use crate::test_utils::{create_test_event, create_test_organizer, test_pool};
+
+    fn speaker(id: i64, event_id: i64, name: &str) -> Speaker {
+        Speaker {
+            id,
+            event_id,
+            name: name.to_string(),
+            bio: None,
+            photo: None,
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+        }
+    }
+
+    #[actix_web::test]
+    async fn speaker_round_trip() {
+        let pool = test_pool().await;
+        let (organizer_id, _) = create_test_organizer("organizer", &pool).await;
+        let event_id = create_test_event(organizer_id, &pool).await;
+        let mut conn = pool.acquire().await.unwrap();
+
+        let created = create_speakers(vec![speaker(0, event_id, "Ada")], &mut conn).await.unwrap();
+        let speaker_id = created[0].id;
+        assert_eq!(created[0].name, "Ada");
+
+        update_speakers(event_id, vec![speaker(speaker_id, event_id, "Grace")], &mut conn).await.unwrap();
+        drop(conn);
+        assert_eq!(fetch_speaker(speaker_id, &pool).await.unwrap().name, "Grace");
+
+        let mut conn = pool.acquire().await.unwrap();
+        delete_speaker(DeleteSpeakerData { event_id, speaker_id }, &mut conn).await.unwrap();
+        assert!(matches!(
+            delete_speaker(DeleteSpeakerData { event_id, speaker_id }, &mut conn).await,
+            Err(sqlx::Error::RowNotFound)
+        ));
+        drop(conn);
+        assert!(fetch_speakers(GetSpeakerData { event_id }, &pool).await.unwrap().is_empty());
+    }
+}
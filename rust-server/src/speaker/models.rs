@@ -1,5 +1,6 @@
 // External Libraries
 use serde::{Serialize, Deserialize};
+use chrono::NaiveDateTime;
 
 
 /// Represents a speaker in the system.
@@ -19,6 +20,14 @@ pub struct Speaker {
 
     /// Photo of the speaker
     pub photo: Option<String>,
+
+    /// Timestamp for when the speaker was created.
+    #[serde(skip_deserializing)]
+    pub created_at: NaiveDateTime,
+
+    /// Timestamp for the last update to the speaker.
+    #[serde(skip_deserializing)]
+    pub updated_at: NaiveDateTime,
 }
 
 
@@ -27,4 +36,24 @@ pub struct Speaker {
 pub struct GetSpeakerData {
     /// Unique identifier for the event of the speaker.
     pub event_id: i64,
-}
\ No newline at end of file
+}
+
+
+/// Data required to delete a single speaker of an event.
+pub struct DeleteSpeakerData {
+    /// Unique identifier for the event of the speaker.
+    pub event_id: i64,
+
+    /// Unique identifier of the speaker to delete.
+    pub speaker_id: i64,
+}
+
+
+/// Data required to delete several speakers of an event.
+pub struct DeleteSpeakersData {
+    /// Unique identifier for the event of the speakers.
+    pub event_id: i64,
+
+    /// Unique identifiers of the speakers to delete.
+    pub ids: Vec<i64>,
+}
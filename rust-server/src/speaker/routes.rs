@@ -0,0 +1,60 @@
+// External Libraries
+use actix_web::{web, HttpRequest, HttpResponse};
+use sqlx::SqlitePool;
+
+// Internal Mappers
+use crate::speaker::mapper::fetch_speaker;
+use crate::event::mapper::fetch_event;
+
+// Internal Models
+use crate::event::models::GetEventData;
+
+// Internal Services
+use crate::auth::services::validate_session;
+
+// Internal Errors
+use crate::error::AppError;
+
+
+/// Handles retrieving a single speaker of an event owned by the session's organizer.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `id` - The path parameter representing the speaker's ID.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the speaker, `404 Not Found` if it does not exist or the organizer
+/// does not own its event, or an error message.
+pub async fn get_speaker(
+    req: HttpRequest,
+    id: web::Path<i64>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let speaker = fetch_speaker(*id, &pool).await
+        .map_err(|e| AppError::lookup("Speaker", e))?;
+
+    fetch_event(GetEventData {event_id: speaker.event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Speaker", e))?;
+
+    Ok(HttpResponse::Ok().json(speaker))
+}
+
+
+/// Configures all routes related to speakers.
+///
+/// # Arguments
+///
+/// * `cfg` - A mutable reference to the Actix service configuration.
+///
+/// # Returns
+///
+/// Adds all speaker routes to the Actix web application.
+pub fn configure_speaker_routes(cfg: &mut web::ServiceConfig) {
+    cfg
+        .route("/speakers/{id}/", web::get().to(get_speaker));
+}
@@ -0,0 +1,123 @@
+// External Libraries
+use sqlx::SqlitePool;
+use sqlx::sqlite::SqlitePoolOptions;
+
+
+/// Schema of `database.db` before the first migration.
+const BASE_SCHEMA: &str = include_str!("../tests/fixtures/base_schema.sql");
+
+
+/// Creates an empty in-memory database with the base schema and every migration applied.
+///
+/// The pool holds a single connection, since each connection to `sqlite::memory:` opens a
+/// database of its own.
+///
+/// # Returns
+///
+/// A `SqlitePool` connected to the new database.
+///
+/// # Panics
+///
+/// Panics if the database cannot be created or migrated.
+pub async fn test_pool() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("Failed to create in-memory database");
+
+    sqlx::raw_sql(BASE_SCHEMA)
+        .execute(&pool)
+        .await
+        .expect("Failed to load base schema");
+
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("Failed to apply migrations");
+
+    pool
+}
+
+
+/// Creates a user with an organizer profile and a signed-in session.
+///
+/// # Arguments
+///
+/// * `username` - Username of the user, also used as the organizer's name.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// The ID of the user and organizer, and the session token to send as the `session_token` cookie.
+///
+/// # Panics
+///
+/// Panics if any of the rows cannot be inserted.
+pub async fn create_test_organizer(
+    username: &str,
+    pool: &SqlitePool
+) -> (i64, String) {
+    let user_id = sqlx::query_scalar::<_, i64>("INSERT INTO users (username, password) VALUES (?, '') RETURNING id")
+        .bind(username)
+        .fetch_one(pool)
+        .await
+        .expect("Failed to create user");
+
+    sqlx::query("INSERT INTO organizers (id, name) VALUES (?, ?)")
+        .bind(user_id)
+        .bind(username)
+        .execute(pool)
+        .await
+        .expect("Failed to create organizer");
+
+    let token = format!("{}-session", username);
+    sqlx::query("INSERT INTO sessions (user_id, token, expires_at) VALUES (?, ?, datetime('now', '+1 day'))")
+        .bind(user_id)
+        .bind(&token)
+        .execute(pool)
+        .await
+        .expect("Failed to create session");
+
+    (user_id, token)
+}
+
+
+/// Creates an upcoming event of an organizer, filed under a category of their own.
+///
+/// # Arguments
+///
+/// * `organizer_id` - Unique identifier of the organizer owning the event.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// The ID of the new event.
+///
+/// # Panics
+///
+/// Panics if any of the rows cannot be inserted.
+pub async fn create_test_event(
+    organizer_id: i64,
+    pool: &SqlitePool
+) -> i64 {
+    sqlx::query("INSERT OR IGNORE INTO categories (name, description, organizer_id) VALUES ('Test', '', ?)")
+        .bind(organizer_id)
+        .execute(pool)
+        .await
+        .expect("Failed to create category");
+
+    sqlx::query_scalar::<_, i64>(
+        "INSERT INTO events (title, description, event_date, start_time, end_time, location, category_id, status,
+                             organizer_id, price, tickets_sold, attendees, max_attendees, contact_email, contact_phone,
+                             registration_deadline)
+         VALUES ('Test event', '', date('now', '+30 days'), '09:00', '17:00', 'Hall A',
+                 (SELECT id FROM categories WHERE organizer_id = ?1 AND name = 'Test'), 'upcoming',
+                 ?1, 0, 0, 0, 100, 'organizer@example.com', '', date('now', '+20 days'))
+         RETURNING id"
+    )
+        .bind(organizer_id)
+        .fetch_one(pool)
+        .await
+        .expect("Failed to create event")
+}
@@ -0,0 +1,159 @@
+// External Libraries
+use log::debug;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+
+// Internal Models
+use crate::ticket_tier::models::{TicketTier, TierSales, GetTicketTiersData, GetTierSalesData, CreateTicketTierData, UpdateTicketTierData};
+
+
+/// Retrieves the ticket tiers of a specific event, in the order they were created.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_id`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing a list of `TicketTier` structs, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn fetch_ticket_tiers(
+    data: GetTicketTiersData,
+    pool: &SqlitePool
+) -> Result<Vec<TicketTier>, sqlx::Error> {
+    sqlx::query_as!(
+        TicketTier,
+        "SELECT id, event_id, name, price, quantity, created_at, updated_at
+         FROM ticket_tiers WHERE event_id = ? ORDER BY id",
+        data.event_id
+    )
+        .fetch_all(pool)
+        .await
+}
+
+
+/// Retrieves the tickets sold in each tier of several events.
+///
+/// A tier's tickets are held by the event's confirmed attendees whose `ticket_type` matches
+/// the tier's name, ignoring case.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the `event_ids`.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the `TierSales` of each event that has tiers, keyed by event ID,
+/// or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn fetch_tier_sales(
+    data: GetTierSalesData,
+    pool: &SqlitePool
+) -> Result<HashMap<i64, Vec<TierSales>>, sqlx::Error> {
+    let event_ids = serde_json::to_string(&data.event_ids).unwrap_or_else(|_| "[]".to_string());
+
+    let rows = sqlx::query!(
+        r#"SELECT t.id AS "tier_id!", t.event_id, t.name, t.price, t.quantity, COUNT(a.id) AS "sold!: i64"
+           FROM ticket_tiers t
+           LEFT JOIN attendees a ON a.event_id = t.event_id AND a.ticket_type = t.name COLLATE NOCASE AND a.confirmed = 1
+           WHERE t.event_id IN (SELECT value FROM json_each(?))
+           GROUP BY t.id
+           ORDER BY t.id"#,
+        event_ids
+    )
+        .fetch_all(pool)
+        .await?;
+
+    let mut sales: HashMap<i64, Vec<TierSales>> = HashMap::new();
+    for row in rows {
+        sales.entry(row.event_id).or_default().push(TierSales {
+            tier_id: row.tier_id,
+            event_id: row.event_id,
+            name: row.name,
+            price: row.price,
+            quantity: row.quantity,
+            sold: row.sold,
+        });
+    }
+
+    Ok(sales)
+}
+
+
+/// Creates a new ticket tier for an event.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the event ID and the name, price and quantity of the tier.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the created `TicketTier`, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns a unique-violation database error if the event already has a tier with the same name.
+pub async fn create_ticket_tier(
+    data: CreateTicketTierData,
+    pool: &SqlitePool
+) -> Result<TicketTier, sqlx::Error> {
+    debug!(target: "ticket_tier", "Creating ticket tier '{}' for event {}: price={}, quantity={}", data.name, data.event_id, data.price, data.quantity);
+
+    sqlx::query_as!(
+        TicketTier,
+        "INSERT INTO ticket_tiers (event_id, name, price, quantity) VALUES (?, ?, ?, ?)
+         RETURNING id, event_id, name, price, quantity, created_at, updated_at",
+        data.event_id,
+        data.name,
+        data.price,
+        data.quantity
+    )
+        .fetch_one(pool)
+        .await
+}
+
+
+/// Updates the name, price and quantity of a ticket tier of an event.
+///
+/// # Arguments
+///
+/// * `data` - A struct containing the tier and event IDs and the new name, price and quantity.
+/// * `pool` - A reference to the SQLite connection pool.
+///
+/// # Returns
+///
+/// A `Result` containing the updated `TicketTier`, or an `sqlx::Error` if the query fails.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error::RowNotFound` if the event has no tier with the given ID, or a
+/// unique-violation database error if another of its tiers already has the new name.
+pub async fn update_ticket_tier(
+    data: UpdateTicketTierData,
+    pool: &SqlitePool
+) -> Result<TicketTier, sqlx::Error> {
+    debug!(target: "ticket_tier", "Updating ticket tier {} of event {}: name='{}', price={}, quantity={}", data.tier_id, data.event_id, data.name, data.price, data.quantity);
+
+    sqlx::query_as!(
+        TicketTier,
+        "UPDATE ticket_tiers SET name = ?, price = ?, quantity = ?, updated_at = CURRENT_TIMESTAMP
+         WHERE id = ? AND event_id = ?
+         RETURNING id, event_id, name, price, quantity, created_at, updated_at",
+        data.name,
+        data.price,
+        data.quantity,
+        data.tier_id,
+        data.event_id
+    )
+        .fetch_one(pool)
+        .await
+}
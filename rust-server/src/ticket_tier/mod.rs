@@ -0,0 +1,5 @@
+// Internal Modules
+pub mod mapper;
+pub mod models;
+pub mod routes;
+pub mod validation;
\ No newline at end of file
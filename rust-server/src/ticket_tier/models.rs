@@ -0,0 +1,119 @@
+// External Libraries
+use serde::{Serialize, Deserialize};
+use chrono::NaiveDateTime;
+
+
+/// Represents a priced ticket tier of an event, e.g. General, Student or VIP.
+///
+/// Confirmed attendees whose `ticket_type` matches the tier's name (ignoring case) hold its tickets.
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketTier {
+    /// Unique identifier for the ticket tier.
+    pub id: i64,
+
+    /// Unique identifier of the event for the ticket tier.
+    pub event_id: i64,
+
+    /// Name of the ticket tier, unique within the event regardless of case.
+    pub name: String,
+
+    /// Price of a ticket in the tier.
+    pub price: f64,
+
+    /// Number of tickets the tier offers.
+    pub quantity: i64,
+
+    /// Timestamp for when the ticket tier was created.
+    #[serde(skip_deserializing)]
+    pub created_at: NaiveDateTime,
+
+    /// Timestamp for the last update to the ticket tier.
+    #[serde(skip_deserializing)]
+    pub updated_at: NaiveDateTime,
+}
+
+
+/// Represents the tickets sold so far in a ticket tier.
+#[derive(Debug, Clone, Serialize)]
+pub struct TierSales {
+    /// Unique identifier for the ticket tier.
+    pub tier_id: i64,
+
+    /// Unique identifier of the event for the ticket tier.
+    pub event_id: i64,
+
+    /// Name of the ticket tier.
+    pub name: String,
+
+    /// Price of a ticket in the tier.
+    pub price: f64,
+
+    /// Number of tickets the tier offers.
+    pub quantity: i64,
+
+    /// Number of confirmed attendees holding a ticket of the tier.
+    pub sold: i64,
+}
+
+
+/// Data required to retrieve the ticket tiers of an event.
+pub struct GetTicketTiersData {
+    /// Unique identifier for the event of the ticket tiers.
+    pub event_id: i64,
+}
+
+
+/// Data required to retrieve the ticket sales of several events' tiers.
+pub struct GetTierSalesData {
+    /// Unique identifiers of the events.
+    pub event_ids: Vec<i64>,
+}
+
+
+/// Data submitted to create or update a ticket tier.
+#[derive(Deserialize)]
+pub struct TicketTierRequestData {
+    /// Name of the ticket tier.
+    pub name: String,
+
+    /// Price of a ticket in the tier.
+    pub price: f64,
+
+    /// Number of tickets the tier offers.
+    pub quantity: i64,
+}
+
+
+/// Data required to create a ticket tier.
+pub struct CreateTicketTierData {
+    /// Unique identifier of the event the tier belongs to.
+    pub event_id: i64,
+
+    /// Name of the ticket tier.
+    pub name: String,
+
+    /// Price of a ticket in the tier.
+    pub price: f64,
+
+    /// Number of tickets the tier offers.
+    pub quantity: i64,
+}
+
+
+/// Data required to update a ticket tier.
+pub struct UpdateTicketTierData {
+    /// Unique identifier of the ticket tier to update.
+    pub tier_id: i64,
+
+    /// Unique identifier of the event the tier belongs to.
+    pub event_id: i64,
+
+    /// New name of the ticket tier.
+    pub name: String,
+
+    /// New price of a ticket in the tier.
+    pub price: f64,
+
+    /// New number of tickets the tier offers.
+    pub quantity: i64,
+}
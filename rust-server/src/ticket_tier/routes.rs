@@ -0,0 +1,169 @@
+// External Libraries
+use actix_web::{web, HttpRequest, HttpResponse};
+use log::info;
+use sqlx::SqlitePool;
+
+// Internal Mappers
+use crate::ticket_tier::mapper::{fetch_ticket_tiers, create_ticket_tier, update_ticket_tier};
+use crate::event::mapper::fetch_event;
+
+// Internal Models
+use crate::ticket_tier::models::{GetTicketTiersData, TicketTierRequestData, CreateTicketTierData, UpdateTicketTierData};
+use crate::event::models::GetEventData;
+
+// Internal Services
+use crate::audit::services::record_session_audit;
+use crate::auth::services::validate_session;
+
+// Internal Validation
+use crate::ticket_tier::validation::validate_ticket_tier;
+
+// Internal Errors
+use crate::error::AppError;
+
+
+/// Handles retrieving the ticket tiers of an event owned by the session's organizer.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the event's ticket tiers, `404 Not Found` if the organizer does not
+/// own the event, or an error message.
+pub async fn get_ticket_tiers(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let event = fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    let tiers = fetch_ticket_tiers(GetTicketTiersData {event_id: event.id}, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(tiers))
+}
+
+
+/// Handles adding a ticket tier to an event owned by the session's organizer.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `event_id` - The path parameter representing the event's ID.
+/// * `data` - The JSON body containing the tier's name, price and quantity.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// `201 Created` with the new tier, `400 Bad Request` for an invalid tier or one that would offer
+/// more tickets than the event has seats, `404 Not Found` if the organizer does not own the event,
+/// `409 Conflict` if the event already has a tier with the name, or an error message.
+pub async fn post_ticket_tier(
+    req: HttpRequest,
+    event_id: web::Path<i64>,
+    data: web::Json<TicketTierRequestData>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let event = fetch_event(GetEventData {event_id: *event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    let mut data = data.into_inner();
+    data.name = data.name.trim().to_string();
+
+    let tiers = fetch_ticket_tiers(GetTicketTiersData {event_id: event.id}, &pool).await?;
+    let other_quantity = tiers.iter().map(|tier| tier.quantity).sum();
+    validate_ticket_tier(&data, other_quantity, event.max_attendees)?;
+
+    let TicketTierRequestData { name, price, quantity } = data;
+    let tier = create_ticket_tier(CreateTicketTierData {event_id: event.id, name: name.clone(), price, quantity}, &pool).await
+        .map_err(|e| ticket_tier_write_error(e, &name))?;
+
+    info!(target: "ticket_tier", "Organizer {} added ticket tier {} to event {}", session.user_id, tier.id, event.id);
+    record_session_audit(&session, "event", event.id, "create_ticket_tier", Some(format!("Added ticket tier '{}'", tier.name)), &pool).await;
+
+    Ok(HttpResponse::Created().json(tier))
+}
+
+
+/// Handles updating a ticket tier of an event owned by the session's organizer.
+///
+/// # Arguments
+///
+/// * `req` - The incoming HTTP request containing session data.
+/// * `path` - The path parameters representing the event and tier IDs.
+/// * `data` - The JSON body containing the tier's new name, price and quantity.
+/// * `pool` - The SQLite database connection pool.
+///
+/// # Returns
+///
+/// An HTTP response with the updated tier, `400 Bad Request` for an invalid tier or one that would
+/// offer more tickets than the event has seats, `404 Not Found` if the organizer does not own the
+/// event or the tier does not belong to it, `409 Conflict` if another of the event's tiers already
+/// has the name, or an error message.
+pub async fn put_ticket_tier(
+    req: HttpRequest,
+    path: web::Path<(i64, i64)>,
+    data: web::Json<TicketTierRequestData>,
+    pool: web::Data<SqlitePool>,
+) -> Result<HttpResponse, AppError> {
+    let session = validate_session(&req, &pool).await?;
+
+    let (event_id, tier_id) = path.into_inner();
+
+    let event = fetch_event(GetEventData {event_id, organizer_id: session.user_id}, &pool).await
+        .map_err(|e| AppError::lookup("Event", e))?;
+
+    let mut data = data.into_inner();
+    data.name = data.name.trim().to_string();
+
+    let tiers = fetch_ticket_tiers(GetTicketTiersData {event_id: event.id}, &pool).await?;
+    let other_quantity = tiers.iter().filter(|tier| tier.id != tier_id).map(|tier| tier.quantity).sum();
+    validate_ticket_tier(&data, other_quantity, event.max_attendees)?;
+
+    let TicketTierRequestData { name, price, quantity } = data;
+    let tier = update_ticket_tier(UpdateTicketTierData {tier_id, event_id: event.id, name: name.clone(), price, quantity}, &pool).await
+        .map_err(|e| ticket_tier_write_error(e, &name))?;
+
+    info!(target: "ticket_tier", "Organizer {} updated ticket tier {} of event {}", session.user_id, tier.id, event.id);
+    record_session_audit(&session, "event", event.id, "update_ticket_tier", Some(format!("Updated ticket tier '{}'", tier.name)), &pool).await;
+
+    Ok(HttpResponse::Ok().json(tier))
+}
+
+
+/// Converts a failed ticket tier insert or update into an error, reporting a duplicate name as a conflict.
+fn ticket_tier_write_error(
+    error: sqlx::Error,
+    name: &str
+) -> AppError {
+    match error {
+        sqlx::Error::Database(e) if e.is_unique_violation() =>
+            AppError::Conflict(format!("A ticket tier named '{}' already exists", name)),
+        e => AppError::lookup("Ticket tier", e),
+    }
+}
+
+
+/// Configures all routes related to event ticket tiers.
+///
+/// # Arguments
+///
+/// * `cfg` - A mutable reference to the Actix service configuration.
+///
+/// # Returns
+///
+/// Adds all ticket tier routes to the Actix web application.
+pub fn configure_ticket_tier_routes(cfg: &mut web::ServiceConfig) {
+    cfg
+        .route("/events/{id}/tiers/", web::get().to(get_ticket_tiers))
+        .route("/events/{id}/tiers/", web::post().to(post_ticket_tier))
+        .route("/events/{id}/tiers/{tier_id}/", web::put().to(put_ticket_tier));
+}
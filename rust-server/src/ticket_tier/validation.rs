@@ -0,0 +1,51 @@
+// Internal Models
+use crate::ticket_tier::models::TicketTierRequestData;
+
+// Internal Validation
+use crate::validation::ValidationErrors;
+
+
+/// Maximum length of a ticket tier name, in characters.
+pub const MAX_TIER_NAME_LENGTH: usize = 100;
+
+
+/// Validates a ticket tier before it is created or updated.
+///
+/// The tiers of an event together must not offer more tickets than the event has seats.
+///
+/// # Arguments
+///
+/// * `data` - The submitted tier.
+/// * `other_quantity` - Number of tickets offered by the event's other tiers.
+/// * `max_attendees` - Seat capacity of the event.
+///
+/// # Returns
+///
+/// `Ok(())` if the tier is valid, or `Err(ValidationErrors)` listing each invalid field.
+pub fn validate_ticket_tier(
+    data: &TicketTierRequestData,
+    other_quantity: i64,
+    max_attendees: i64
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+
+    if data.name.trim().is_empty() {
+        errors.add("name", "Name must not be empty");
+    } else if data.name.chars().count() > MAX_TIER_NAME_LENGTH {
+        errors.add("name", format!("Name must be at most {} characters", MAX_TIER_NAME_LENGTH));
+    }
+    if data.price < 0.0 {
+        errors.add("price", "must not be negative");
+    }
+    if data.quantity < 0 {
+        errors.add("quantity", "must not be negative");
+    } else if other_quantity + data.quantity > max_attendees {
+        errors.add("quantity", format!(
+            "must not exceed the {} seats of max_attendees ({}) not offered by other tiers",
+            (max_attendees - other_quantity).max(0),
+            max_attendees
+        ));
+    }
+
+    errors.into_result()
+}
@@ -0,0 +1,267 @@
+// External Libraries
+use actix_web::{HttpResponse, ResponseError, http::StatusCode};
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::fmt;
+
+
+/// Represents a validation failure for a single request field.
+#[derive(Debug, Serialize)]
+pub struct FieldError {
+    /// Name of the offending field, or `body` when it cannot be determined.
+    pub field: String,
+
+    /// Human-readable description of the failure.
+    pub message: String,
+}
+
+
+/// Represents every validation failure collected for a request.
+///
+/// Serializes as `{"errors": [{"field": "...", "message": "..."}]}` so clients can map
+/// each failure back to a form field.
+#[derive(Debug, Default, Serialize)]
+pub struct ValidationErrors {
+    /// List of field-level validation failures.
+    pub errors: Vec<FieldError>,
+}
+
+
+impl ValidationErrors {
+    /// Creates an empty collection of validation errors.
+    ///
+    /// # Returns
+    ///
+    /// A `ValidationErrors` with no failures.
+    pub fn new() -> Self {
+        ValidationErrors::default()
+    }
+
+
+    /// Records a validation failure for a field.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - Name of the offending field.
+    /// * `message` - Description of the failure.
+    pub fn add(
+        &mut self,
+        field: &str,
+        message: impl Into<String>
+    ) {
+        self.errors.push(FieldError { field: field.to_string(), message: message.into() });
+    }
+
+
+    /// Converts the collection into a result, failing if any errors were recorded.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if no failures were recorded, otherwise `Err(self)`.
+    pub fn into_result(self) -> Result<(), ValidationErrors> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+
+    /// Adds every failure of another collection, prefixing its field names.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - Prefix for the merged field names, e.g. `"event"` turns `price` into `event.price`.
+    /// * `other` - The failures to merge.
+    pub fn merge(
+        &mut self,
+        prefix: &str,
+        other: ValidationErrors
+    ) {
+        for error in other.errors {
+            self.add(&format!("{}.{}", prefix, error.field), error.message);
+        }
+    }
+
+
+    /// Creates a collection containing a single field error.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - Name of the offending field.
+    /// * `message` - Description of the failure.
+    ///
+    /// # Returns
+    ///
+    /// A `ValidationErrors` holding the one failure.
+    pub fn single(
+        field: &str,
+        message: impl Into<String>
+    ) -> Self {
+        ValidationErrors {
+            errors: vec![FieldError { field: field.to_string(), message: message.into() }],
+        }
+    }
+}
+
+
+/// Parses a `YYYY-MM-DD` date string, recording a failure for the field if it is malformed.
+///
+/// # Arguments
+///
+/// * `value` - The date string to parse.
+/// * `field` - Name of the field the value came from.
+/// * `errors` - The collection to record a failure in.
+///
+/// # Returns
+///
+/// The parsed `NaiveDate`, or `None` if the value is malformed.
+pub fn parse_date(
+    value: &str,
+    field: &str,
+    errors: &mut ValidationErrors
+) -> Option<NaiveDate> {
+    match NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        Ok(date) => Some(date),
+        Err(_) => {
+            errors.add(field, format!("'{}' is not a valid date (expected YYYY-MM-DD)", value));
+            None
+        },
+    }
+}
+
+
+/// Records a failure for the field unless the value looks like an email address.
+///
+/// Only the shape is checked: a single `@` with text on both sides, a dot inside the domain and
+/// no whitespace.
+///
+/// # Arguments
+///
+/// * `value` - The email address to check.
+/// * `field` - Name of the field the value came from.
+/// * `errors` - The collection to record a failure in.
+pub fn validate_email(
+    value: &str,
+    field: &str,
+    errors: &mut ValidationErrors
+) {
+    let valid = match value.split_once('@') {
+        Some((local, domain)) => !local.is_empty()
+            && !domain.contains('@')
+            && domain.split('.').count() > 1
+            && domain.split('.').all(|label| !label.is_empty())
+            && !value.chars().any(char::is_whitespace),
+        None => false,
+    };
+
+    if !valid {
+        errors.add(field, format!("'{}' is not a valid email address", value));
+    }
+}
+
+
+/// Records a failure for the field unless the value is a `0`/`1` flag.
+///
+/// # Arguments
+///
+/// * `value` - The flag to check.
+/// * `field` - Name of the field the value came from.
+/// * `errors` - The collection to record a failure in.
+pub fn validate_flag(
+    value: i64,
+    field: &str,
+    errors: &mut ValidationErrors
+) {
+    if value != 0 && value != 1 {
+        errors.add(field, format!("{} is not a valid flag (expected 0 or 1)", value));
+    }
+}
+
+
+/// Records a failure for the field unless the value is an `http` or `https` URL with a host.
+///
+/// # Arguments
+///
+/// * `value` - The URL to check.
+/// * `field` - Name of the field the value came from.
+/// * `errors` - The collection to record a failure in.
+pub fn validate_url(
+    value: &str,
+    field: &str,
+    errors: &mut ValidationErrors
+) {
+    let host = value.strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"))
+        .and_then(|rest| rest.split(['/', '?', '#']).next());
+
+    if host.is_none_or(|host| host.is_empty() || host.chars().any(char::is_whitespace)) {
+        errors.add(field, format!("'{}' is not a valid URL (expected http:// or https://)", value));
+    }
+}
+
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages: Vec<String> = self.errors
+            .iter()
+            .map(|error| format!("{}: {}", error.field, error.message))
+            .collect();
+
+        write!(f, "{}", messages.join(", "))
+    }
+}
+
+
+impl ResponseError for ValidationErrors {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(self)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(value: &str) -> (Option<NaiveDate>, ValidationErrors) {
+        let mut errors = ValidationErrors::new();
+        let date = parse_date(value, "event_date", &mut errors);
+        (date, errors)
+    }
+
+    #[test]
+    fn parse_date_accepts_valid_date() {
+        let (date, errors) = parse("2026-02-28");
+        assert_eq!(date, NaiveDate::from_ymd_opt(2026, 2, 28));
+        assert!(errors.errors.is_empty());
+    }
+
+    #[test]
+    fn parse_date_rejects_malformed_strings() {
+        for value in ["28/02/2026", "2026-02", "tomorrow", "2026-02-28T10:00"] {
+            let (date, errors) = parse(value);
+            assert_eq!(date, None, "{} should not parse", value);
+            assert_eq!(errors.errors.len(), 1);
+            assert_eq!(errors.errors[0].field, "event_date");
+        }
+    }
+
+    #[test]
+    fn parse_date_rejects_impossible_date() {
+        let (date, errors) = parse("2026-02-30");
+        assert_eq!(date, None);
+        assert_eq!(errors.errors.len(), 1);
+    }
+
+    #[test]
+    fn parse_date_rejects_empty_string() {
+        let (date, errors) = parse("");
+        assert_eq!(date, None);
+        assert_eq!(errors.errors.len(), 1);
+    }
+}
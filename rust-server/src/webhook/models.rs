@@ -0,0 +1,44 @@
+// External Libraries
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+
+/// Settings for notifying an external integration of event changes.
+#[derive(Clone, Default)]
+pub struct WebhookConfig {
+    /// URL the notifications are posted to; `None` disables webhooks.
+    pub url: Option<String>,
+
+    /// Secret the notification bodies are signed with; `None` sends them unsigned.
+    pub secret: Option<String>,
+}
+
+
+/// Data describing a change to an event that integrations are notified of.
+pub struct EventWebhookData {
+    /// Unique identifier of the changed event.
+    pub event_id: i64,
+
+    /// What happened to the event, e.g. `"create"`, `"update"` or `"delete"`.
+    pub action: &'static str,
+
+    /// Identifier of the organizer who owns the event.
+    pub organizer_id: i64,
+}
+
+
+/// JSON body posted to the webhook URL.
+#[derive(Serialize)]
+pub struct EventWebhookPayload {
+    /// Unique identifier of the changed event.
+    pub event_id: i64,
+
+    /// What happened to the event.
+    pub action: &'static str,
+
+    /// Identifier of the organizer who owns the event.
+    pub organizer_id: i64,
+
+    /// Time the change was made.
+    pub timestamp: DateTime<Utc>,
+}
@@ -0,0 +1,108 @@
+// External Libraries
+use actix_web::rt;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use log::{debug, warn};
+use sha2::Sha256;
+use std::time::Duration;
+
+// Internal Models
+use crate::webhook::models::{WebhookConfig, EventWebhookData, EventWebhookPayload};
+
+
+/// Header carrying the hex-encoded HMAC-SHA256 of the body, prefixed with `sha256=`.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+
+/// Time a single webhook delivery may take before it is abandoned.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+
+/// Posts event change notifications to the configured webhook URL.
+///
+/// Shared across workers through `web::Data`, so every worker reuses the same HTTP client.
+pub struct WebhookNotifier {
+    /// Where and how notifications are sent.
+    config: WebhookConfig,
+
+    /// HTTP client used for every delivery.
+    client: reqwest::Client,
+}
+
+
+impl WebhookNotifier {
+    /// Creates a notifier for the given settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The webhook URL and signing secret.
+    ///
+    /// # Returns
+    ///
+    /// A new `WebhookNotifier`; it sends nothing when `config.url` is `None`.
+    pub fn new(
+        config: WebhookConfig
+    ) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(WEBHOOK_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+
+        WebhookNotifier { config, client }
+    }
+
+
+    /// Notifies the webhook of a change to an event without waiting for the delivery.
+    ///
+    /// Delivery is fire-and-forget: failures are logged and never affect the request that
+    /// made the change.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A struct containing the event, the action taken and the owning organizer.
+    pub fn notify_event_change(
+        &self,
+        data: EventWebhookData
+    ) {
+        let Some(url) = self.config.url.clone() else {
+            return;
+        };
+
+        let payload = EventWebhookPayload {
+            event_id: data.event_id,
+            action: data.action,
+            organizer_id: data.organizer_id,
+            timestamp: Utc::now(),
+        };
+        let body = match serde_json::to_vec(&payload) {
+            Ok(body) => body,
+            Err(e) => {
+                warn!(target: "webhook", "Failed to serialize {} of event {}: {}", payload.action, payload.event_id, e);
+                return;
+            },
+        };
+
+        let mut request = self.client.post(&url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json");
+        if let Some(secret) = self.config.secret.as_deref() {
+            request = request.header(WEBHOOK_SIGNATURE_HEADER, format!("sha256={}", sign(secret, &body)));
+        }
+
+        rt::spawn(async move {
+            match request.body(body).send().await.and_then(|response| response.error_for_status()) {
+                Ok(_) => debug!(target: "webhook", "Delivered {} of event {}", payload.action, payload.event_id),
+                Err(e) => warn!(target: "webhook", "Failed to deliver {} of event {} to {}: {}", payload.action, payload.event_id, url, e),
+            }
+        });
+    }
+}
+
+
+/// Computes the hex-encoded HMAC-SHA256 of a body.
+fn sign(
+    secret: &str,
+    body: &[u8]
+) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
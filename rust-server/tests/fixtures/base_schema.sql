@@ -0,0 +1,109 @@
+-- Schema of database.db before the first migration. Tests load it into an in-memory database
+-- and apply migrations/ on top, as `cargo run --bin migrate` does for database.db.
+CREATE TABLE IF NOT EXISTS "sessions" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"user_id"	INTEGER NOT NULL,
+	"token"	TEXT NOT NULL,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("user_id") REFERENCES "users"("id")
+);
+CREATE TABLE IF NOT EXISTS "attachments" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"event_id"	INTEGER NOT NULL,
+	"name"	TEXT NOT NULL,
+	"url"	TEXT NOT NULL,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("event_id") REFERENCES "events"("id")
+);
+CREATE TABLE IF NOT EXISTS "faqs" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"event_id"	INTEGER NOT NULL,
+	"question"	TEXT NOT NULL,
+	"answer"	TEXT,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("event_id") REFERENCES "events"("id")
+);
+CREATE TABLE IF NOT EXISTS "speakers" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"event_id"	INTEGER NOT NULL,
+	"name"	TEXT NOT NULL,
+	"bio"	TEXT,
+	"photo"	TEXT,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("event_id") REFERENCES "events"("id")
+);
+CREATE TABLE IF NOT EXISTS "comments" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"event_id"	INTEGER NOT NULL,
+	"message"	TEXT NOT NULL,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("event_id") REFERENCES "events"("id")
+);
+CREATE TABLE IF NOT EXISTS "agendas" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"event_id"	INTEGER NOT NULL,
+	"start_time"	DATETIME NOT NULL,
+	"title"	TEXT NOT NULL,
+	"speaker"	TEXT NOT NULL,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("event_id") REFERENCES "events"("id")
+);
+CREATE TABLE IF NOT EXISTS "categories" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"name"	TEXT NOT NULL,
+	"description"	TEXT NOT NULL,
+	PRIMARY KEY("id" AUTOINCREMENT)
+);
+CREATE TABLE IF NOT EXISTS "events" (
+	"id"	INTEGER NOT NULL,
+	"title"	TEXT NOT NULL,
+	"description"	TEXT NOT NULL,
+	"event_date"	DATE NOT NULL,
+	"start_time"	TEXT NOT NULL,
+	"end_time"	TEXT NOT NULL,
+	"location"	TEXT NOT NULL,
+	"category_id"	INTEGER NOT NULL,
+	"status"	TEXT NOT NULL,
+	"organizer_id"	INTEGER NOT NULL,
+	"price"	REAL NOT NULL,
+	"tickets_sold"	INTEGER NOT NULL,
+	"attendees"	INTEGER NOT NULL,
+	"max_attendees"	INTEGER NOT NULL,
+	"contact_email"	TEXT NOT NULL,
+	"contact_phone"	TEXT NOT NULL,
+	"registration_deadline"	DATE NOT NULL,
+	"is_virtual"	INTEGER NOT NULL DEFAULT 0,
+	"image"	TEXT,
+	"map_embed"	TEXT,
+	"accessibility_info"	TEXT,
+	"safety_guidelines"	TEXT,
+	"created_at"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	"updated_at"	DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("category_id") REFERENCES "categories"("id"),
+	FOREIGN KEY("organizer_id") REFERENCES "users"("id")
+);
+CREATE TABLE IF NOT EXISTS "organizers" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"name"	TEXT NOT NULL,
+	"logo"	TEXT,
+	"website"	TEXT,
+	PRIMARY KEY("id"),
+	FOREIGN KEY("id") REFERENCES "users"("id")
+);
+CREATE TABLE IF NOT EXISTS "users" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"username"	TEXT NOT NULL UNIQUE,
+	"password"	TEXT NOT NULL,
+	PRIMARY KEY("id" AUTOINCREMENT)
+);
+CREATE TABLE IF NOT EXISTS "attendees" (
+	"id"	INTEGER NOT NULL UNIQUE,
+	"event_id"	INTEGER NOT NULL,
+	"name"	TEXT NOT NULL,
+	"email"	TEXT NOT NULL,
+	"ticket_type"	TEXT NOT NULL,
+	"registration_date"	DATE NOT NULL,
+	PRIMARY KEY("id" AUTOINCREMENT),
+	FOREIGN KEY("event_id") REFERENCES "events"("id")
+);